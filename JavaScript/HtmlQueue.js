@@ -16,7 +16,11 @@ var HtmlQueue = /** @class */ (function () {
     };
     HtmlQueue.renderQueueTypeBadge = function (queue) {
         var badgeType = queue.queueType == 1 ? "badge-success" : "badge-warning";
-        return '<span class="badge ' + badgeType + '">' + this.renderQueueTypeName(queue) + "</span>";
+        var result = '<span class="badge ' + badgeType + '">' + this.renderQueueTypeName(queue) + "</span>";
+        if (queue.paused) {
+            result += ' <span class="badge badge-danger">paused</span>';
+        }
+        return result;
     };
     HtmlQueue.getQueueSizeBadgeType = function (queue) {
         if (queue.size > 100) {
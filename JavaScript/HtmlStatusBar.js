@@ -18,6 +18,8 @@ var HtmlStatusBar = /** @class */ (function () {
             '<td><div class="statusbar-separator"></div></td>' +
             '<td style="padding-left: 5px; min-width:270px">Total pages size:<span id="total-pages-size" style="text-shadow: 0 0 2px white;"></span></td>' +
             '<td style="padding-left: 5px; min-width:270px">Persistence ver: <span id="persistence-ver" style="text-shadow: 0 0 2px white;"></span></td>' +
+            '<td><div class="statusbar-separator"></div></td>' +
+            '<td style="padding-left: 5px; min-width:120px">Alerts: <b id="alerts" style="text-shadow: 0 0 2px white;"></b></td>' +
             '</tr></table></div>';
     };
     HtmlStatusBar.updateSessionsAmount = function (amount) {
@@ -87,6 +89,22 @@ var HtmlStatusBar = /** @class */ (function () {
             this.currentPersistenceVersion = ver;
         }
     };
+    HtmlStatusBar.updateAlerts = function (alerts) {
+        if (!this.alerts) {
+            this.alerts = document.getElementById('alerts');
+        }
+        if (!alerts) {
+            alerts = [];
+        }
+        var title = "";
+        for (var _i = 0, alerts_1 = alerts; _i < alerts_1.length; _i++) {
+            var alert_1 = alerts_1[_i];
+            title += alert_1.rule + ' ' + alert_1.target + ': ' + alert_1.message + '\n';
+        }
+        var color = alerts.length > 0 ? 'red' : 'green';
+        this.alerts.innerHTML = '<span style="color: ' + color + '">' + alerts.length + '</span>';
+        this.alerts.title = title;
+    };
     HtmlStatusBar.updateOffline = function () {
         if (this.connected) {
             this.connected = false;
@@ -38,48 +38,123 @@ var main = /** @class */ (function () {
             this.statusBarElement = document.getElementById('status-bar');
         }
         this.resize();
+        if (!this.statusStream)
+            this.startStatusStream();
+        if (this.streamIsActive)
+            return;
         if (this.requested)
             return;
         this.requested = true;
         $.ajax({ url: '/status', type: 'get' })
             .then(function (result) {
             _this.requested = false;
-            var filterPhrase = document.getElementById('filter').value;
-            filterPhrase == filterPhrase.trim();
-            var filterPhraseIsChanged = ServiceLocator.checkIfFilterPhraseIsChanged(filterPhrase);
-            if (filterPhraseIsChanged) {
-                console.log("filterPhraseIsChanged");
-            }
-            var topics_are_changed = ServiceLocator.checkIfTopicsAreChanged(result.topics);
-            if (topics_are_changed) {
-                console.log("topics_are_changed");
-            }
-            if (topics_are_changed || filterPhraseIsChanged) {
-                _this.topicsElement.innerHTML = HtmlTopics.renderTopics(result.topics);
-                ServiceLocator.topics = result.topics;
-            }
-            else {
-                HtmlTopics.updateTopicData(result.topics);
-            }
-            if (ServiceLocator.checkIfSessionsAreChanged(result.sessions)) {
-                _this.connectionsElement.innerHTML = HtmlSessions.renderSessions(result);
-                ServiceLocator.sessions = result.sessions;
-            }
-            else {
-                HtmlSessions.updateSessionData(result);
-            }
-            HtmlTopics.updateTopicSessions(result);
-            HtmlTopics.updateTopicQueues(result);
-            HtmlStatusBar.updateStatusbar(result);
-            HtmlStatusBar.updateSessionsAmount(result.sessions.items.length);
-            HtmlStatusBar.updatePersistenceVersion(result.persistenceVersion);
-            _this.filterLines(filterPhrase);
+            _this.render(result);
         }).fail(function () {
             _this.requested = false;
             HtmlStatusBar.updateOffline();
         });
     };
+    main.startStatusStream = function () {
+        var _this = this;
+        if (typeof EventSource === "undefined")
+            return;
+        var url = '/Status/Stream';
+        this.statusStream = new EventSource(url);
+        this.statusStream.addEventListener('snapshot', function (e) {
+            _this.streamIsActive = true;
+            _this.status = JSON.parse(e.data);
+            _this.render(_this.status);
+        });
+        this.statusStream.addEventListener('delta', function (e) {
+            _this.streamIsActive = true;
+            _this.status = _this.applyStatusDelta(_this.status, JSON.parse(e.data));
+            _this.render(_this.status);
+        });
+        this.statusStream.onerror = function () {
+            _this.streamIsActive = false;
+        };
+    };
+    main.applyStatusDelta = function (status, delta) {
+        if (!status) {
+            status = {
+                topics: { snapshotId: 0, items: [] },
+                queues: {},
+                sessions: { snapshotId: 0, items: [] },
+                system: delta.system,
+                persistenceVersion: delta.persistenceVersion,
+                alerts: delta.alerts
+            };
+        }
+        var topics = [];
+        for (var _i = 0, _a = status.topics.items; _i < _a.length; _i++) {
+            var topic = _a[_i];
+            if (delta.removedTopics.indexOf(topic.id) < 0)
+                topics.push(topic);
+        }
+        for (var _b = 0, _c = delta.topics; _b < _c.length; _b++) {
+            var topic = _c[_b];
+            var found = false;
+            for (var i = 0; i < topics.length; i++) {
+                if (topics[i].id == topic.id) {
+                    topics[i] = topic;
+                    found = true;
+                }
+            }
+            if (!found)
+                topics.push(topic);
+        }
+        var queues = status.queues;
+        for (var _d = 0, _e = delta.removedTopics; _d < _e.length; _d++) {
+            var topicId = _e[_d];
+            delete queues[topicId];
+        }
+        for (var topicId in delta.queues) {
+            queues[topicId] = delta.queues[topicId];
+        }
+        return {
+            topics: { snapshotId: delta.topicsSnapshotId, items: topics },
+            queues: queues,
+            sessions: delta.sessions ? delta.sessions : status.sessions,
+            system: delta.system,
+            persistenceVersion: delta.persistenceVersion,
+            alerts: delta.alerts
+        };
+    };
+    main.render = function (result) {
+        var filterPhrase = document.getElementById('filter').value;
+        filterPhrase == filterPhrase.trim();
+        var filterPhraseIsChanged = ServiceLocator.checkIfFilterPhraseIsChanged(filterPhrase);
+        if (filterPhraseIsChanged) {
+            console.log("filterPhraseIsChanged");
+        }
+        var topics_are_changed = ServiceLocator.checkIfTopicsAreChanged(result.topics);
+        if (topics_are_changed) {
+            console.log("topics_are_changed");
+        }
+        if (topics_are_changed || filterPhraseIsChanged) {
+            this.topicsElement.innerHTML = HtmlTopics.renderTopics(result.topics);
+            ServiceLocator.topics = result.topics;
+        }
+        else {
+            HtmlTopics.updateTopicData(result.topics);
+        }
+        if (ServiceLocator.checkIfSessionsAreChanged(result.sessions)) {
+            this.connectionsElement.innerHTML = HtmlSessions.renderSessions(result);
+            ServiceLocator.sessions = result.sessions;
+        }
+        else {
+            HtmlSessions.updateSessionData(result);
+        }
+        HtmlTopics.updateTopicSessions(result);
+        HtmlTopics.updateTopicQueues(result);
+        HtmlStatusBar.updateStatusbar(result);
+        HtmlStatusBar.updateSessionsAmount(result.sessions.items.length);
+        HtmlStatusBar.updatePersistenceVersion(result.persistenceVersion);
+        HtmlStatusBar.updateAlerts(result.alerts);
+        this.filterLines(filterPhrase);
+    };
     main.requested = false;
+    main.streamIsActive = false;
     main.statusBarHeight = 24;
     return main;
 }());
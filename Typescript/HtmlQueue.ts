@@ -27,7 +27,13 @@ class HtmlQueue {
 
         let badgeType = queue.queueType == 1 ? "badge-success" : "badge-warning";
 
-        return '<span class="badge ' + badgeType + '">' + this.renderQueueTypeName(queue) + "</span>";
+        let result = '<span class="badge ' + badgeType + '">' + this.renderQueueTypeName(queue) + "</span>";
+
+        if (queue.paused) {
+            result += ' <span class="badge badge-danger">paused</span>';
+        }
+
+        return result;
 
     }
 
@@ -28,7 +28,12 @@ class HtmlSessions {
             '<div><b>Read:</b>' + Utils.format_bytes(session.readSize) + '</div>' +
             '<div><b>Written:</b>' + Utils.format_bytes(session.writtenSize) + '</div>' +
             '<div><b>Read/sec:</b>' + Utils.format_bytes(session.readPerSec) + '</div>' +
-            '<div><b>Written/sec:</b>' + Utils.format_bytes(session.writtenPerSec) + '</div>';
+            '<div><b>Written/sec:</b>' + Utils.format_bytes(session.writtenPerSec) + '</div>' +
+            '<div><b>Published:</b>' + Utils.formatNumber(session.published) + '</div>' +
+            '<div><b>Rejects:</b>' + Utils.formatNumber(session.rejects) + '</div>' +
+            '<div><b>Delivered:</b>' + Utils.formatNumber(session.delivered) + '</div>' +
+            '<div><b>Confirmed:</b>' + Utils.formatNumber(session.confirmed) + '</div>' +
+            '<div><b>Avg confirmation:</b>' + Utils.format_duration(session.avgConfirmationMicroseconds) + '</div>';
     }
 
 
@@ -8,6 +8,7 @@ class HtmlStatusBar {
     private static bytesRwPerSec: HTMLElement;
     private static sessions: HTMLElement;
     private static persistenceVersion: HTMLElement;
+    private static alerts: HTMLElement;
 
     private static currentSessionsAmout: number = -1;
     private static currentPersistenceVersion: string = "";
@@ -42,6 +43,9 @@ class HtmlStatusBar {
             '<td style="padding-left: 5px; min-width:270px">Total pages size:<span id="total-pages-size" style="text-shadow: 0 0 2px white;"></span></td>' +
 
             '<td style="padding-left: 5px; min-width:270px">Persistence ver: <span id="persistence-ver" style="text-shadow: 0 0 2px white;"></span></td>' +
+            '<td><div class="statusbar-separator"></div></td>' +
+
+            '<td style="padding-left: 5px; min-width:120px">Alerts: <b id="alerts" style="text-shadow: 0 0 2px white;"></b></td>' +
 
 
             '</tr></table></div>';
@@ -151,6 +155,29 @@ class HtmlStatusBar {
     }
 
 
+    public static updateAlerts(alerts: IAlert[]) {
+
+        if (!this.alerts) {
+            this.alerts = document.getElementById('alerts');
+        }
+
+        if (!alerts) {
+            alerts = [];
+        }
+
+        let title = "";
+
+        for (let alert of alerts) {
+            title += alert.rule + ' ' + alert.target + ': ' + alert.message + '\n';
+        }
+
+        let color = alerts.length > 0 ? 'red' : 'green';
+
+        this.alerts.innerHTML = '<span style="color: ' + color + '">' + alerts.length + '</span>';
+        this.alerts.title = title;
+    }
+
+
     public static updateOffline() {
         if (this.connected) {
             this.connected = false;
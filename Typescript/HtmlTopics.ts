@@ -30,7 +30,9 @@ class HtmlTopics {
         let msgPerSecColor = topic.messagesPerSec > 0 ? "white" : "gray";
         let packetsPerSecColor = topic.packetPerSec > 0 ? "white" : "gray";
 
-        return '<div>MsgId:' + Utils.highlightPageOfMessageId(topic.messageId.toString()) + '</div>' +
+        let readOnlyBadge = topic.readOnly ? '<div><span class="badge badge-danger">read-only</span></div>' : '';
+
+        return readOnlyBadge + '<div>MsgId:' + Utils.highlightPageOfMessageId(topic.messageId.toString()) + '</div>' +
             '<div>Msg/sec: <span style="color:' + msgPerSecColor + '">' + topic.messagesPerSec + '</span></div>' +
             '<div>Req/sec: <span style="color:' + packetsPerSecColor + '">' + topic.packetPerSec + '</span></div>' +
             '<div>Persist queue:<span style="color:' + queuesizeColor + '">' + topic.persistSize + '</span></div>' +
@@ -4,7 +4,30 @@ interface IStatusApiContract {
     queues: object,
     sessions: ISessions
     system: ISystemStatus,
-    persistenceVersion: string
+    persistenceVersion: string,
+    alerts: IAlert[]
+}
+
+interface IAlert {
+    id: number,
+    rule: string,
+    type: string,
+    target: string,
+    status: string,
+    message: string,
+    started: string,
+    resolved: string
+}
+
+interface IStatusDeltaContract {
+    topicsSnapshotId: number,
+    topics: ITopic[],
+    removedTopics: string[],
+    queues: object,
+    sessions: ISessions,
+    system: ISystemStatus,
+    persistenceVersion: string,
+    alerts: IAlert[]
 }
 
 interface ISession {
@@ -18,6 +41,13 @@ interface ISession {
     writtenSize: number,
     readPerSec: number,
     writtenPerSec: number,
+    packets: { [packet: string]: number },
+    published: number,
+    rejects: number,
+    delivered: number,
+    confirmations: number,
+    confirmed: number,
+    avgConfirmationMicroseconds: number,
 }
 
 interface ISessions {
@@ -44,7 +74,8 @@ interface ITopic {
     publishHistory: number[],
     pages: IPage[],
     publishers: ITopicPublisherApiContract[],
-    subscribers: ISubscriberApiContract[]
+    subscribers: ISubscriberApiContract[],
+    readOnly: boolean
 }
 
 interface IPage {
@@ -65,6 +96,9 @@ interface ITopicQueue {
     queueType: number,
     size: number,
     onDelivery: number,
+    paused: boolean,
+    lagMessages: number,
+    lagSeconds?: number,
     data: IQueueIndexRange[]
 }
 
@@ -6,6 +6,10 @@ class main {
     private static statusBarElement: HTMLElement;
     private static requested = false;
 
+    private static statusStream: EventSource;
+    private static streamIsActive = false;
+    private static status: IStatusApiContract;
+
     private static windowHeight: number;
     private static windowWidth: number;
 
@@ -66,6 +70,11 @@ class main {
 
         this.resize();
 
+        if (!this.statusStream)
+            this.startStatusStream();
+
+        if (this.streamIsActive)
+            return;
 
         if (this.requested)
             return;
@@ -76,58 +85,138 @@ class main {
         $.ajax({ url: '/status', type: 'get' })
             .then((result: IStatusApiContract) => {
                 this.requested = false;
+                this.render(result);
+            }).fail(() => {
+                this.requested = false;
+                HtmlStatusBar.updateOffline();
+            })
 
-                let filterPhrase = (<HTMLInputElement>document.getElementById('filter')).value;
+    }
 
-                filterPhrase == filterPhrase.trim();
+    static startStatusStream() {
+        if (typeof EventSource === "undefined")
+            return;
 
-                let filterPhraseIsChanged = ServiceLocator.checkIfFilterPhraseIsChanged(filterPhrase);
+        let url = '/Status/Stream';
 
-                if (filterPhraseIsChanged) {
-                    console.log("filterPhraseIsChanged");
-                }
+        this.statusStream = new EventSource(url);
 
-                let topics_are_changed = ServiceLocator.checkIfTopicsAreChanged(result.topics);
+        this.statusStream.addEventListener('snapshot', (e: MessageEvent) => {
+            this.streamIsActive = true;
+            this.status = JSON.parse(e.data);
+            this.render(this.status);
+        });
 
-                if (topics_are_changed) {
-                    console.log("topics_are_changed");
-                }
+        this.statusStream.addEventListener('delta', (e: MessageEvent) => {
+            this.streamIsActive = true;
+            this.status = this.applyStatusDelta(this.status, JSON.parse(e.data));
+            this.render(this.status);
+        });
 
-                if (topics_are_changed || filterPhraseIsChanged) {
-                    this.topicsElement.innerHTML = HtmlTopics.renderTopics(result.topics);
-                    ServiceLocator.topics = result.topics;
-                }
-                else {
-                    HtmlTopics.updateTopicData(result.topics);
-                }
+        this.statusStream.onerror = () => {
+            this.streamIsActive = false;
+        };
+    }
 
-                if (ServiceLocator.checkIfSessionsAreChanged(result.sessions)) {
-                    this.connectionsElement.innerHTML = HtmlSessions.renderSessions(result);
-                    ServiceLocator.sessions = result.sessions;
-                }
-                else {
-                    HtmlSessions.updateSessionData(result);
+    static applyStatusDelta(status: IStatusApiContract, delta: IStatusDeltaContract): IStatusApiContract {
+
+        if (!status) {
+            status = {
+                topics: { snapshotId: 0, items: [] },
+                queues: {},
+                sessions: { snapshotId: 0, items: [] },
+                system: delta.system,
+                persistenceVersion: delta.persistenceVersion,
+                alerts: delta.alerts
+            };
+        }
+
+        let topics: ITopic[] = [];
+
+        for (let topic of status.topics.items) {
+            if (delta.removedTopics.indexOf(topic.id) < 0)
+                topics.push(topic);
+        }
+
+        for (let topic of delta.topics) {
+            let found = false;
+            for (let i = 0; i < topics.length; i++) {
+                if (topics[i].id == topic.id) {
+                    topics[i] = topic;
+                    found = true;
                 }
+            }
 
-                HtmlTopics.updateTopicSessions(result);
+            if (!found)
+                topics.push(topic);
+        }
 
-                HtmlTopics.updateTopicQueues(result);
+        let queues = status.queues;
 
-                HtmlStatusBar.updateStatusbar(result);
+        for (let topicId of delta.removedTopics) {
+            delete queues[topicId];
+        }
 
-                HtmlStatusBar.updateSessionsAmount(result.sessions.items.length);
+        for (let topicId in delta.queues) {
+            queues[topicId] = delta.queues[topicId];
+        }
 
-                HtmlStatusBar.updatePersistenceVersion(result.persistenceVersion);
+        return {
+            topics: { snapshotId: delta.topicsSnapshotId, items: topics },
+            queues: queues,
+            sessions: delta.sessions ? delta.sessions : status.sessions,
+            system: delta.system,
+            persistenceVersion: delta.persistenceVersion,
+            alerts: delta.alerts
+        };
+    }
 
-                this.filterLines(filterPhrase);
+    static render(result: IStatusApiContract) {
+        let filterPhrase = (<HTMLInputElement>document.getElementById('filter')).value;
 
+        filterPhrase == filterPhrase.trim();
 
+        let filterPhraseIsChanged = ServiceLocator.checkIfFilterPhraseIsChanged(filterPhrase);
 
-            }).fail(() => {
-                this.requested = false;
-                HtmlStatusBar.updateOffline();
-            })
+        if (filterPhraseIsChanged) {
+            console.log("filterPhraseIsChanged");
+        }
+
+        let topics_are_changed = ServiceLocator.checkIfTopicsAreChanged(result.topics);
+
+        if (topics_are_changed) {
+            console.log("topics_are_changed");
+        }
+
+        if (topics_are_changed || filterPhraseIsChanged) {
+            this.topicsElement.innerHTML = HtmlTopics.renderTopics(result.topics);
+            ServiceLocator.topics = result.topics;
+        }
+        else {
+            HtmlTopics.updateTopicData(result.topics);
+        }
+
+        if (ServiceLocator.checkIfSessionsAreChanged(result.sessions)) {
+            this.connectionsElement.innerHTML = HtmlSessions.renderSessions(result);
+            ServiceLocator.sessions = result.sessions;
+        }
+        else {
+            HtmlSessions.updateSessionData(result);
+        }
+
+        HtmlTopics.updateTopicSessions(result);
+
+        HtmlTopics.updateTopicQueues(result);
+
+        HtmlStatusBar.updateStatusbar(result);
+
+        HtmlStatusBar.updateSessionsAmount(result.sessions.items.length);
+
+        HtmlStatusBar.updatePersistenceVersion(result.persistenceVersion);
+
+        HtmlStatusBar.updateAlerts(result.alerts);
 
+        this.filterLines(filterPhrase);
     }
 
 }
@@ -1,3 +1,4 @@
 fn main() {
     tonic_build::compile_protos("proto/MyServicePersistenceGrpcService.proto").unwrap();
+    tonic_build::compile_protos("proto/MyServiceBusGrpcService.proto").unwrap();
 }
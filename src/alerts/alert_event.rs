@@ -0,0 +1,28 @@
+use rust_extensions::date_time::DateTimeAsMicroseconds;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AlertStatus {
+    Firing,
+    Resolved,
+}
+
+impl AlertStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AlertStatus::Firing => "Firing",
+            AlertStatus::Resolved => "Resolved",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AlertEvent {
+    pub id: u64,
+    pub rule: String,
+    pub rule_type: &'static str,
+    pub target: String,
+    pub status: AlertStatus,
+    pub message: String,
+    pub started: DateTimeAsMicroseconds,
+    pub resolved: Option<DateTimeAsMicroseconds>,
+}
@@ -0,0 +1,108 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct AlertsSettingsJson {
+    #[serde(rename = "Webhooks", default)]
+    pub webhooks: Vec<String>,
+
+    #[serde(rename = "Rules", default)]
+    pub rules: Vec<AlertRuleJson>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AlertRuleJson {
+    #[serde(rename = "Name")]
+    pub name: String,
+
+    #[serde(rename = "Type")]
+    pub rule_type: String,
+
+    #[serde(rename = "TopicId")]
+    pub topic_id: Option<String>,
+
+    #[serde(rename = "QueueId")]
+    pub queue_id: Option<String>,
+
+    #[serde(rename = "Threshold")]
+    pub threshold: Option<i64>,
+
+    #[serde(rename = "ForSeconds", default)]
+    pub for_seconds: u64,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum AlertRuleType {
+    QueueSize { threshold: i64 },
+    NoSubscribers,
+    PersistenceFailing,
+    SubscriberKicked,
+}
+
+impl AlertRuleType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AlertRuleType::QueueSize { .. } => "QueueSize",
+            AlertRuleType::NoSubscribers => "NoSubscribers",
+            AlertRuleType::PersistenceFailing => "PersistenceFailing",
+            AlertRuleType::SubscriberKicked => "SubscriberKicked",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AlertRule {
+    pub name: String,
+    pub rule_type: AlertRuleType,
+    pub topic_id: Option<String>,
+    pub queue_id: Option<String>,
+    pub for_duration: Duration,
+}
+
+impl AlertRule {
+    pub fn parse(src: &AlertRuleJson) -> Result<Self, String> {
+        let rule_type = match src.rule_type.as_str() {
+            "QueueSize" => {
+                let threshold = src.threshold.ok_or_else(|| {
+                    format!("Alert rule '{}' of type QueueSize requires Threshold", src.name)
+                })?;
+
+                AlertRuleType::QueueSize { threshold }
+            }
+            "NoSubscribers" => AlertRuleType::NoSubscribers,
+            "PersistenceFailing" => AlertRuleType::PersistenceFailing,
+            "SubscriberKicked" => AlertRuleType::SubscriberKicked,
+            _ => {
+                return Err(format!(
+                    "Invalid type '{}' of alert rule '{}'. Supported types: QueueSize, NoSubscribers, PersistenceFailing, SubscriberKicked",
+                    src.rule_type, src.name
+                ))
+            }
+        };
+
+        Ok(Self {
+            name: src.name.to_string(),
+            rule_type,
+            topic_id: src.topic_id.clone(),
+            queue_id: src.queue_id.clone(),
+            for_duration: Duration::from_secs(src.for_seconds),
+        })
+    }
+
+    pub fn matches_queue(&self, topic_id: &str, queue_id: &str) -> bool {
+        if let Some(rule_topic_id) = &self.topic_id {
+            if rule_topic_id != topic_id {
+                return false;
+            }
+        }
+
+        if let Some(rule_queue_id) = &self.queue_id {
+            if rule_queue_id != queue_id {
+                return false;
+            }
+        }
+
+        true
+    }
+}
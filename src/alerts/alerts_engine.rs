@@ -0,0 +1,312 @@
+use std::{
+    collections::{BTreeMap, HashMap, HashSet, VecDeque},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+use rust_extensions::date_time::DateTimeAsMicroseconds;
+
+use super::{AlertEvent, AlertRule, AlertRuleType, AlertStatus};
+
+const MAX_EVENTS_IN_HISTORY: usize = 1_000;
+
+//Kick is a moment. Alert stays active for a while so it can be seen in the UI
+const SUBSCRIBER_KICKED_WINDOW: Duration = Duration::from_secs(60);
+
+pub struct AlertCondition {
+    pub rule_index: usize,
+    pub target: String,
+    pub message: String,
+}
+
+type AlertKey = (usize, String);
+
+struct AlertsEngineData {
+    pending: HashMap<AlertKey, DateTimeAsMicroseconds>,
+    active: BTreeMap<AlertKey, AlertEvent>,
+    last_kicks: HashMap<AlertKey, DateTimeAsMicroseconds>,
+    history: VecDeque<AlertEvent>,
+    next_id: u64,
+}
+
+impl AlertsEngineData {
+    fn kick_window_is_open(&self, key: &AlertKey, now: DateTimeAsMicroseconds) -> bool {
+        match self.last_kicks.get(key) {
+            Some(last_kick) => {
+                now.duration_since(*last_kick).as_positive_or_zero() < SUBSCRIBER_KICKED_WINDOW
+            }
+            None => false,
+        }
+    }
+
+    fn add_event(
+        &mut self,
+        rule: &AlertRule,
+        target: String,
+        message: String,
+        started: DateTimeAsMicroseconds,
+    ) -> AlertEvent {
+        let event = AlertEvent {
+            id: self.next_id,
+            rule: rule.name.to_string(),
+            rule_type: rule.rule_type.as_str(),
+            target,
+            status: AlertStatus::Firing,
+            message,
+            started,
+            resolved: None,
+        };
+
+        self.next_id += 1;
+        self.add_to_history(event.clone());
+
+        event
+    }
+
+    fn add_to_history(&mut self, event: AlertEvent) {
+        self.history.push_back(event);
+
+        while self.history.len() > MAX_EVENTS_IN_HISTORY {
+            self.history.pop_front();
+        }
+    }
+}
+
+pub struct AlertsEngine {
+    rules: Vec<AlertRule>,
+    pub webhooks: Vec<String>,
+    pub service_name: String,
+    persistence_is_failing: AtomicBool,
+    data: Mutex<AlertsEngineData>,
+}
+
+impl AlertsEngine {
+    pub fn new(rules: Vec<AlertRule>, webhooks: Vec<String>, service_name: String) -> Self {
+        Self {
+            rules,
+            webhooks,
+            service_name,
+            persistence_is_failing: AtomicBool::new(false),
+            data: Mutex::new(AlertsEngineData {
+                pending: HashMap::new(),
+                active: BTreeMap::new(),
+                last_kicks: HashMap::new(),
+                history: VecDeque::new(),
+                next_id: 1,
+            }),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        !self.rules.is_empty()
+    }
+
+    pub fn get_rules(&self) -> &[AlertRule] {
+        self.rules.as_slice()
+    }
+
+    pub fn set_persistence_state(&self, is_ok: bool) {
+        self.persistence_is_failing.store(!is_ok, Ordering::SeqCst);
+    }
+
+    pub fn persistence_is_failing(&self) -> bool {
+        self.persistence_is_failing.load(Ordering::SeqCst)
+    }
+
+    // Conditions which are observed now. Alert fires once condition holds for the rule duration
+    // and resolves as soon as condition is not observed anymore.
+    // SubscriberKicked alert resolves when no kicks happened during the kick window
+    pub fn evaluate(
+        &self,
+        conditions: Vec<AlertCondition>,
+        now: DateTimeAsMicroseconds,
+    ) -> Vec<AlertEvent> {
+        let mut result = Vec::new();
+        let mut data = self.data.lock().unwrap();
+
+        let mut observed = HashSet::new();
+
+        for condition in conditions {
+            let key = (condition.rule_index, condition.target);
+            observed.insert(key.clone());
+
+            if data.active.contains_key(&key) {
+                continue;
+            }
+
+            let since = *data.pending.entry(key.clone()).or_insert(now);
+
+            let rule = &self.rules[condition.rule_index];
+
+            if now.duration_since(since).as_positive_or_zero() >= rule.for_duration {
+                data.pending.remove(&key);
+
+                let event = data.add_event(rule, key.1.to_string(), condition.message, since);
+                data.active.insert(key, event.clone());
+                result.push(event);
+            }
+        }
+
+        data.pending.retain(|key, _| observed.contains(key));
+
+        let resolved: Vec<AlertKey> = data
+            .active
+            .keys()
+            .filter(|key| !observed.contains(*key) && !data.kick_window_is_open(key, now))
+            .cloned()
+            .collect();
+
+        for key in resolved {
+            data.last_kicks.remove(&key);
+
+            if let Some(mut event) = data.active.remove(&key) {
+                event.status = AlertStatus::Resolved;
+                event.resolved = Some(now);
+
+                data.add_to_history(event.clone());
+                result.push(event);
+            }
+        }
+
+        result
+    }
+
+    pub fn subscriber_is_kicked(
+        &self,
+        topic_id: &str,
+        queue_id: &str,
+        message: String,
+    ) -> Vec<AlertEvent> {
+        self.subscriber_is_kicked_at(topic_id, queue_id, message, DateTimeAsMicroseconds::now())
+    }
+
+    fn subscriber_is_kicked_at(
+        &self,
+        topic_id: &str,
+        queue_id: &str,
+        message: String,
+        now: DateTimeAsMicroseconds,
+    ) -> Vec<AlertEvent> {
+        let mut result = Vec::new();
+
+        let mut data = self.data.lock().unwrap();
+
+        for (rule_index, rule) in self.rules.iter().enumerate() {
+            if let AlertRuleType::SubscriberKicked = rule.rule_type {
+                if !rule.matches_queue(topic_id, queue_id) {
+                    continue;
+                }
+
+                let key = (rule_index, format!("{}/{}", topic_id, queue_id));
+                data.last_kicks.insert(key.clone(), now);
+
+                //Alert is already active. Next kicks only prolong it
+                if data.active.contains_key(&key) {
+                    continue;
+                }
+
+                let event = data.add_event(rule, key.1.to_string(), message.to_string(), now);
+                data.active.insert(key, event.clone());
+                result.push(event);
+            }
+        }
+
+        result
+    }
+
+    pub fn get_active(&self) -> Vec<AlertEvent> {
+        let data = self.data.lock().unwrap();
+        data.active.values().cloned().collect()
+    }
+
+    pub fn get_history(&self, limit: usize) -> Vec<AlertEvent> {
+        let data = self.data.lock().unwrap();
+        data.history.iter().rev().take(limit).cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn queue_size_rule(for_seconds: u64) -> AlertRule {
+        AlertRule {
+            name: "big-queue".to_string(),
+            rule_type: AlertRuleType::QueueSize { threshold: 10 },
+            topic_id: None,
+            queue_id: None,
+            for_duration: Duration::from_secs(for_seconds),
+        }
+    }
+
+    fn at(started: DateTimeAsMicroseconds, seconds: i64) -> DateTimeAsMicroseconds {
+        DateTimeAsMicroseconds::new(started.unix_microseconds + seconds * 1_000_000)
+    }
+
+    fn condition() -> AlertCondition {
+        AlertCondition {
+            rule_index: 0,
+            target: "topic/queue".to_string(),
+            message: "Queue size is 11".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_alert_fires_after_duration_and_resolves() {
+        let engine = AlertsEngine::new(vec![queue_size_rule(5)], vec![], "test".to_string());
+
+        let started = DateTimeAsMicroseconds::now();
+        assert_eq!(0, engine.evaluate(vec![condition()], started).len());
+        assert_eq!(0, engine.evaluate(vec![condition()], at(started, 3)).len());
+
+        let events = engine.evaluate(vec![condition()], at(started, 5));
+        assert_eq!(1, events.len());
+        assert_eq!(AlertStatus::Firing, events[0].status);
+        assert_eq!(1, engine.get_active().len());
+
+        assert_eq!(0, engine.evaluate(vec![condition()], at(started, 6)).len());
+
+        let events = engine.evaluate(vec![], at(started, 7));
+        assert_eq!(1, events.len());
+        assert_eq!(AlertStatus::Resolved, events[0].status);
+        assert_eq!(0, engine.get_active().len());
+        assert_eq!(2, engine.get_history(10).len());
+    }
+
+    #[test]
+    fn test_subscriber_kicked_alert_is_active_during_kick_window() {
+        let rule = AlertRule {
+            name: "kicked".to_string(),
+            rule_type: AlertRuleType::SubscriberKicked,
+            topic_id: None,
+            queue_id: None,
+            for_duration: Duration::from_secs(0),
+        };
+
+        let engine = AlertsEngine::new(vec![rule], vec![], "test".to_string());
+
+        let started = DateTimeAsMicroseconds::now();
+
+        let events =
+            engine.subscriber_is_kicked_at("topic", "queue", "Kicked".to_string(), started);
+        assert_eq!(1, events.len());
+        assert_eq!(1, engine.get_active().len());
+
+        let events =
+            engine.subscriber_is_kicked_at("topic", "queue", "Kicked".to_string(), at(started, 30));
+        assert_eq!(0, events.len());
+
+        assert_eq!(0, engine.evaluate(vec![], at(started, 60)).len());
+        assert_eq!(1, engine.get_active().len());
+
+        let events = engine.evaluate(vec![], at(started, 90));
+        assert_eq!(1, events.len());
+        assert_eq!(AlertStatus::Resolved, events[0].status);
+        assert_eq!(0, engine.get_active().len());
+    }
+}
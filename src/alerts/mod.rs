@@ -0,0 +1,8 @@
+mod alert_event;
+mod alert_rules;
+mod alerts_engine;
+mod webhook;
+pub use alert_event::{AlertEvent, AlertStatus};
+pub use alert_rules::{AlertRule, AlertRuleType, AlertsSettingsJson};
+pub use alerts_engine::{AlertCondition, AlertsEngine};
+pub use webhook::send_webhook;
@@ -0,0 +1,69 @@
+use std::time::Duration;
+
+use hyper::{header::CONTENT_TYPE, Body, Method, Request};
+use serde::Serialize;
+
+use super::AlertEvent;
+
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Serialize, Debug)]
+pub struct AlertWebhookPayload {
+    pub service: String,
+    pub id: u64,
+    pub rule: String,
+    #[serde(rename = "type")]
+    pub rule_type: String,
+    pub target: String,
+    pub status: String,
+    pub message: String,
+    #[serde(rename = "startedAt")]
+    pub started_at: String,
+    #[serde(rename = "resolvedAt")]
+    pub resolved_at: Option<String>,
+}
+
+impl AlertWebhookPayload {
+    pub fn new(service: &str, event: &AlertEvent) -> Self {
+        Self {
+            service: service.to_string(),
+            id: event.id,
+            rule: event.rule.to_string(),
+            rule_type: event.rule_type.to_string(),
+            target: event.target.to_string(),
+            status: event.status.as_str().to_string(),
+            message: event.message.to_string(),
+            started_at: event.started.to_rfc3339(),
+            resolved_at: event.resolved.map(|resolved| resolved.to_rfc3339()),
+        }
+    }
+}
+
+pub async fn send_webhook(url: &str, service: &str, event: &AlertEvent) -> Result<(), String> {
+    let body = serde_json::to_vec(&AlertWebhookPayload::new(service, event))
+        .map_err(|err| format!("Can not serialize alert: {:?}", err))?;
+
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri(url)
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(body))
+        .map_err(|err| format!("Can not build webhook request: {:?}", err))?;
+
+    let response = tokio::time::timeout(
+        WEBHOOK_TIMEOUT,
+        crate::utils::create_http_client().request(request),
+    )
+    .await
+    .map_err(|_| format!("Webhook did not respond within {:?}", WEBHOOK_TIMEOUT))?
+    .map_err(|err| format!("Can not send alert: {:?}", err))?;
+
+    if !response.status().is_success() {
+        return Err(format!(
+            "Webhook responded with status {}",
+            response.status()
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,861 @@
+use std::{collections::HashMap, net::SocketAddr, sync::Arc, time::Duration};
+
+use my_service_bus_shared::queue::TopicQueueType;
+use my_service_bus_tcp_shared::MessageToPublishTcpContract;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{
+        tcp::{OwnedReadHalf, OwnedWriteHalf},
+        TcpStream,
+    },
+    sync::mpsc::UnboundedReceiver,
+};
+
+use crate::{
+    app::{logs::SystemProcess, AppContext},
+    operations::{self, OperationFailResult},
+    sessions::{AmqpConnectionData, MyServiceBusSession},
+};
+
+use super::{
+    frames::{
+        self, AmqpError, AmqpFrame, FRAME_BODY, FRAME_HEADER, FRAME_HEARTBEAT, FRAME_METHOD,
+        PROTOCOL_HEADER,
+    },
+    methods::{self, AmqpContentHeader, AmqpMethod},
+    AmqpConfirmation, AmqpConfirmationResult, AmqpConsumer,
+};
+
+const FRAME_MAX: u32 = 131072;
+const FRAME_MIN_SIZE: u32 = 4096;
+
+struct AmqpHandshake {
+    name: Option<String>,
+    version: Option<String>,
+    frame_max: usize,
+    heartbeat: u16,
+}
+
+struct PendingPublish {
+    topic_id: String,
+    header: Option<AmqpContentHeader>,
+    content: Vec<u8>,
+}
+
+struct AmqpQueue {
+    topic_id: Option<String>,
+    queue_type: TopicQueueType,
+}
+
+pub async fn serve(app: Arc<AppContext>, tcp_stream: TcpStream, addr: SocketAddr) {
+    let (mut read_half, mut write_half) = tcp_stream.into_split();
+
+    let handshake = match handshake(&mut read_half, &mut write_half).await {
+        Ok(handshake) => handshake,
+        Err(err) => {
+            app.logs.add_error(
+                None,
+                SystemProcess::TcpSocket,
+                "AmqpHandshake".to_string(),
+                format!("Err: {:?}", err),
+                Some(format!("Addr:{}", addr)),
+            );
+            return;
+        }
+    };
+
+    let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+    let (confirmations_sender, confirmations_receiver) = tokio::sync::mpsc::unbounded_channel();
+
+    let session = app
+        .sessions
+        .add_amqp(AmqpConnectionData::new(
+            handshake.name,
+            handshake.version,
+            addr,
+            handshake.frame_max,
+            sender,
+            confirmations_sender,
+        ))
+        .await;
+
+    tokio::spawn(write_loop(write_half, receiver, handshake.heartbeat));
+    tokio::spawn(confirmations_loop(app.clone(), confirmations_receiver));
+
+    let read_timeout = if handshake.heartbeat > 0 {
+        Some(Duration::from_secs(handshake.heartbeat as u64 * 2))
+    } else {
+        None
+    };
+
+    let mut connection = AmqpConnection::new(app.clone(), session.clone());
+
+    let data = session.connection.unwrap_as_amqp();
+    let frame_max = handshake.frame_max;
+
+    loop {
+        let read_future = async {
+            match read_timeout {
+                Some(read_timeout) => {
+                    let read_future = frames::read_frame(&mut read_half, frame_max);
+                    match tokio::time::timeout(read_timeout, read_future).await {
+                        Ok(frame) => frame,
+                        Err(_) => Err(AmqpError::Protocol(
+                            "Missed heartbeats from client".to_string(),
+                        )),
+                    }
+                }
+                None => frames::read_frame(&mut read_half, frame_max).await,
+            }
+        };
+
+        let frame = tokio::select! {
+            _ = data.disconnected.notified() => break,
+            frame = read_future => frame,
+        };
+
+        let result = match frame {
+            Ok(frame) => connection.handle_frame(frame).await,
+            Err(err) => Err(err),
+        };
+
+        match result {
+            Ok(true) => {}
+            Ok(false) => break,
+            Err(AmqpError::Io(_)) => break,
+            Err(AmqpError::Protocol(reason)) => {
+                connection.send(methods::connection_close(
+                    methods::REPLY_INTERNAL_ERROR,
+                    reason.as_str(),
+                ));
+
+                app.logs.add_error(
+                    None,
+                    SystemProcess::TcpSocket,
+                    "AmqpConnection".to_string(),
+                    reason,
+                    Some(format!("SessionId:{}", session.id)),
+                );
+                break;
+            }
+        }
+    }
+
+    session.disconnect().await;
+    if let Some(session) = app.sessions.remove_amqp(session.id).await {
+        operations::sessions::disconnect(app.as_ref(), session.as_ref()).await;
+    }
+}
+
+async fn handshake(
+    read_half: &mut OwnedReadHalf,
+    write_half: &mut OwnedWriteHalf,
+) -> Result<AmqpHandshake, AmqpError> {
+    let mut protocol_header = [0u8; 8];
+    read_half.read_exact(&mut protocol_header).await?;
+
+    if &protocol_header != PROTOCOL_HEADER {
+        write_half.write_all(PROTOCOL_HEADER).await?;
+        return Err(AmqpError::Protocol(format!(
+            "Unsupported protocol header: {:?}",
+            protocol_header
+        )));
+    }
+
+    write_half
+        .write_all(&methods::connection_start(crate::app::APP_VERSION).serialize())
+        .await?;
+
+    let mut client_properties = match read_method(read_half).await? {
+        AmqpMethod::ConnectionStartOk { client_properties } => client_properties,
+        _ => return Err(unexpected_method("connection.start-ok")),
+    };
+
+    write_half
+        .write_all(&methods::connection_tune(FRAME_MAX).serialize())
+        .await?;
+
+    let (frame_max, heartbeat) = match read_method(read_half).await? {
+        AmqpMethod::ConnectionTuneOk {
+            frame_max,
+            heartbeat,
+        } => (frame_max, heartbeat),
+        _ => return Err(unexpected_method("connection.tune-ok")),
+    };
+
+    match read_method(read_half).await? {
+        AmqpMethod::ConnectionOpen => {}
+        _ => return Err(unexpected_method("connection.open")),
+    }
+
+    write_half
+        .write_all(&methods::connection_open_ok().serialize())
+        .await?;
+
+    if frame_max != 0 && frame_max < FRAME_MIN_SIZE {
+        return Err(AmqpError::Protocol(format!(
+            "Frame max {} is less than minimum {}",
+            frame_max, FRAME_MIN_SIZE
+        )));
+    }
+
+    let frame_max = if frame_max == 0 || frame_max > FRAME_MAX {
+        FRAME_MAX
+    } else {
+        frame_max
+    };
+
+    let name = match client_properties.remove("connection_name") {
+        Some(name) => Some(name),
+        None => client_properties.remove("product"),
+    };
+
+    Ok(AmqpHandshake {
+        name,
+        version: client_properties.remove("version"),
+        frame_max: frame_max as usize,
+        heartbeat,
+    })
+}
+
+async fn read_method(read_half: &mut OwnedReadHalf) -> Result<AmqpMethod, AmqpError> {
+    loop {
+        let frame = frames::read_frame(read_half, FRAME_MAX as usize).await?;
+
+        match frame.frame_type {
+            FRAME_METHOD => return AmqpMethod::parse(&frame.payload),
+            FRAME_HEARTBEAT => continue,
+            _ => {
+                return Err(AmqpError::Protocol(format!(
+                    "Unexpected frame type {} during handshake",
+                    frame.frame_type
+                )))
+            }
+        }
+    }
+}
+
+fn unexpected_method(expected: &str) -> AmqpError {
+    AmqpError::Protocol(format!("Expected {} method", expected))
+}
+
+async fn write_loop(
+    mut write_half: OwnedWriteHalf,
+    mut receiver: UnboundedReceiver<Vec<u8>>,
+    heartbeat: u16,
+) {
+    let heartbeat_frame = AmqpFrame::new(FRAME_HEARTBEAT, 0, Vec::new()).serialize();
+
+    let heartbeat_interval = Duration::from_secs((heartbeat as u64 / 2).max(1));
+    let mut heartbeat_timer = tokio::time::interval(heartbeat_interval);
+
+    loop {
+        let payload = tokio::select! {
+            payload = receiver.recv() => match payload {
+                Some(payload) => payload,
+                None => break,
+            },
+            _ = heartbeat_timer.tick(), if heartbeat > 0 => heartbeat_frame.clone(),
+        };
+
+        if write_half.write_all(&payload).await.is_err() {
+            break;
+        }
+    }
+
+    let _ = write_half.shutdown().await;
+}
+
+async fn confirmations_loop(
+    app: Arc<AppContext>,
+    mut receiver: UnboundedReceiver<AmqpConfirmation>,
+) {
+    while let Some(confirmation) = receiver.recv().await {
+        apply_confirmation(&app, confirmation).await;
+    }
+}
+
+async fn apply_confirmation(app: &Arc<AppContext>, confirmation: AmqpConfirmation) {
+    let topic_id = confirmation.topic_id.as_str();
+    let queue_id = confirmation.queue_id.as_str();
+
+    let result = match confirmation.result {
+        AmqpConfirmationResult::AllConfirmed => {
+            operations::delivery_confirmation::all_confirmed(
+                app,
+                topic_id,
+                queue_id,
+                confirmation.confirmation_id,
+            )
+            .await
+        }
+        AmqpConfirmationResult::AllFail => {
+            operations::delivery_confirmation::all_fail(
+                app,
+                topic_id,
+                queue_id,
+                confirmation.confirmation_id,
+            )
+            .await
+        }
+        AmqpConfirmationResult::SomeConfirmed(confirmed) => {
+            operations::delivery_confirmation::some_messages_are_confirmed(
+                app,
+                topic_id,
+                queue_id,
+                confirmation.confirmation_id,
+                confirmed,
+            )
+            .await
+        }
+    };
+
+    if let Err(err) = result {
+        app.logs.add_error(
+            Some(topic_id.to_string()),
+            SystemProcess::DeliveryOperation,
+            "AmqpConfirmation".to_string(),
+            format!("Err: {:?}", err),
+            Some(format!(
+                "QueueId:{}. ConfirmationId:{}",
+                queue_id, confirmation.confirmation_id
+            )),
+        );
+    }
+}
+
+struct AmqpConnection {
+    app: Arc<AppContext>,
+    session: Arc<MyServiceBusSession>,
+    queues: HashMap<String, AmqpQueue>,
+    pending_publishes: HashMap<u16, PendingPublish>,
+    last_consumer_tag: u64,
+}
+
+impl AmqpConnection {
+    fn new(app: Arc<AppContext>, session: Arc<MyServiceBusSession>) -> Self {
+        Self {
+            app,
+            session,
+            queues: HashMap::new(),
+            pending_publishes: HashMap::new(),
+            last_consumer_tag: 0,
+        }
+    }
+
+    fn get_data(&self) -> &AmqpConnectionData {
+        self.session.connection.unwrap_as_amqp()
+    }
+
+    fn send(&self, frame: AmqpFrame) {
+        self.get_data().send_payload(frame.serialize());
+    }
+
+    async fn handle_frame(&mut self, frame: AmqpFrame) -> Result<bool, AmqpError> {
+        self.get_data().update_read_amount(frame.payload.len() + 8);
+
+        match frame.frame_type {
+            FRAME_METHOD => {
+                let method = AmqpMethod::parse(&frame.payload)?;
+                self.handle_method(frame.channel, method).await
+            }
+            FRAME_HEADER => {
+                let header = AmqpContentHeader::parse(&frame.payload)?;
+                let body_size = header.body_size;
+
+                let max_body_size = self.app.get_max_delivery_size() as u64;
+
+                if body_size > max_body_size {
+                    return Err(AmqpError::Protocol(format!(
+                        "Content body size {} exceeds max size {}",
+                        body_size, max_body_size
+                    )));
+                }
+
+                match self.pending_publishes.get_mut(&frame.channel) {
+                    Some(pending) if pending.header.is_none() => {
+                        pending.header = Some(header);
+                    }
+                    _ => return Err(AmqpError::Protocol("Unexpected content header".to_string())),
+                }
+
+                if body_size == 0 {
+                    self.publish_pending(frame.channel).await;
+                }
+
+                Ok(true)
+            }
+            FRAME_BODY => {
+                let completed = match self.pending_publishes.get_mut(&frame.channel) {
+                    Some(PendingPublish {
+                        header: Some(header),
+                        content,
+                        ..
+                    }) => {
+                        if (content.len() + frame.payload.len()) as u64 > header.body_size {
+                            return Err(AmqpError::Protocol(
+                                "Content body exceeds declared body size".to_string(),
+                            ));
+                        }
+
+                        content.extend_from_slice(&frame.payload);
+                        content.len() as u64 == header.body_size
+                    }
+                    _ => return Err(AmqpError::Protocol("Unexpected content body".to_string())),
+                };
+
+                if completed {
+                    self.publish_pending(frame.channel).await;
+                }
+
+                Ok(true)
+            }
+            FRAME_HEARTBEAT => Ok(true),
+            _ => Err(AmqpError::Protocol(format!(
+                "Unknown frame type {}",
+                frame.frame_type
+            ))),
+        }
+    }
+
+    async fn handle_method(&mut self, channel: u16, method: AmqpMethod) -> Result<bool, AmqpError> {
+        match method {
+            AmqpMethod::ConnectionClose => {
+                self.send(methods::connection_close_ok());
+                return Ok(false);
+            }
+            AmqpMethod::ConnectionCloseOk => {
+                return Ok(false);
+            }
+            AmqpMethod::ChannelOpen => {
+                self.send(methods::channel_open_ok(channel));
+            }
+            AmqpMethod::ChannelClose => {
+                self.pending_publishes.remove(&channel);
+                self.cancel_channel_consumers(channel).await;
+                self.send(methods::channel_close_ok(channel));
+            }
+            AmqpMethod::ChannelCloseOk => {}
+            AmqpMethod::ExchangeDeclare { exchange, no_wait } => {
+                if !is_default_exchange(exchange.as_str()) {
+                    let result = operations::publisher::create_topic_if_not_exists(
+                        &self.app,
+                        Some(self.session.id),
+                        exchange.as_str(),
+                    )
+                    .await;
+
+                    if let Err(err) = result {
+                        self.close_channel(channel, err).await;
+                        return Ok(true);
+                    }
+                }
+
+                if !no_wait {
+                    self.send(methods::exchange_declare_ok(channel));
+                }
+            }
+            AmqpMethod::QueueDeclare {
+                queue,
+                durable,
+                exclusive,
+                auto_delete,
+                no_wait,
+            } => {
+                let queue = if queue.is_empty() {
+                    format!("amq.gen-{}", uuid::Uuid::new_v4())
+                } else {
+                    queue
+                };
+
+                let queue_type = get_queue_type(durable, exclusive, auto_delete);
+
+                match self.queues.get_mut(queue.as_str()) {
+                    Some(amqp_queue) => amqp_queue.queue_type = queue_type,
+                    None => {
+                        self.queues.insert(
+                            queue.to_string(),
+                            AmqpQueue {
+                                topic_id: None,
+                                queue_type,
+                            },
+                        );
+                    }
+                }
+
+                if !no_wait {
+                    self.send(methods::queue_declare_ok(channel, queue.as_str(), 0));
+                }
+            }
+            AmqpMethod::QueueBind {
+                queue,
+                exchange,
+                routing_key,
+                no_wait,
+            } => {
+                let topic_id = get_topic_id(exchange.as_str(), routing_key.as_str());
+
+                let queue_type = match self.queues.get(queue.as_str()) {
+                    Some(AmqpQueue {
+                        topic_id: Some(bound_topic_id),
+                        ..
+                    }) => {
+                        if bound_topic_id != topic_id {
+                            let reply_text = format!(
+                                "Queue {} is already bound to {}. Binding to {} is not supported",
+                                queue, bound_topic_id, topic_id
+                            );
+
+                            self.close_channel_with_reply(
+                                channel,
+                                methods::REPLY_PRECONDITION_FAILED,
+                                reply_text.as_str(),
+                            )
+                            .await;
+                        } else if !no_wait {
+                            self.send(methods::queue_bind_ok(channel));
+                        }
+
+                        return Ok(true);
+                    }
+                    Some(amqp_queue) => amqp_queue.queue_type,
+                    None => TopicQueueType::Permanent,
+                };
+
+                let result = operations::queues::create_queue_if_not_exists(
+                    self.app.as_ref(),
+                    topic_id,
+                    queue.as_str(),
+                    queue_type,
+                    None,
+                )
+                .await;
+
+                if let Err(err) = result {
+                    self.close_channel(channel, err).await;
+                    return Ok(true);
+                }
+
+                self.queues.insert(
+                    queue,
+                    AmqpQueue {
+                        topic_id: Some(topic_id.to_string()),
+                        queue_type,
+                    },
+                );
+
+                if !no_wait {
+                    self.send(methods::queue_bind_ok(channel));
+                }
+            }
+            AmqpMethod::BasicQos => {
+                self.send(methods::basic_qos_ok(channel));
+            }
+            AmqpMethod::BasicConsume {
+                queue,
+                consumer_tag,
+                no_ack,
+                no_wait,
+            } => {
+                self.consume(channel, queue, consumer_tag, no_ack, no_wait)
+                    .await;
+            }
+            AmqpMethod::BasicCancel {
+                consumer_tag,
+                no_wait,
+            } => {
+                let consumer = self
+                    .get_data()
+                    .deliveries
+                    .lock()
+                    .unwrap()
+                    .remove_consumer(channel, consumer_tag.as_str());
+
+                if let Some(consumer) = consumer {
+                    let confirmations = self
+                        .get_data()
+                        .deliveries
+                        .lock()
+                        .unwrap()
+                        .fail_consumer_deliveries(&consumer);
+
+                    self.apply_confirmations(confirmations).await;
+                    self.unsubscribe(consumer).await;
+                }
+
+                if !no_wait {
+                    self.send(methods::basic_cancel_ok(channel, consumer_tag.as_str()));
+                }
+            }
+            AmqpMethod::BasicPublish {
+                exchange,
+                routing_key,
+            } => {
+                // Content of the previous publish is not completed yet
+                if self.pending_publishes.remove(&channel).is_some() {
+                    self.close_channel_with_reply(
+                        channel,
+                        methods::REPLY_PRECONDITION_FAILED,
+                        "basic.publish is received before the content of the previous one",
+                    )
+                    .await;
+
+                    return Ok(true);
+                }
+
+                self.pending_publishes.insert(
+                    channel,
+                    PendingPublish {
+                        topic_id: get_topic_id(exchange.as_str(), routing_key.as_str()).to_string(),
+                        header: None,
+                        content: Vec::new(),
+                    },
+                );
+            }
+            AmqpMethod::BasicAck {
+                delivery_tag,
+                multiple,
+            } => {
+                self.confirm(channel, delivery_tag, multiple, true).await;
+            }
+            AmqpMethod::BasicNack {
+                delivery_tag,
+                multiple,
+            } => {
+                self.confirm(channel, delivery_tag, multiple, false).await;
+            }
+            AmqpMethod::ConnectionStartOk { .. }
+            | AmqpMethod::ConnectionTuneOk { .. }
+            | AmqpMethod::ConnectionOpen => {
+                return Err(AmqpError::Protocol(
+                    "Connection is already opened".to_string(),
+                ));
+            }
+            AmqpMethod::Unsupported {
+                class_id,
+                method_id,
+            } => {
+                self.send(methods::connection_close(
+                    methods::REPLY_NOT_IMPLEMENTED,
+                    format!("Method {}.{} is not supported", class_id, method_id).as_str(),
+                ));
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    async fn consume(
+        &mut self,
+        channel: u16,
+        queue: String,
+        consumer_tag: String,
+        no_ack: bool,
+        no_wait: bool,
+    ) {
+        let (topic_id, queue_type) = match self.resolve_queue(queue.as_str()) {
+            Some(result) => result,
+            None => {
+                self.send(methods::channel_close(
+                    channel,
+                    methods::REPLY_NOT_FOUND,
+                    format!("Queue {} is not bound to any exchange", queue).as_str(),
+                ));
+                return;
+            }
+        };
+
+        let consumer_tag = if consumer_tag.is_empty() {
+            self.last_consumer_tag += 1;
+            format!("amq.ctag-{}", self.last_consumer_tag)
+        } else {
+            consumer_tag
+        };
+
+        let is_first_queue_consumer =
+            self.get_data()
+                .deliveries
+                .lock()
+                .unwrap()
+                .add_consumer(AmqpConsumer {
+                    channel,
+                    consumer_tag: consumer_tag.to_string(),
+                    topic_id: topic_id.to_string(),
+                    queue_id: queue.to_string(),
+                    no_ack,
+                });
+
+        if !no_wait {
+            self.send(methods::basic_consume_ok(channel, consumer_tag.as_str()));
+        }
+
+        // Session is subscribed to the queue once; other consumers of the queue share deliveries
+        if !is_first_queue_consumer {
+            return;
+        }
+
+        let result = operations::subscriber::subscribe_to_queue(
+            &self.app,
+            topic_id,
+            queue,
+            queue_type,
+            &self.session,
+        )
+        .await;
+
+        if let Err(err) = result {
+            self.get_data()
+                .deliveries
+                .lock()
+                .unwrap()
+                .remove_consumer(channel, consumer_tag.as_str());
+
+            self.close_channel(channel, err).await;
+        }
+    }
+
+    // Only queues bound within the connection are consumed. The same queue id may exist in many topics
+    fn resolve_queue(&self, queue_id: &str) -> Option<(String, TopicQueueType)> {
+        let amqp_queue = self.queues.get(queue_id)?;
+        let topic_id = amqp_queue.topic_id.as_ref()?;
+        Some((topic_id.to_string(), amqp_queue.queue_type))
+    }
+
+    async fn publish_pending(&mut self, channel: u16) {
+        let pending = match self.pending_publishes.remove(&channel) {
+            Some(pending) => pending,
+            None => return,
+        };
+
+        let header = match pending.header {
+            Some(header) => header,
+            None => return,
+        };
+
+        let message = MessageToPublishTcpContract {
+            headers: header.headers,
+            content: pending.content,
+        };
+
+        let result = operations::publisher::publish(
+            &self.app,
+            pending.topic_id.as_str(),
+            vec![message],
+            header.delivery_mode == 2,
+            &self.session,
+        )
+        .await;
+
+        if let Err(err) = result {
+            self.close_channel(channel, err).await;
+        }
+    }
+
+    async fn confirm(&self, channel: u16, delivery_tag: u64, multiple: bool, delivered: bool) {
+        let confirmations = self.get_data().deliveries.lock().unwrap().confirm(
+            channel,
+            delivery_tag,
+            multiple,
+            delivered,
+        );
+
+        self.apply_confirmations(confirmations).await;
+    }
+
+    async fn apply_confirmations(&self, confirmations: Vec<AmqpConfirmation>) {
+        for confirmation in confirmations {
+            apply_confirmation(&self.app, confirmation).await;
+        }
+    }
+
+    async fn unsubscribe(&self, consumer: AmqpConsumer) {
+        let has_queue_consumers = self
+            .get_data()
+            .deliveries
+            .lock()
+            .unwrap()
+            .get_consumer(&consumer.topic_id, &consumer.queue_id)
+            .is_some();
+
+        if has_queue_consumers {
+            return;
+        }
+
+        let result = operations::subscriber::unsubscribe_from_queue(
+            &self.app,
+            consumer.topic_id.as_str(),
+            consumer.queue_id.as_str(),
+            self.session.id,
+        )
+        .await;
+
+        if let Err(err) = result {
+            self.app.logs.add_error(
+                Some(consumer.topic_id),
+                SystemProcess::QueueOperation,
+                "AmqpCancel".to_string(),
+                format!("Err: {:?}", err),
+                Some(format!("SessionId:{}", self.session.id)),
+            );
+        }
+    }
+
+    async fn cancel_channel_consumers(&self, channel: u16) {
+        let (consumers, confirmations) = {
+            let mut deliveries = self.get_data().deliveries.lock().unwrap();
+            let confirmations = deliveries.fail_channel_deliveries(channel);
+            (deliveries.remove_channel_consumers(channel), confirmations)
+        };
+
+        self.apply_confirmations(confirmations).await;
+
+        for consumer in consumers {
+            self.unsubscribe(consumer).await;
+        }
+    }
+
+    async fn close_channel(&self, channel: u16, err: OperationFailResult) {
+        self.close_channel_with_reply(channel, get_reply_code(&err), format!("{:?}", err).as_str())
+            .await;
+    }
+
+    async fn close_channel_with_reply(&self, channel: u16, reply_code: u16, reply_text: &str) {
+        self.cancel_channel_consumers(channel).await;
+        self.send(methods::channel_close(channel, reply_code, reply_text));
+    }
+}
+
+fn is_default_exchange(exchange: &str) -> bool {
+    exchange.is_empty() || exchange.starts_with("amq.")
+}
+
+fn get_topic_id<'s>(exchange: &'s str, routing_key: &'s str) -> &'s str {
+    if is_default_exchange(exchange) {
+        routing_key
+    } else {
+        exchange
+    }
+}
+
+fn get_queue_type(durable: bool, exclusive: bool, auto_delete: bool) -> TopicQueueType {
+    if durable && exclusive {
+        return TopicQueueType::PermanentWithSingleConnection;
+    }
+
+    if durable && !auto_delete {
+        return TopicQueueType::Permanent;
+    }
+
+    TopicQueueType::DeleteOnDisconnect
+}
+
+fn get_reply_code(err: &OperationFailResult) -> u16 {
+    match err {
+        OperationFailResult::TopicNotFound { .. } | OperationFailResult::QueueNotFound { .. } => {
+            methods::REPLY_NOT_FOUND
+        }
+        OperationFailResult::TopicOrQueueValidationError(_) => methods::REPLY_PRECONDITION_FAILED,
+        _ => methods::REPLY_INTERNAL_ERROR,
+    }
+}
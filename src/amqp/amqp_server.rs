@@ -0,0 +1,51 @@
+use std::{net::SocketAddr, sync::Arc};
+
+use tokio::net::TcpListener;
+
+use crate::app::{logs::SystemProcess, AppContext};
+
+pub fn start(app: Arc<AppContext>, addr: SocketAddr) {
+    tokio::spawn(async move {
+        let listener = match TcpListener::bind(addr).await {
+            Ok(listener) => listener,
+            Err(err) => {
+                app.logs.add_fatal_error(
+                    SystemProcess::System,
+                    "AmqpServer".to_string(),
+                    format!("Can not start Amqp listener. Err: {:?}", err),
+                    Some(format!("Addr:{}", addr)),
+                );
+                return;
+            }
+        };
+
+        app.logs.add_info(
+            None,
+            SystemProcess::System,
+            "AmqpServer".to_string(),
+            format!("Amqp listener is started at {}", addr),
+            None,
+        );
+
+        loop {
+            match listener.accept().await {
+                Ok((tcp_stream, remote_addr)) => {
+                    tokio::spawn(super::amqp_connection::serve(
+                        app.clone(),
+                        tcp_stream,
+                        remote_addr,
+                    ));
+                }
+                Err(err) => {
+                    app.logs.add_error(
+                        None,
+                        SystemProcess::TcpSocket,
+                        "AmqpAccept".to_string(),
+                        format!("Err: {:?}", err),
+                        None,
+                    );
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,368 @@
+use std::collections::{BTreeMap, HashMap};
+
+use my_service_bus_shared::{queue_with_intervals::QueueWithIntervals, MessageId};
+
+#[derive(Clone)]
+pub struct AmqpConsumer {
+    pub channel: u16,
+    pub consumer_tag: String,
+    pub topic_id: String,
+    pub queue_id: String,
+    pub no_ack: bool,
+}
+
+pub enum AmqpConfirmationResult {
+    AllConfirmed,
+    AllFail,
+    SomeConfirmed(QueueWithIntervals),
+}
+
+pub struct AmqpConfirmation {
+    pub topic_id: String,
+    pub queue_id: String,
+    pub confirmation_id: i64,
+    pub result: AmqpConfirmationResult,
+}
+
+struct DeliveryPackage {
+    topic_id: String,
+    queue_id: String,
+    confirmation_id: i64,
+    pending: usize,
+    confirmed: QueueWithIntervals,
+    failed: usize,
+}
+
+impl DeliveryPackage {
+    fn into_confirmation(self) -> AmqpConfirmation {
+        let result = if self.failed == 0 {
+            AmqpConfirmationResult::AllConfirmed
+        } else if self.confirmed.len() == 0 {
+            AmqpConfirmationResult::AllFail
+        } else {
+            AmqpConfirmationResult::SomeConfirmed(self.confirmed)
+        };
+
+        AmqpConfirmation {
+            topic_id: self.topic_id,
+            queue_id: self.queue_id,
+            confirmation_id: self.confirmation_id,
+            result,
+        }
+    }
+}
+
+struct DeliveredMessage {
+    package_key: String,
+    message_id: MessageId,
+}
+
+pub struct AmqpDeliveries {
+    consumers: BTreeMap<(u16, String), AmqpConsumer>,
+    last_delivery_tags: HashMap<u16, u64>,
+    on_delivery: BTreeMap<(u16, u64), DeliveredMessage>,
+    packages: HashMap<String, DeliveryPackage>,
+}
+
+impl AmqpDeliveries {
+    pub fn new() -> Self {
+        Self {
+            consumers: BTreeMap::new(),
+            last_delivery_tags: HashMap::new(),
+            on_delivery: BTreeMap::new(),
+            packages: HashMap::new(),
+        }
+    }
+
+    // Returns true if it is the first consumer of the queue within the connection
+    pub fn add_consumer(&mut self, consumer: AmqpConsumer) -> bool {
+        let is_first = self
+            .get_consumer(&consumer.topic_id, &consumer.queue_id)
+            .is_none();
+
+        let key = (consumer.channel, consumer.consumer_tag.to_string());
+        self.consumers.insert(key, consumer);
+
+        is_first
+    }
+
+    pub fn remove_consumer(&mut self, channel: u16, consumer_tag: &str) -> Option<AmqpConsumer> {
+        self.consumers.remove(&(channel, consumer_tag.to_string()))
+    }
+
+    pub fn remove_channel_consumers(&mut self, channel: u16) -> Vec<AmqpConsumer> {
+        let keys: Vec<(u16, String)> = self
+            .consumers
+            .range((channel, String::new())..)
+            .take_while(|(key, _)| key.0 == channel)
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        keys.iter()
+            .filter_map(|key| self.consumers.remove(key))
+            .collect()
+    }
+
+    pub fn get_consumer(&self, topic_id: &str, queue_id: &str) -> Option<&AmqpConsumer> {
+        self.consumers
+            .values()
+            .find(|itm| itm.topic_id == topic_id && itm.queue_id == queue_id)
+    }
+
+    pub fn register_delivery(
+        &mut self,
+        consumer: &AmqpConsumer,
+        confirmation_id: i64,
+        message_ids: &[MessageId],
+    ) -> Vec<u64> {
+        let last_delivery_tag = self.last_delivery_tags.entry(consumer.channel).or_insert(0);
+
+        let mut delivery_tags = Vec::with_capacity(message_ids.len());
+
+        for _ in message_ids {
+            *last_delivery_tag += 1;
+            delivery_tags.push(*last_delivery_tag);
+        }
+
+        if consumer.no_ack {
+            return delivery_tags;
+        }
+
+        let package_key = format!(
+            "{}/{}",
+            get_queue_key(&consumer.topic_id, &consumer.queue_id),
+            confirmation_id
+        );
+
+        for (delivery_tag, message_id) in delivery_tags.iter().zip(message_ids) {
+            self.on_delivery.insert(
+                (consumer.channel, *delivery_tag),
+                DeliveredMessage {
+                    package_key: package_key.to_string(),
+                    message_id: *message_id,
+                },
+            );
+        }
+
+        self.packages.insert(
+            package_key,
+            DeliveryPackage {
+                topic_id: consumer.topic_id.to_string(),
+                queue_id: consumer.queue_id.to_string(),
+                confirmation_id,
+                pending: message_ids.len(),
+                confirmed: QueueWithIntervals::new(),
+                failed: 0,
+            },
+        );
+
+        delivery_tags
+    }
+
+    pub fn confirm(
+        &mut self,
+        channel: u16,
+        delivery_tag: u64,
+        multiple: bool,
+        delivered: bool,
+    ) -> Vec<AmqpConfirmation> {
+        let keys: Vec<(u16, u64)> = if multiple {
+            self.on_delivery
+                .range((channel, 0)..=(channel, delivery_tag))
+                .map(|(key, _)| *key)
+                .collect()
+        } else {
+            vec![(channel, delivery_tag)]
+        };
+
+        let mut result = Vec::new();
+
+        for key in keys {
+            let delivered_message = match self.on_delivery.remove(&key) {
+                Some(delivered_message) => delivered_message,
+                None => continue,
+            };
+
+            let completed = match self.packages.get_mut(&delivered_message.package_key) {
+                Some(package) => {
+                    package.pending -= 1;
+
+                    if delivered {
+                        package.confirmed.enqueue(delivered_message.message_id);
+                    } else {
+                        package.failed += 1;
+                    }
+
+                    package.pending == 0
+                }
+                None => false,
+            };
+
+            if completed {
+                if let Some(package) = self.packages.remove(&delivered_message.package_key) {
+                    result.push(package.into_confirmation());
+                }
+            }
+        }
+
+        result
+    }
+
+    // Outstanding messages of the cancelled consumer are failed, so the queue delivers them again
+    pub fn fail_consumer_deliveries(&mut self, consumer: &AmqpConsumer) -> Vec<AmqpConfirmation> {
+        self.fail_deliveries(consumer.channel, |package| {
+            package.topic_id == consumer.topic_id && package.queue_id == consumer.queue_id
+        })
+    }
+
+    pub fn fail_channel_deliveries(&mut self, channel: u16) -> Vec<AmqpConfirmation> {
+        self.fail_deliveries(channel, |_| true)
+    }
+
+    fn fail_deliveries(
+        &mut self,
+        channel: u16,
+        filter: impl Fn(&DeliveryPackage) -> bool,
+    ) -> Vec<AmqpConfirmation> {
+        let delivery_tags: Vec<u64> = self
+            .on_delivery
+            .range((channel, 0)..=(channel, u64::MAX))
+            .filter(|(_, delivered_message)| {
+                match self.packages.get(&delivered_message.package_key) {
+                    Some(package) => filter(package),
+                    None => true,
+                }
+            })
+            .map(|(key, _)| key.1)
+            .collect();
+
+        let mut result = Vec::new();
+
+        for delivery_tag in delivery_tags {
+            result.extend(self.confirm(channel, delivery_tag, false, false));
+        }
+
+        result
+    }
+}
+
+fn get_queue_key(topic_id: &str, queue_id: &str) -> String {
+    format!("{}/{}", topic_id, queue_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_consumer(no_ack: bool) -> AmqpConsumer {
+        AmqpConsumer {
+            channel: 1,
+            consumer_tag: "ctag".to_string(),
+            topic_id: "topic".to_string(),
+            queue_id: "queue".to_string(),
+            no_ack,
+        }
+    }
+
+    #[test]
+    fn test_package_is_confirmed_when_all_messages_are_acked() {
+        let mut deliveries = AmqpDeliveries::new();
+        let consumer = create_consumer(false);
+
+        let tags = deliveries.register_delivery(&consumer, 5, &[10, 11, 12]);
+        assert_eq!(vec![1, 2, 3], tags);
+
+        assert_eq!(0, deliveries.confirm(1, 2, true, true).len());
+
+        let result = deliveries.confirm(1, 3, false, true);
+        assert_eq!(1, result.len());
+        assert_eq!(5, result[0].confirmation_id);
+
+        if let AmqpConfirmationResult::AllConfirmed = result[0].result {
+        } else {
+            panic!("Should not be here");
+        }
+    }
+
+    #[test]
+    fn test_package_is_partially_confirmed_when_some_messages_are_nacked() {
+        let mut deliveries = AmqpDeliveries::new();
+        let consumer = create_consumer(false);
+
+        deliveries.register_delivery(&consumer, 5, &[10, 11]);
+
+        assert_eq!(0, deliveries.confirm(1, 1, false, false).len());
+        let result = deliveries.confirm(1, 2, false, true);
+
+        if let AmqpConfirmationResult::SomeConfirmed(confirmed) = &result[0].result {
+            assert_eq!(1, confirmed.len());
+            assert_eq!(11, confirmed.get_min_id().unwrap());
+        } else {
+            panic!("Should not be here");
+        }
+    }
+
+    #[test]
+    fn test_consumers_of_the_same_queue_on_different_channels_are_kept() {
+        let mut deliveries = AmqpDeliveries::new();
+
+        assert!(deliveries.add_consumer(create_consumer(false)));
+
+        let mut second = create_consumer(false);
+        second.channel = 2;
+        assert!(!deliveries.add_consumer(second));
+
+        assert_eq!(1, deliveries.remove_channel_consumers(1).len());
+        assert_eq!(
+            2,
+            deliveries.get_consumer("topic", "queue").unwrap().channel
+        );
+    }
+
+    #[test]
+    fn test_outstanding_deliveries_are_failed_when_consumer_is_cancelled() {
+        let mut deliveries = AmqpDeliveries::new();
+        let consumer = create_consumer(false);
+
+        let mut other_queue_consumer = create_consumer(false);
+        other_queue_consumer.consumer_tag = "ctag2".to_string();
+        other_queue_consumer.queue_id = "queue2".to_string();
+
+        deliveries.register_delivery(&consumer, 5, &[10, 11]);
+        deliveries.register_delivery(&other_queue_consumer, 6, &[12]);
+
+        assert_eq!(0, deliveries.confirm(1, 1, false, true).len());
+
+        let result = deliveries.fail_consumer_deliveries(&consumer);
+        assert_eq!(1, result.len());
+        assert_eq!(5, result[0].confirmation_id);
+
+        if let AmqpConfirmationResult::SomeConfirmed(confirmed) = &result[0].result {
+            assert_eq!(10, confirmed.get_min_id().unwrap());
+        } else {
+            panic!("Should not be here");
+        }
+
+        let result = deliveries.fail_channel_deliveries(1);
+        assert_eq!(1, result.len());
+        assert_eq!(6, result[0].confirmation_id);
+
+        if let AmqpConfirmationResult::AllFail = result[0].result {
+        } else {
+            panic!("Should not be here");
+        }
+
+        assert_eq!(0, deliveries.fail_channel_deliveries(1).len());
+    }
+
+    #[test]
+    fn test_no_ack_consumer_does_not_track_deliveries() {
+        let mut deliveries = AmqpDeliveries::new();
+        let consumer = create_consumer(true);
+
+        let tags = deliveries.register_delivery(&consumer, 5, &[10, 11]);
+        assert_eq!(2, tags.len());
+
+        assert_eq!(0, deliveries.confirm(1, 2, true, true).len());
+    }
+}
@@ -0,0 +1,248 @@
+use std::collections::HashMap;
+
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+pub const FRAME_METHOD: u8 = 1;
+pub const FRAME_HEADER: u8 = 2;
+pub const FRAME_BODY: u8 = 3;
+pub const FRAME_HEARTBEAT: u8 = 8;
+pub const FRAME_END: u8 = 0xCE;
+
+pub const PROTOCOL_HEADER: &[u8; 8] = b"AMQP\x00\x00\x09\x01";
+
+#[derive(Debug)]
+pub enum AmqpError {
+    Io(std::io::Error),
+    Protocol(String),
+}
+
+impl From<std::io::Error> for AmqpError {
+    fn from(src: std::io::Error) -> Self {
+        Self::Io(src)
+    }
+}
+
+pub struct AmqpFrame {
+    pub frame_type: u8,
+    pub channel: u16,
+    pub payload: Vec<u8>,
+}
+
+impl AmqpFrame {
+    pub fn new(frame_type: u8, channel: u16, payload: Vec<u8>) -> Self {
+        Self {
+            frame_type,
+            channel,
+            payload,
+        }
+    }
+
+    pub fn serialize_to(&self, dest: &mut Vec<u8>) {
+        dest.push(self.frame_type);
+        dest.extend_from_slice(&self.channel.to_be_bytes());
+        dest.extend_from_slice(&(self.payload.len() as u32).to_be_bytes());
+        dest.extend_from_slice(&self.payload);
+        dest.push(FRAME_END);
+    }
+
+    pub fn serialize(&self) -> Vec<u8> {
+        let mut result = Vec::with_capacity(self.payload.len() + 8);
+        self.serialize_to(&mut result);
+        result
+    }
+}
+
+pub async fn read_frame<TReader: AsyncRead + Unpin>(
+    reader: &mut TReader,
+    max_frame_size: usize,
+) -> Result<AmqpFrame, AmqpError> {
+    let frame_type = reader.read_u8().await?;
+    let channel = reader.read_u16().await?;
+    let size = reader.read_u32().await? as usize;
+
+    if size > max_frame_size {
+        return Err(AmqpError::Protocol(format!(
+            "Frame size {} exceeds max frame size {}",
+            size, max_frame_size
+        )));
+    }
+
+    let mut payload = vec![0u8; size];
+    reader.read_exact(&mut payload).await?;
+
+    let frame_end = reader.read_u8().await?;
+
+    if frame_end != FRAME_END {
+        return Err(AmqpError::Protocol(format!(
+            "Invalid frame end byte: {}",
+            frame_end
+        )));
+    }
+
+    Ok(AmqpFrame {
+        frame_type,
+        channel,
+        payload,
+    })
+}
+
+pub struct PayloadReader<'s> {
+    payload: &'s [u8],
+    pos: usize,
+}
+
+impl<'s> PayloadReader<'s> {
+    pub fn new(payload: &'s [u8]) -> Self {
+        Self { payload, pos: 0 }
+    }
+
+    fn read_slice(&mut self, len: usize) -> Result<&'s [u8], AmqpError> {
+        if self.pos + len > self.payload.len() {
+            return Err(AmqpError::Protocol(format!(
+                "Payload is too short. Pos: {}, requested: {}, len: {}",
+                self.pos,
+                len,
+                self.payload.len()
+            )));
+        }
+
+        let result = &self.payload[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(result)
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, AmqpError> {
+        Ok(self.read_slice(1)?[0])
+    }
+
+    pub fn read_u16(&mut self) -> Result<u16, AmqpError> {
+        let slice = self.read_slice(2)?;
+        Ok(u16::from_be_bytes([slice[0], slice[1]]))
+    }
+
+    pub fn read_u32(&mut self) -> Result<u32, AmqpError> {
+        let slice = self.read_slice(4)?;
+        Ok(u32::from_be_bytes([slice[0], slice[1], slice[2], slice[3]]))
+    }
+
+    pub fn read_u64(&mut self) -> Result<u64, AmqpError> {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(self.read_slice(8)?);
+        Ok(u64::from_be_bytes(bytes))
+    }
+
+    pub fn read_short_str(&mut self) -> Result<String, AmqpError> {
+        let len = self.read_u8()? as usize;
+        let slice = self.read_slice(len)?;
+        Ok(String::from_utf8_lossy(slice).to_string())
+    }
+
+    pub fn read_long_str(&mut self) -> Result<Vec<u8>, AmqpError> {
+        let len = self.read_u32()? as usize;
+        Ok(self.read_slice(len)?.to_vec())
+    }
+
+    pub fn read_table(&mut self) -> Result<HashMap<String, String>, AmqpError> {
+        let len = self.read_u32()? as usize;
+        let mut reader = PayloadReader::new(self.read_slice(len)?);
+
+        let mut result = HashMap::new();
+
+        while reader.pos < reader.payload.len() {
+            let key = reader.read_short_str()?;
+            let value = reader.read_field_value()?;
+            result.insert(key, value);
+        }
+
+        Ok(result)
+    }
+
+    fn read_field_value(&mut self) -> Result<String, AmqpError> {
+        let value_type = self.read_u8()?;
+
+        let result = match value_type {
+            b't' => (self.read_u8()? != 0).to_string(),
+            b'b' => (self.read_u8()? as i8).to_string(),
+            b'B' => self.read_u8()?.to_string(),
+            b's' => (self.read_u16()? as i16).to_string(),
+            b'u' => self.read_u16()?.to_string(),
+            b'I' => (self.read_u32()? as i32).to_string(),
+            b'i' => self.read_u32()?.to_string(),
+            b'l' => (self.read_u64()? as i64).to_string(),
+            b'T' => self.read_u64()?.to_string(),
+            b'f' => f32::from_bits(self.read_u32()?).to_string(),
+            b'd' => f64::from_bits(self.read_u64()?).to_string(),
+            b'D' => {
+                let scale = self.read_u8()?;
+                let value = self.read_u32()? as i32;
+                (value as f64 / 10f64.powi(scale as i32)).to_string()
+            }
+            b'S' | b'x' => String::from_utf8_lossy(&self.read_long_str()?).to_string(),
+            b'A' | b'F' => {
+                self.read_long_str()?;
+                String::new()
+            }
+            b'V' => String::new(),
+            _ => {
+                return Err(AmqpError::Protocol(format!(
+                    "Unsupported field value type: {}",
+                    value_type as char
+                )))
+            }
+        };
+
+        Ok(result)
+    }
+}
+
+pub struct PayloadWriter {
+    pub payload: Vec<u8>,
+}
+
+impl PayloadWriter {
+    pub fn new() -> Self {
+        Self {
+            payload: Vec::new(),
+        }
+    }
+
+    pub fn write_u8(&mut self, value: u8) {
+        self.payload.push(value);
+    }
+
+    pub fn write_u16(&mut self, value: u16) {
+        self.payload.extend_from_slice(&value.to_be_bytes());
+    }
+
+    pub fn write_u32(&mut self, value: u32) {
+        self.payload.extend_from_slice(&value.to_be_bytes());
+    }
+
+    pub fn write_u64(&mut self, value: u64) {
+        self.payload.extend_from_slice(&value.to_be_bytes());
+    }
+
+    pub fn write_short_str(&mut self, value: &str) {
+        let bytes = value.as_bytes();
+        let len = bytes.len().min(255);
+        self.payload.push(len as u8);
+        self.payload.extend_from_slice(&bytes[..len]);
+    }
+
+    pub fn write_long_str(&mut self, value: &[u8]) {
+        self.write_u32(value.len() as u32);
+        self.payload.extend_from_slice(value);
+    }
+
+    pub fn write_table<'s>(&mut self, items: impl Iterator<Item = (&'s String, &'s String)>) {
+        let mut table = PayloadWriter::new();
+
+        for (key, value) in items {
+            table.write_short_str(key);
+            table.write_u8(b'S');
+            table.write_long_str(value.as_bytes());
+        }
+
+        self.write_long_str(&table.payload);
+    }
+}
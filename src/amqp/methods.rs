@@ -0,0 +1,380 @@
+use std::collections::HashMap;
+
+use super::deliveries::AmqpConsumer;
+use super::frames::{
+    AmqpError, AmqpFrame, PayloadReader, PayloadWriter, FRAME_BODY, FRAME_HEADER, FRAME_METHOD,
+};
+
+pub const CLASS_CONNECTION: u16 = 10;
+pub const CLASS_CHANNEL: u16 = 20;
+pub const CLASS_EXCHANGE: u16 = 40;
+pub const CLASS_QUEUE: u16 = 50;
+pub const CLASS_BASIC: u16 = 60;
+
+pub const REPLY_CONNECTION_FORCED: u16 = 320;
+pub const REPLY_NOT_FOUND: u16 = 404;
+pub const REPLY_PRECONDITION_FAILED: u16 = 406;
+pub const REPLY_NOT_IMPLEMENTED: u16 = 540;
+pub const REPLY_INTERNAL_ERROR: u16 = 541;
+
+const HEADERS_PROPERTY_FLAG: u16 = 0x2000;
+
+pub enum AmqpMethod {
+    ConnectionStartOk {
+        client_properties: HashMap<String, String>,
+    },
+    ConnectionTuneOk {
+        frame_max: u32,
+        heartbeat: u16,
+    },
+    ConnectionOpen,
+    ConnectionClose,
+    ConnectionCloseOk,
+    ChannelOpen,
+    ChannelClose,
+    ChannelCloseOk,
+    ExchangeDeclare {
+        exchange: String,
+        no_wait: bool,
+    },
+    QueueDeclare {
+        queue: String,
+        durable: bool,
+        exclusive: bool,
+        auto_delete: bool,
+        no_wait: bool,
+    },
+    QueueBind {
+        queue: String,
+        exchange: String,
+        routing_key: String,
+        no_wait: bool,
+    },
+    BasicQos,
+    BasicConsume {
+        queue: String,
+        consumer_tag: String,
+        no_ack: bool,
+        no_wait: bool,
+    },
+    BasicCancel {
+        consumer_tag: String,
+        no_wait: bool,
+    },
+    BasicPublish {
+        exchange: String,
+        routing_key: String,
+    },
+    BasicAck {
+        delivery_tag: u64,
+        multiple: bool,
+    },
+    BasicNack {
+        delivery_tag: u64,
+        multiple: bool,
+    },
+    Unsupported {
+        class_id: u16,
+        method_id: u16,
+    },
+}
+
+impl AmqpMethod {
+    pub fn parse(payload: &[u8]) -> Result<Self, AmqpError> {
+        let mut reader = PayloadReader::new(payload);
+
+        let class_id = reader.read_u16()?;
+        let method_id = reader.read_u16()?;
+
+        let result = match (class_id, method_id) {
+            (CLASS_CONNECTION, 11) => Self::ConnectionStartOk {
+                client_properties: reader.read_table()?,
+            },
+            (CLASS_CONNECTION, 31) => {
+                let _channel_max = reader.read_u16()?;
+                Self::ConnectionTuneOk {
+                    frame_max: reader.read_u32()?,
+                    heartbeat: reader.read_u16()?,
+                }
+            }
+            (CLASS_CONNECTION, 40) => Self::ConnectionOpen,
+            (CLASS_CONNECTION, 50) => Self::ConnectionClose,
+            (CLASS_CONNECTION, 51) => Self::ConnectionCloseOk,
+            (CLASS_CHANNEL, 10) => Self::ChannelOpen,
+            (CLASS_CHANNEL, 40) => Self::ChannelClose,
+            (CLASS_CHANNEL, 41) => Self::ChannelCloseOk,
+            (CLASS_EXCHANGE, 10) => {
+                let _reserved = reader.read_u16()?;
+                let exchange = reader.read_short_str()?;
+                let _exchange_type = reader.read_short_str()?;
+                let bits = reader.read_u8()?;
+                Self::ExchangeDeclare {
+                    exchange,
+                    no_wait: bits & 0x10 > 0,
+                }
+            }
+            (CLASS_QUEUE, 10) => {
+                let _reserved = reader.read_u16()?;
+                let queue = reader.read_short_str()?;
+                let bits = reader.read_u8()?;
+                Self::QueueDeclare {
+                    queue,
+                    durable: bits & 0x02 > 0,
+                    exclusive: bits & 0x04 > 0,
+                    auto_delete: bits & 0x08 > 0,
+                    no_wait: bits & 0x10 > 0,
+                }
+            }
+            (CLASS_QUEUE, 20) => {
+                let _reserved = reader.read_u16()?;
+                let queue = reader.read_short_str()?;
+                let exchange = reader.read_short_str()?;
+                let routing_key = reader.read_short_str()?;
+                let bits = reader.read_u8()?;
+                Self::QueueBind {
+                    queue,
+                    exchange,
+                    routing_key,
+                    no_wait: bits & 0x01 > 0,
+                }
+            }
+            (CLASS_BASIC, 10) => Self::BasicQos,
+            (CLASS_BASIC, 20) => {
+                let _reserved = reader.read_u16()?;
+                let queue = reader.read_short_str()?;
+                let consumer_tag = reader.read_short_str()?;
+                let bits = reader.read_u8()?;
+                Self::BasicConsume {
+                    queue,
+                    consumer_tag,
+                    no_ack: bits & 0x02 > 0,
+                    no_wait: bits & 0x08 > 0,
+                }
+            }
+            (CLASS_BASIC, 30) => {
+                let consumer_tag = reader.read_short_str()?;
+                let bits = reader.read_u8()?;
+                Self::BasicCancel {
+                    consumer_tag,
+                    no_wait: bits & 0x01 > 0,
+                }
+            }
+            (CLASS_BASIC, 40) => {
+                let _reserved = reader.read_u16()?;
+                Self::BasicPublish {
+                    exchange: reader.read_short_str()?,
+                    routing_key: reader.read_short_str()?,
+                }
+            }
+            (CLASS_BASIC, 80) => {
+                let delivery_tag = reader.read_u64()?;
+                let bits = reader.read_u8()?;
+                Self::BasicAck {
+                    delivery_tag,
+                    multiple: bits & 0x01 > 0,
+                }
+            }
+            (CLASS_BASIC, 90) => Self::BasicNack {
+                delivery_tag: reader.read_u64()?,
+                multiple: false,
+            },
+            (CLASS_BASIC, 120) => {
+                let delivery_tag = reader.read_u64()?;
+                let bits = reader.read_u8()?;
+                Self::BasicNack {
+                    delivery_tag,
+                    multiple: bits & 0x01 > 0,
+                }
+            }
+            _ => Self::Unsupported {
+                class_id,
+                method_id,
+            },
+        };
+
+        Ok(result)
+    }
+}
+
+pub struct AmqpContentHeader {
+    pub body_size: u64,
+    pub headers: Option<HashMap<String, String>>,
+    pub delivery_mode: u8,
+}
+
+impl AmqpContentHeader {
+    pub fn parse(payload: &[u8]) -> Result<Self, AmqpError> {
+        let mut reader = PayloadReader::new(payload);
+
+        let _class_id = reader.read_u16()?;
+        let _weight = reader.read_u16()?;
+        let body_size = reader.read_u64()?;
+        let flags = reader.read_u16()?;
+
+        let mut result = Self {
+            body_size,
+            headers: None,
+            delivery_mode: 1,
+        };
+
+        // content-type, content-encoding
+        for bit in [15, 14] {
+            if flags & (1 << bit) > 0 {
+                reader.read_short_str()?;
+            }
+        }
+
+        if flags & HEADERS_PROPERTY_FLAG > 0 {
+            result.headers = Some(reader.read_table()?);
+        }
+
+        if flags & (1 << 12) > 0 {
+            result.delivery_mode = reader.read_u8()?;
+        }
+
+        Ok(result)
+    }
+}
+
+fn method_writer(class_id: u16, method_id: u16) -> PayloadWriter {
+    let mut writer = PayloadWriter::new();
+    writer.write_u16(class_id);
+    writer.write_u16(method_id);
+    writer
+}
+
+fn method_frame(channel: u16, writer: PayloadWriter) -> AmqpFrame {
+    AmqpFrame::new(FRAME_METHOD, channel, writer.payload)
+}
+
+pub fn connection_start(version: &str) -> AmqpFrame {
+    let mut writer = method_writer(CLASS_CONNECTION, 10);
+    writer.write_u8(0);
+    writer.write_u8(9);
+
+    let mut server_properties = HashMap::new();
+    server_properties.insert("product".to_string(), "MyServiceBus".to_string());
+    server_properties.insert("version".to_string(), version.to_string());
+    writer.write_table(server_properties.iter());
+
+    writer.write_long_str(b"PLAIN AMQPLAIN");
+    writer.write_long_str(b"en_US");
+    method_frame(0, writer)
+}
+
+pub fn connection_tune(frame_max: u32) -> AmqpFrame {
+    let mut writer = method_writer(CLASS_CONNECTION, 30);
+    writer.write_u16(2047);
+    writer.write_u32(frame_max);
+    writer.write_u16(0);
+    method_frame(0, writer)
+}
+
+pub fn connection_open_ok() -> AmqpFrame {
+    let mut writer = method_writer(CLASS_CONNECTION, 41);
+    writer.write_short_str("");
+    method_frame(0, writer)
+}
+
+pub fn connection_close(reply_code: u16, reply_text: &str) -> AmqpFrame {
+    let mut writer = method_writer(CLASS_CONNECTION, 50);
+    writer.write_u16(reply_code);
+    writer.write_short_str(reply_text);
+    writer.write_u16(0);
+    writer.write_u16(0);
+    method_frame(0, writer)
+}
+
+pub fn connection_close_ok() -> AmqpFrame {
+    method_frame(0, method_writer(CLASS_CONNECTION, 51))
+}
+
+pub fn channel_open_ok(channel: u16) -> AmqpFrame {
+    let mut writer = method_writer(CLASS_CHANNEL, 11);
+    writer.write_long_str(b"");
+    method_frame(channel, writer)
+}
+
+pub fn channel_close(channel: u16, reply_code: u16, reply_text: &str) -> AmqpFrame {
+    let mut writer = method_writer(CLASS_CHANNEL, 40);
+    writer.write_u16(reply_code);
+    writer.write_short_str(reply_text);
+    writer.write_u16(0);
+    writer.write_u16(0);
+    method_frame(channel, writer)
+}
+
+pub fn channel_close_ok(channel: u16) -> AmqpFrame {
+    method_frame(channel, method_writer(CLASS_CHANNEL, 41))
+}
+
+pub fn exchange_declare_ok(channel: u16) -> AmqpFrame {
+    method_frame(channel, method_writer(CLASS_EXCHANGE, 11))
+}
+
+pub fn queue_declare_ok(channel: u16, queue: &str, message_count: u32) -> AmqpFrame {
+    let mut writer = method_writer(CLASS_QUEUE, 11);
+    writer.write_short_str(queue);
+    writer.write_u32(message_count);
+    writer.write_u32(0);
+    method_frame(channel, writer)
+}
+
+pub fn queue_bind_ok(channel: u16) -> AmqpFrame {
+    method_frame(channel, method_writer(CLASS_QUEUE, 21))
+}
+
+pub fn basic_qos_ok(channel: u16) -> AmqpFrame {
+    method_frame(channel, method_writer(CLASS_BASIC, 11))
+}
+
+pub fn basic_consume_ok(channel: u16, consumer_tag: &str) -> AmqpFrame {
+    let mut writer = method_writer(CLASS_BASIC, 21);
+    writer.write_short_str(consumer_tag);
+    method_frame(channel, writer)
+}
+
+pub fn basic_cancel_ok(channel: u16, consumer_tag: &str) -> AmqpFrame {
+    let mut writer = method_writer(CLASS_BASIC, 31);
+    writer.write_short_str(consumer_tag);
+    method_frame(channel, writer)
+}
+
+pub fn serialize_basic_deliver(
+    dest: &mut Vec<u8>,
+    consumer: &AmqpConsumer,
+    delivery_tag: u64,
+    redelivered: bool,
+    headers: &Option<HashMap<String, String>>,
+    content: &[u8],
+    frame_max: usize,
+) {
+    let channel = consumer.channel;
+
+    let mut writer = method_writer(CLASS_BASIC, 60);
+    writer.write_short_str(consumer.consumer_tag.as_str());
+    writer.write_u64(delivery_tag);
+    writer.write_u8(if redelivered { 1 } else { 0 });
+    writer.write_short_str(consumer.topic_id.as_str());
+    writer.write_short_str("");
+    method_frame(channel, writer).serialize_to(dest);
+
+    let mut writer = PayloadWriter::new();
+    writer.write_u16(CLASS_BASIC);
+    writer.write_u16(0);
+    writer.write_u64(content.len() as u64);
+
+    match headers {
+        Some(headers) => {
+            writer.write_u16(HEADERS_PROPERTY_FLAG);
+            writer.write_table(headers.iter());
+        }
+        None => writer.write_u16(0),
+    }
+
+    AmqpFrame::new(FRAME_HEADER, channel, writer.payload).serialize_to(dest);
+
+    for chunk in content.chunks(frame_max - 8) {
+        AmqpFrame::new(FRAME_BODY, channel, chunk.to_vec()).serialize_to(dest);
+    }
+}
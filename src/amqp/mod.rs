@@ -0,0 +1,9 @@
+mod amqp_connection;
+mod amqp_server;
+mod deliveries;
+pub mod frames;
+pub mod methods;
+
+pub use amqp_server::start;
+pub use deliveries::{AmqpConfirmation, AmqpConfirmationResult, AmqpConsumer, AmqpDeliveries};
+pub use methods::serialize_basic_deliver;
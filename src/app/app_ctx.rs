@@ -1,18 +1,34 @@
-use std::{sync::Arc, time::Duration};
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 
 use futures_util::lock::Mutex;
 use rust_extensions::{events_loop::EventsLoop, AppStates, ApplicationStates};
 use tokio::sync::RwLock;
 
 use crate::{
+    alerts::AlertsEngine,
+    audit::AuditLog,
+    metric_data::MetricsHistoryStore,
     persistence::{MessagesPagesRepo, TopicsAndQueuesSnapshotRepo},
     queue_subscribers::SubscriberIdGenerator,
     sessions::SessionsList,
-    settings::SettingsModel,
+    settings::{CliArgs, SettingsModel},
+    streaming::StatusEvents,
+    telemetry::Telemetry,
     topics::{Topic, TopicsList},
+    topology::TopologyJson,
 };
 
-use super::{logs::Logs, prometheus_metrics::PrometheusMetrics};
+use super::{
+    logs::{LogSinks, Logs},
+    prometheus_metrics::PrometheusMetrics,
+    TimerHeartbeats,
+};
 
 pub const APP_VERSION: &'static str = env!("CARGO_PKG_VERSION");
 
@@ -25,7 +41,7 @@ pub struct DebugTopicAndQueue {
 pub struct AppContext {
     pub states: Arc<AppStates>,
     pub topic_list: TopicsList,
-    pub max_delivery_size: usize,
+    max_delivery_size: AtomicUsize,
     pub topics_and_queues_repo: Arc<TopicsAndQueuesSnapshotRepo>,
     pub messages_pages_repo: Arc<MessagesPagesRepo>,
     pub logs: Arc<Logs>,
@@ -33,53 +49,97 @@ pub struct AppContext {
     pub process_id: String,
     pub subscriber_id_generator: SubscriberIdGenerator,
 
-    pub empty_queue_gc_timeout: Duration,
+    empty_queue_gc_timeout_ms: AtomicU64,
     pub prometheus: PrometheusMetrics,
 
-    pub delivery_timeout: Duration,
+    delivery_timeout_ms: AtomicU64,
 
+    debug_mode: AtomicBool,
     pub debug_topic_and_queue: RwLock<Option<DebugTopicAndQueue>>,
 
-    pub auto_create_topic_on_publish: bool,
-    pub auto_create_topic_on_subscribe: bool,
+    auto_create_topic_on_publish: AtomicBool,
+    auto_create_topic_on_subscribe: AtomicBool,
 
     pub immediatly_persist_event_loop: EventsLoop<Arc<Topic>>,
 
     pub persist_compressed: bool,
 
     pub persistence_version: Mutex<String>,
+
+    pub status_events: StatusEvents,
+
+    pub topology: Option<TopologyJson>,
+    pub topology_file: Option<String>,
+
+    pub settings_reload_requested: Arc<AtomicBool>,
+    pub cli_args: CliArgs,
+
+    pub telemetry: Telemetry,
+
+    pub metrics_history: MetricsHistoryStore,
+
+    pub timer_heartbeats: TimerHeartbeats,
+    pub health_max_persist_queue_size: usize,
+
+    pub audit: AuditLog,
+
+    pub alerts: AlertsEngine,
 }
 
 impl AppContext {
-    pub async fn new(settings: &SettingsModel) -> Self {
-        let logs = Arc::new(Logs::new());
+    pub async fn new(settings: &SettingsModel, cli_args: CliArgs) -> Self {
+        let logs = Arc::new(Logs::new(LogSinks::new(&settings.log_sinks)));
 
         let topics_and_queues_repo = settings.create_topics_and_queues_snapshot_repo().await;
         let messages_pages_repo = settings.create_messages_pages_repo().await;
+
+        let audit = AuditLog::new(
+            settings.audit_file.clone(),
+            settings.audit_trust_user_header,
+            logs.clone(),
+        );
+
         Self {
             states: Arc::new(AppStates::create_un_initialized()),
             topic_list: TopicsList::new(),
-            max_delivery_size: settings.max_delivery_size,
+            max_delivery_size: AtomicUsize::new(settings.max_delivery_size),
             topics_and_queues_repo: Arc::new(topics_and_queues_repo),
             messages_pages_repo: Arc::new(messages_pages_repo),
             logs,
             sessions: SessionsList::new(),
             process_id: uuid::Uuid::new_v4().to_string(),
-            empty_queue_gc_timeout: settings.queue_gc_timeout,
+            empty_queue_gc_timeout_ms: AtomicU64::new(settings.queue_gc_timeout.as_millis() as u64),
             subscriber_id_generator: SubscriberIdGenerator::new(),
             prometheus: PrometheusMetrics::new(),
             persist_compressed: settings.persist_compressed,
 
-            delivery_timeout: if let Some(delivery_timeout) = settings.delivery_timeout {
-                delivery_timeout
-            } else {
-                Duration::from_secs(30)
-            },
+            delivery_timeout_ms: AtomicU64::new(get_delivery_timeout(settings).as_millis() as u64),
+            debug_mode: AtomicBool::new(settings.debug_mode),
             debug_topic_and_queue: RwLock::new(None),
-            auto_create_topic_on_publish: settings.auto_create_topic_on_publish,
-            auto_create_topic_on_subscribe: settings.auto_create_topic_on_subscribe,
+            auto_create_topic_on_publish: AtomicBool::new(settings.auto_create_topic_on_publish),
+            auto_create_topic_on_subscribe: AtomicBool::new(
+                settings.auto_create_topic_on_subscribe,
+            ),
             immediatly_persist_event_loop: EventsLoop::new("ImmediatelyPersist".to_string()),
             persistence_version: Mutex::new(String::new()),
+            status_events: StatusEvents::new(),
+            topology: settings.topology.clone(),
+            topology_file: settings.topology_file.clone(),
+            settings_reload_requested: Arc::new(AtomicBool::new(false)),
+            cli_args,
+            telemetry: Telemetry::new(
+                settings.open_telemetry_endpoint.clone(),
+                settings.service_name.clone(),
+            ),
+            metrics_history: MetricsHistoryStore::new(settings.metrics_history_file.clone()),
+            timer_heartbeats: TimerHeartbeats::new(settings.persist_timer_interval),
+            health_max_persist_queue_size: settings.health_max_persist_queue_size,
+            audit,
+            alerts: AlertsEngine::new(
+                settings.alert_rules.clone(),
+                settings.alert_webhooks.clone(),
+                settings.service_name.clone(),
+            ),
         }
     }
 
@@ -99,7 +159,89 @@ impl AppContext {
     }
 
     pub fn get_max_delivery_size(&self) -> usize {
-        self.max_delivery_size
+        self.max_delivery_size.load(Ordering::Relaxed)
+    }
+
+    pub fn get_delivery_timeout(&self) -> Duration {
+        Duration::from_millis(self.delivery_timeout_ms.load(Ordering::Relaxed))
+    }
+
+    pub fn get_empty_queue_gc_timeout(&self) -> Duration {
+        Duration::from_millis(self.empty_queue_gc_timeout_ms.load(Ordering::Relaxed))
+    }
+
+    pub fn is_debug_mode(&self) -> bool {
+        self.debug_mode.load(Ordering::Relaxed)
+    }
+
+    pub fn auto_create_topic_on_publish(&self) -> bool {
+        self.auto_create_topic_on_publish.load(Ordering::Relaxed)
+    }
+
+    pub fn auto_create_topic_on_subscribe(&self) -> bool {
+        self.auto_create_topic_on_subscribe.load(Ordering::Relaxed)
+    }
+
+    pub async fn apply_settings(&self, settings: &SettingsModel) -> Vec<String> {
+        let mut changes = Vec::new();
+
+        let prev = self
+            .max_delivery_size
+            .swap(settings.max_delivery_size, Ordering::SeqCst);
+        if prev != settings.max_delivery_size {
+            changes.push(format!(
+                "MaxDeliverySize: {} -> {}",
+                prev, settings.max_delivery_size
+            ));
+        }
+
+        let value = get_delivery_timeout(settings).as_millis() as u64;
+        let prev = self.delivery_timeout_ms.swap(value, Ordering::SeqCst);
+        if prev != value {
+            changes.push(format!("DeliveryTimeout: {}ms -> {}ms", prev, value));
+        }
+
+        let value = settings.queue_gc_timeout.as_millis() as u64;
+        let prev = self.empty_queue_gc_timeout_ms.swap(value, Ordering::SeqCst);
+        if prev != value {
+            changes.push(format!("QueueGcTimeout: {}ms -> {}ms", prev, value));
+        }
+
+        let value = settings.auto_create_topic_on_publish;
+        let prev = self
+            .auto_create_topic_on_publish
+            .swap(value, Ordering::SeqCst);
+        if prev != value {
+            changes.push(format!("AutoCreateTopicOnPublish: {} -> {}", prev, value));
+        }
+
+        let value = settings.auto_create_topic_on_subscribe;
+        let prev = self
+            .auto_create_topic_on_subscribe
+            .swap(value, Ordering::SeqCst);
+        if prev != value {
+            changes.push(format!("AutoCreateTopicOnSubscribe: {} -> {}", prev, value));
+        }
+
+        let value = settings.debug_mode;
+        let prev = self.debug_mode.swap(value, Ordering::SeqCst);
+        if prev != value {
+            changes.push(format!("DebugMode: {} -> {}", prev, value));
+
+            if !value {
+                self.disable_debug_topic_and_queue().await;
+            }
+        }
+
+        changes
+    }
+}
+
+fn get_delivery_timeout(settings: &SettingsModel) -> Duration {
+    if let Some(delivery_timeout) = settings.delivery_timeout {
+        delivery_timeout
+    } else {
+        Duration::from_secs(30)
     }
 }
 
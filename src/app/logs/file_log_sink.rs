@@ -0,0 +1,157 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::Write,
+    sync::Mutex,
+};
+
+use super::{LogItem, LogSink};
+
+struct FileLogSinkData {
+    file: Option<File>,
+    size: u64,
+}
+
+pub struct FileLogSink {
+    file_name: String,
+    max_size: u64,
+    max_files: usize,
+    data: Mutex<FileLogSinkData>,
+}
+
+impl FileLogSink {
+    pub fn open(file_name: &str, max_size: u64, max_files: usize) -> std::io::Result<Self> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(file_name)?;
+
+        let size = file.metadata()?.len();
+
+        Ok(Self {
+            file_name: file_name.to_string(),
+            max_size,
+            max_files,
+            data: Mutex::new(FileLogSinkData {
+                file: Some(file),
+                size,
+            }),
+        })
+    }
+
+    fn rotate(&self, data: &mut FileLogSinkData) -> std::io::Result<()> {
+        data.file = None;
+
+        if self.max_files > 0 {
+            for no in (1..self.max_files).rev() {
+                let _ = std::fs::rename(
+                    get_rotated_file_name(self.file_name.as_str(), no),
+                    get_rotated_file_name(self.file_name.as_str(), no + 1),
+                );
+            }
+
+            std::fs::rename(
+                self.file_name.as_str(),
+                get_rotated_file_name(self.file_name.as_str(), 1),
+            )?;
+        }
+
+        data.file = Some(
+            OpenOptions::new()
+                .create(true)
+                .write(true)
+                .truncate(true)
+                .open(self.file_name.as_str())?,
+        );
+
+        data.size = 0;
+
+        Ok(())
+    }
+}
+
+impl LogSink for FileLogSink {
+    fn write(&self, item: &LogItem) -> Result<(), String> {
+        let mut line = item.to_json_line();
+        line.push('\n');
+
+        let mut data = self.data.lock().unwrap();
+
+        if data.size > 0 && data.size + line.len() as u64 > self.max_size {
+            if let Err(err) = self.rotate(&mut data) {
+                return Err(format!(
+                    "Can not rotate log file {}. Reason: {:?}",
+                    self.file_name, err
+                ));
+            }
+        }
+
+        let file = match &mut data.file {
+            Some(file) => file,
+            None => return Err(format!("Log file {} is not opened", self.file_name)),
+        };
+
+        file.write_all(line.as_bytes()).map_err(|err| {
+            format!(
+                "Can not write to log file {}. Reason: {:?}",
+                self.file_name, err
+            )
+        })?;
+
+        data.size += line.len() as u64;
+
+        Ok(())
+    }
+}
+
+fn get_rotated_file_name(file_name: &str, no: usize) -> String {
+    format!("{}.{}", file_name, no)
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_extensions::date_time::DateTimeAsMicroseconds;
+
+    use crate::app::logs::{LogLevel, SystemProcess};
+
+    use super::*;
+
+    fn create_item(message: &str) -> LogItem {
+        LogItem {
+            date: DateTimeAsMicroseconds::now(),
+            topic: None,
+            level: LogLevel::Info,
+            process: SystemProcess::System,
+            process_name: "test".to_string(),
+            message: message.to_string(),
+            err_ctx: None,
+        }
+    }
+
+    #[test]
+    fn test_file_is_rotated() {
+        let dir = std::env::temp_dir().join(uuid::Uuid::new_v4().to_string());
+        std::fs::create_dir_all(&dir).unwrap();
+        let file_name = dir.join("my-service-bus.log");
+        let file_name = file_name.to_str().unwrap();
+
+        let sink = FileLogSink::open(file_name, 300, 2).unwrap();
+
+        for no in 0..10 {
+            sink.write(&create_item(format!("Message {}", no).as_str()))
+                .unwrap();
+        }
+
+        let current = std::fs::read_to_string(file_name).unwrap();
+        assert!(current.contains("Message 9"));
+        assert!(std::fs::metadata(get_rotated_file_name(file_name, 1)).is_ok());
+        assert!(std::fs::metadata(get_rotated_file_name(file_name, 2)).is_ok());
+        assert!(std::fs::metadata(get_rotated_file_name(file_name, 3)).is_err());
+
+        for line in current.lines() {
+            let json: serde_json::Value = serde_json::from_str(line).unwrap();
+            assert_eq!("Info", json["level"]);
+        }
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
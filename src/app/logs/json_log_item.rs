@@ -0,0 +1,33 @@
+use serde::Serialize;
+
+use super::LogItem;
+
+#[derive(Serialize)]
+struct JsonLogItem<'s> {
+    time: String,
+    level: String,
+    process: String,
+    #[serde(rename = "processName")]
+    process_name: &'s str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    topic: Option<&'s str>,
+    message: &'s str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    context: Option<&'s str>,
+}
+
+impl LogItem {
+    pub fn to_json_line(&self) -> String {
+        let item = JsonLogItem {
+            time: self.date.to_rfc3339(),
+            level: format!("{:?}", self.level),
+            process: format!("{:?}", self.process),
+            process_name: self.process_name.as_str(),
+            topic: self.topic.as_deref(),
+            message: self.message.as_str(),
+            context: self.err_ctx.as_deref(),
+        };
+
+        serde_json::to_string(&item).unwrap()
+    }
+}
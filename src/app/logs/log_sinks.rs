@@ -0,0 +1,137 @@
+use std::io::Write;
+
+use super::{FileLogSink, LogItem, LogLevel};
+
+#[derive(Clone, Copy)]
+pub enum LogFormat {
+    Text,
+    Json,
+}
+
+impl LogFormat {
+    pub fn parse(src: &str) -> Option<Self> {
+        match src.to_lowercase().as_str() {
+            "text" => Some(LogFormat::Text),
+            "json" => Some(LogFormat::Json),
+            _ => None,
+        }
+    }
+}
+
+pub struct LogSinksSettings {
+    pub format: LogFormat,
+    pub min_level: LogLevel,
+    pub file: Option<String>,
+    pub file_max_size: u64,
+    pub file_max_files: usize,
+}
+
+pub trait LogSink {
+    fn write(&self, item: &LogItem) -> Result<(), String>;
+}
+
+pub struct StdoutLogSink {
+    format: LogFormat,
+}
+
+impl LogSink for StdoutLogSink {
+    fn write(&self, item: &LogItem) -> Result<(), String> {
+        let text = match self.format {
+            LogFormat::Text => format_as_text(item),
+            LogFormat::Json => {
+                let mut line = item.to_json_line();
+                line.push('\n');
+                line
+            }
+        };
+
+        std::io::stdout()
+            .lock()
+            .write_all(text.as_bytes())
+            .map_err(|err| format!("Can not write log item to stdout. Reason: {:?}", err))
+    }
+}
+
+pub struct LogSinks {
+    min_level: LogLevel,
+    sinks: Vec<Box<dyn LogSink + Send + Sync + 'static>>,
+    init_errors: Vec<String>,
+}
+
+impl LogSinks {
+    pub fn new(settings: &LogSinksSettings) -> Self {
+        let mut sinks: Vec<Box<dyn LogSink + Send + Sync + 'static>> = Vec::new();
+        let mut init_errors = Vec::new();
+
+        sinks.push(Box::new(StdoutLogSink {
+            format: settings.format,
+        }));
+
+        if let Some(file_name) = &settings.file {
+            match FileLogSink::open(
+                file_name.as_str(),
+                settings.file_max_size,
+                settings.file_max_files,
+            ) {
+                Ok(file_sink) => sinks.push(Box::new(file_sink)),
+                Err(err) => init_errors.push(format!(
+                    "Can not open log file {}. Reason: {:?}",
+                    file_name, err
+                )),
+            }
+        }
+
+        Self {
+            min_level: settings.min_level,
+            sinks,
+            init_errors,
+        }
+    }
+
+    pub fn take_init_errors(&mut self) -> Vec<String> {
+        std::mem::take(&mut self.init_errors)
+    }
+
+    ///Returns the errors of the sinks which could not write the item
+    pub fn write(&self, item: &LogItem) -> Vec<String> {
+        let mut errors = Vec::new();
+
+        if item.level.as_u8() < self.min_level.as_u8() {
+            return errors;
+        }
+
+        for sink in &self.sinks {
+            if let Err(err) = sink.write(item) {
+                errors.push(err);
+            }
+        }
+
+        errors
+    }
+}
+
+fn format_as_text(item: &LogItem) -> String {
+    let mut result = match item.level {
+        LogLevel::FatalError => format!(
+            "{dt} {level:?} {proces:?}\n Process:{processname}\n Message:{message}\n",
+            dt = item.date.to_rfc3339(),
+            level = item.level,
+            proces = item.process,
+            processname = item.process_name,
+            message = item.message
+        ),
+        _ => format!(
+            "{dt} {level:?} {proces:?}\n Topic:{topic:?}\n Process:{processname}\n Message:{message}\n Ctx:{err_ctx:?}\n",
+            topic= item.topic,
+            dt = item.date.to_rfc3339(),
+            level = item.level,
+            proces = item.process,
+            processname = item.process_name,
+            message = item.message,
+            err_ctx = item.err_ctx
+        ),
+    };
+
+    result.push_str("-------------\n");
+    result
+}
@@ -1,3 +1,7 @@
+mod file_log_sink;
+mod json_log_item;
+mod log_sinks;
+
 use std::{collections::HashMap, sync::Arc};
 
 use rust_extensions::{date_time::DateTimeAsMicroseconds, Logger};
@@ -6,6 +10,9 @@ use tokio::sync::{
     RwLock,
 };
 
+pub use file_log_sink::FileLogSink;
+pub use log_sinks::{LogFormat, LogSink, LogSinks, LogSinksSettings, StdoutLogSink};
+
 #[derive(Debug, Clone, Copy)]
 pub enum SystemProcess {
     System = 0,
@@ -75,12 +82,31 @@ impl SystemProcess {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy)]
 pub enum LogLevel {
     Info,
     Error,
     FatalError,
 }
+
+impl LogLevel {
+    pub fn parse(src: &str) -> Option<Self> {
+        match src.to_lowercase().as_str() {
+            "info" => Some(LogLevel::Info),
+            "error" => Some(LogLevel::Error),
+            "fatalerror" => Some(LogLevel::FatalError),
+            _ => None,
+        }
+    }
+
+    pub fn as_u8(&self) -> u8 {
+        match self {
+            LogLevel::Info => 0,
+            LogLevel::Error => 1,
+            LogLevel::FatalError => 2,
+        }
+    }
+}
 #[derive(Debug, Clone)]
 pub struct LogItem {
     pub date: DateTimeAsMicroseconds,
@@ -128,7 +154,7 @@ pub struct Logs {
 }
 
 impl Logs {
-    pub fn new() -> Self {
+    pub fn new(sinks: LogSinks) -> Self {
         let logs_data = LogsData {
             items: Vec::new(),
             items_by_topic: HashMap::new(),
@@ -138,7 +164,7 @@ impl Logs {
 
         let (sender, recv) = tokio::sync::mpsc::unbounded_channel();
 
-        tokio::spawn(log_writer_thread(recv, logs_data.clone()));
+        tokio::spawn(log_writer_thread(recv, logs_data.clone(), sinks));
 
         Self {
             data: logs_data,
@@ -193,17 +219,6 @@ impl Logs {
             err_ctx,
         };
 
-        println!(
-                "{dt} {level:?} {proces:?}\n Topic:{topic:?}\n Process:{processname}\n Message:{message}\n Ctx:{err_ctx:?}",
-                topic= item.topic,
-                dt = item.date.to_rfc3339(),
-                level = item.level,
-                proces = item.process,
-                processname = item.process_name,
-                message = item.message,
-                err_ctx = item.err_ctx
-            );
-        println!("-------------");
         self.add_item(item);
     }
 
@@ -224,16 +239,6 @@ impl Logs {
             err_ctx: context,
         };
 
-        println!(
-            "{dt} {level:?} {proces:?}\n Process:{processname}\n Message:{message}",
-            dt = item.date.to_rfc3339(),
-            level = item.level,
-            proces = item.process,
-            processname = item.process_name,
-            message = item.message
-        );
-        println!("-------------");
-
         self.add_item(item);
     }
 
@@ -280,10 +285,68 @@ fn gc_logs(items: &mut Vec<Arc<LogItem>>) {
     }
 }
 
-async fn log_writer_thread(mut recv: UnboundedReceiver<LogItem>, logs_data: Arc<RwLock<LogsData>>) {
+async fn log_writer_thread(
+    mut recv: UnboundedReceiver<LogItem>,
+    logs_data: Arc<RwLock<LogsData>>,
+    mut sinks: LogSinks,
+) {
+    let mut sink_errors = sinks.take_init_errors();
+    let sinks = Arc::new(sinks);
+
     while let Some(next_item) = recv.recv().await {
+        let mut items = vec![next_item];
+
+        while let Ok(next_item) = recv.try_recv() {
+            items.push(next_item);
+        }
+
+        //Sinks do blocking IO, so they are written outside of the async executor
+        let batch_sinks = sinks.clone();
+        let written = tokio::task::spawn_blocking(move || {
+            let mut errors = Vec::new();
+
+            for item in &items {
+                errors.extend(batch_sinks.write(item));
+            }
+
+            (items, errors)
+        })
+        .await;
+
+        let items = match written {
+            Ok((items, errors)) => {
+                sink_errors.extend(errors);
+                items
+            }
+            Err(err) => {
+                sink_errors.push(format!(
+                    "Can not write log items to sinks. Reason: {:?}",
+                    err
+                ));
+                continue;
+            }
+        };
+
         let mut write_access = logs_data.as_ref().write().await;
-        write_access.add(next_item).await;
+
+        for item in items {
+            write_access.add(item).await;
+        }
+
+        //Errors of the sinks are not written to the sinks again. Otherwise a broken sink loops forever
+        for err in sink_errors.drain(..) {
+            let item = LogItem {
+                date: DateTimeAsMicroseconds::now(),
+                level: LogLevel::Error,
+                topic: None,
+                process_name: "log_sinks".to_string(),
+                process: SystemProcess::System,
+                message: err,
+                err_ctx: None,
+            };
+
+            write_access.add(item).await;
+        }
     }
 }
 
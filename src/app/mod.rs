@@ -2,6 +2,8 @@ mod app_ctx;
 pub mod logs;
 pub mod prometheus_metrics;
 pub mod shutdown;
+mod timer_heartbeats;
 
 pub use app_ctx::AppContext;
 pub use app_ctx::APP_VERSION;
+pub use timer_heartbeats::{TimerHeartbeat, TimerHeartbeats};
@@ -1,15 +1,38 @@
-use prometheus::{Encoder, IntGauge, IntGaugeVec, Opts, Registry, TextEncoder};
+use std::time::Duration;
 
-use crate::messages_page::PageSizeMetrics;
+use prometheus::{
+    CounterVec, Encoder, HistogramOpts, HistogramVec, IntCounterVec, IntGauge, IntGaugeVec, Opts,
+    Registry, TextEncoder,
+};
+
+use crate::{messages_page::PageSizeMetrics, topics::QueueLag};
 
 pub struct PrometheusMetrics {
     registry: Registry,
     pub persist_queue_size: IntGaugeVec,
     pub topic_queue_size: IntGaugeVec,
+    queue_lag_messages: IntGaugeVec,
+    queue_lag_seconds: IntGaugeVec,
     permanent_queues_without_subscribers: IntGauge,
     topics_without_queues: IntGauge,
     topic_data_size: IntGaugeVec,
     topic_messages_amount: IntGaugeVec,
+    purged_messages: IntCounterVec,
+    published_messages: IntCounterVec,
+    delivered_messages: IntCounterVec,
+    redelivered_messages: IntCounterVec,
+    confirmed_ok_messages: IntCounterVec,
+    confirmed_fail_messages: IntCounterVec,
+    delivery_round_trip: HistogramVec,
+    persistence_save_duration: HistogramVec,
+    persistence_load_duration: HistogramVec,
+    session_received_packets: IntCounterVec,
+    session_published_messages: IntCounterVec,
+    session_sent_rejects: IntCounterVec,
+    session_delivered_messages: IntCounterVec,
+    session_confirmations: IntCounterVec,
+    session_confirmed_messages: IntCounterVec,
+    session_confirmation_seconds: CounterVec,
 }
 
 impl PrometheusMetrics {
@@ -22,16 +45,96 @@ impl PrometheusMetrics {
 
         let topic_queue_size = create_topic_queue_size();
 
+        let queue_lag_messages = create_queue_lag_messages();
+
+        let queue_lag_seconds = create_queue_lag_seconds();
+
         let topics_without_queues = create_topics_without_queues();
 
         let topic_data_size = create_topic_data_size();
 
         let topic_messages_amount = create_topic_messages_amount();
 
+        let purged_messages = create_purged_messages();
+
+        let published_messages = create_published_messages();
+
+        let delivered_messages = create_queue_messages_counter(
+            "delivered_messages_total",
+            "Messages sent to subscribers",
+        );
+
+        let redelivered_messages = create_queue_messages_counter(
+            "redelivered_messages_total",
+            "Messages sent to subscribers again after failed attempts",
+        );
+
+        let confirmed_ok_messages = create_queue_messages_counter(
+            "confirmed_ok_messages_total",
+            "Messages confirmed as delivered by subscribers",
+        );
+
+        let confirmed_fail_messages = create_queue_messages_counter(
+            "confirmed_fail_messages_total",
+            "Messages confirmed as not delivered by subscribers",
+        );
+
+        let delivery_round_trip = create_delivery_round_trip();
+
+        let persistence_save_duration = create_persistence_duration(
+            "persistence_save_seconds",
+            "Duration of saving messages to persistence",
+        );
+
+        let persistence_load_duration = create_persistence_duration(
+            "persistence_load_seconds",
+            "Duration of loading messages page from persistence",
+        );
+
+        let session_received_packets = create_session_received_packets();
+
+        let session_published_messages = create_session_counter(
+            "session_published_messages_total",
+            "Messages published by sessions with the name",
+        );
+
+        let session_sent_rejects = create_session_counter(
+            "session_sent_rejects_total",
+            "Publish rejects sent to sessions with the name",
+        );
+
+        let session_delivered_messages = create_session_counter(
+            "session_delivered_messages_total",
+            "Messages delivered to sessions with the name",
+        );
+
+        let session_confirmations = create_session_counter(
+            "session_confirmations_total",
+            "Delivery confirmations received from sessions with the name",
+        );
+
+        let session_confirmed_messages = create_session_counter(
+            "session_confirmed_messages_total",
+            "Messages confirmed by sessions with the name",
+        );
+
+        let session_confirmation_seconds = create_session_seconds_counter(
+            "session_confirmation_seconds_total",
+            "Total time between delivery and confirmation for sessions with the name",
+        );
+
         registry
             .register(Box::new(topic_queue_size.clone()))
             .unwrap();
 
+        registry
+            .register(Box::new(queue_lag_messages.clone()))
+            .unwrap();
+
+        registry
+            .register(Box::new(queue_lag_seconds.clone()))
+            .unwrap();
+
         registry
             .register(Box::new(persist_queue_size.clone()))
             .unwrap();
@@ -52,14 +155,83 @@ impl PrometheusMetrics {
             .register(Box::new(topic_messages_amount.clone()))
             .unwrap();
 
+        registry
+            .register(Box::new(purged_messages.clone()))
+            .unwrap();
+
+        registry
+            .register(Box::new(published_messages.clone()))
+            .unwrap();
+
+        registry
+            .register(Box::new(delivered_messages.clone()))
+            .unwrap();
+
+        registry
+            .register(Box::new(redelivered_messages.clone()))
+            .unwrap();
+
+        registry
+            .register(Box::new(confirmed_ok_messages.clone()))
+            .unwrap();
+
+        registry
+            .register(Box::new(confirmed_fail_messages.clone()))
+            .unwrap();
+
+        registry
+            .register(Box::new(delivery_round_trip.clone()))
+            .unwrap();
+
+        registry
+            .register(Box::new(persistence_save_duration.clone()))
+            .unwrap();
+
+        registry
+            .register(Box::new(persistence_load_duration.clone()))
+            .unwrap();
+
+        for counter in [
+            &session_received_packets,
+            &session_published_messages,
+            &session_sent_rejects,
+            &session_delivered_messages,
+            &session_confirmations,
+            &session_confirmed_messages,
+        ] {
+            registry.register(Box::new(counter.clone())).unwrap();
+        }
+
+        registry
+            .register(Box::new(session_confirmation_seconds.clone()))
+            .unwrap();
+
         return Self {
             registry,
             persist_queue_size,
             topic_queue_size,
+            queue_lag_messages,
+            queue_lag_seconds,
             permanent_queues_without_subscribers,
             topics_without_queues,
             topic_data_size,
             topic_messages_amount,
+            purged_messages,
+            published_messages,
+            delivered_messages,
+            redelivered_messages,
+            confirmed_ok_messages,
+            confirmed_fail_messages,
+            delivery_round_trip,
+            persistence_save_duration,
+            persistence_load_duration,
+            session_received_packets,
+            session_published_messages,
+            session_sent_rejects,
+            session_delivered_messages,
+            session_confirmations,
+            session_confirmed_messages,
+            session_confirmation_seconds,
         };
     }
 
@@ -69,6 +241,25 @@ impl PrometheusMetrics {
             .set(value);
     }
 
+    pub fn update_queue_lag(&self, topic_id: &str, queue_id: &str, lag: &QueueLag) {
+        self.queue_lag_messages
+            .with_label_values(&[topic_id, queue_id])
+            .set(lag.messages);
+
+        match lag.seconds {
+            Some(seconds) => {
+                self.queue_lag_seconds
+                    .with_label_values(&[topic_id, queue_id])
+                    .set(seconds);
+            }
+            None => {
+                let _ = self
+                    .queue_lag_seconds
+                    .remove_label_values(&[topic_id, queue_id]);
+            }
+        }
+    }
+
     pub fn update_permanent_queues_without_subscribers(&self, value: i64) {
         self.permanent_queues_without_subscribers.set(value);
     }
@@ -91,6 +282,117 @@ impl PrometheusMetrics {
             .set(metrics.messages_amount as i64);
     }
 
+    pub fn queue_is_purged(&self, topic_id: &str, queue_id: &str, purged_amount: usize) {
+        self.purged_messages
+            .with_label_values(&[topic_id, queue_id])
+            .inc_by(purged_amount as u64);
+    }
+
+    pub fn messages_are_published(&self, topic_id: &str, amount: usize) {
+        self.published_messages
+            .with_label_values(&[topic_id])
+            .inc_by(amount as u64);
+    }
+
+    pub fn messages_are_delivered(
+        &self,
+        topic_id: &str,
+        queue_id: &str,
+        amount: usize,
+        redelivered_amount: usize,
+    ) {
+        self.delivered_messages
+            .with_label_values(&[topic_id, queue_id])
+            .inc_by(amount as u64);
+
+        if redelivered_amount > 0 {
+            self.redelivered_messages
+                .with_label_values(&[topic_id, queue_id])
+                .inc_by(redelivered_amount as u64);
+        }
+    }
+
+    pub fn messages_are_confirmed(
+        &self,
+        topic_id: &str,
+        queue_id: &str,
+        ok_amount: usize,
+        fail_amount: usize,
+    ) {
+        if ok_amount > 0 {
+            self.confirmed_ok_messages
+                .with_label_values(&[topic_id, queue_id])
+                .inc_by(ok_amount as u64);
+        }
+
+        if fail_amount > 0 {
+            self.confirmed_fail_messages
+                .with_label_values(&[topic_id, queue_id])
+                .inc_by(fail_amount as u64);
+        }
+    }
+
+    pub fn observe_delivery_round_trip(&self, topic_id: &str, queue_id: &str, duration: Duration) {
+        self.delivery_round_trip
+            .with_label_values(&[topic_id, queue_id])
+            .observe(duration.as_secs_f64());
+    }
+
+    pub fn observe_persistence_save(&self, topic_id: &str, duration: Duration) {
+        self.persistence_save_duration
+            .with_label_values(&[topic_id])
+            .observe(duration.as_secs_f64());
+    }
+
+    pub fn observe_persistence_load(&self, topic_id: &str, duration: Duration) {
+        self.persistence_load_duration
+            .with_label_values(&[topic_id])
+            .observe(duration.as_secs_f64());
+    }
+
+    pub fn session_packet_is_received(&self, session_name: &str, packet: &str) {
+        self.session_received_packets
+            .with_label_values(&[session_name, packet])
+            .inc();
+    }
+
+    pub fn session_messages_are_published(&self, session_name: &str, amount: usize) {
+        self.session_published_messages
+            .with_label_values(&[session_name])
+            .inc_by(amount as u64);
+    }
+
+    pub fn session_reject_is_sent(&self, session_name: &str) {
+        self.session_sent_rejects
+            .with_label_values(&[session_name])
+            .inc();
+    }
+
+    pub fn session_messages_are_delivered(&self, session_name: &str, amount: usize) {
+        self.session_delivered_messages
+            .with_label_values(&[session_name])
+            .inc_by(amount as u64);
+    }
+
+    pub fn session_confirmation_is_received(
+        &self,
+        session_name: &str,
+        confirmed: usize,
+        latency: Duration,
+    ) {
+        self.session_confirmations
+            .with_label_values(&[session_name])
+            .inc();
+
+        self.session_confirmed_messages
+            .with_label_values(&[session_name])
+            .inc_by(confirmed as u64);
+
+        self.session_confirmation_seconds
+            .with_label_values(&[session_name])
+            .inc_by(latency.as_secs_f64());
+    }
+
     pub fn build(&self) -> Vec<u8> {
         let mut buffer = vec![];
         let encoder = TextEncoder::new();
@@ -100,15 +402,48 @@ impl PrometheusMetrics {
         buffer
     }
 
-    pub fn queue_is_deleted(&self, topic_id: &str, queue_id: &str) {
+    pub fn topic_is_deleted(&self, topic_id: &str) {
+        let _ = self.topic_data_size.remove_label_values(&[topic_id]);
+        let _ = self.persist_queue_size.remove_label_values(&[topic_id]);
+        let _ = self.topic_messages_amount.remove_label_values(&[topic_id]);
+        let _ = self.published_messages.remove_label_values(&[topic_id]);
+        let _ = self
+            .persistence_save_duration
+            .remove_label_values(&[topic_id]);
+        let _ = self
+            .persistence_load_duration
+            .remove_label_values(&[topic_id]);
+    }
+
+    pub fn queue_is_deleted(&self, topic_id: &str, queue_id: &str) -> Result<(), String> {
         let result = self
             .topic_queue_size
             .remove_label_values(&[topic_id, queue_id]);
 
-        println!(
-            "Error during removing topic_queue_size from metrics for Topic:{}, Queue:{}: {:?}",
-            topic_id, queue_id, result
-        );
+        for gauge in [&self.queue_lag_messages, &self.queue_lag_seconds] {
+            let _ = gauge.remove_label_values(&[topic_id, queue_id]);
+        }
+
+        for counter in [
+            &self.purged_messages,
+            &self.delivered_messages,
+            &self.redelivered_messages,
+            &self.confirmed_ok_messages,
+            &self.confirmed_fail_messages,
+        ] {
+            let _ = counter.remove_label_values(&[topic_id, queue_id]);
+        }
+
+        let _ = self
+            .delivery_round_trip
+            .remove_label_values(&[topic_id, queue_id]);
+
+        result.map_err(|err| {
+            format!(
+                "Error during removing topic_queue_size from metrics for Topic:{}, Queue:{}: {:?}",
+                topic_id, queue_id, err
+            )
+        })
     }
 }
 
@@ -129,6 +464,25 @@ fn create_topic_queue_size() -> IntGaugeVec {
     IntGaugeVec::new(gauge_opts, lables).unwrap()
 }
 
+fn create_queue_lag_messages() -> IntGaugeVec {
+    let gauge_opts = Opts::new(
+        "queue_lag_messages",
+        "Amount of messages between the oldest queued message and the last published one",
+    );
+
+    let lables = &["topic", "queue"];
+
+    IntGaugeVec::new(gauge_opts, lables).unwrap()
+}
+
+fn create_queue_lag_seconds() -> IntGaugeVec {
+    let gauge_opts = Opts::new("queue_lag_seconds", "Age of the oldest queued message");
+
+    let lables = &["topic", "queue"];
+
+    IntGaugeVec::new(gauge_opts, lables).unwrap()
+}
+
 fn create_permanent_queues_without_subscribers() -> IntGauge {
     IntGauge::new(
         "permanent_queues_without_subscribers",
@@ -153,6 +507,81 @@ fn create_topic_messages_amount() -> IntGaugeVec {
     IntGaugeVec::new(gauge_opts, lables).unwrap()
 }
 
+fn create_purged_messages() -> IntCounterVec {
+    let counter_opts = Opts::new("purged_messages_total", "Messages dropped by queue purge");
+
+    let lables = &["topic", "queue"];
+
+    IntCounterVec::new(counter_opts, lables).unwrap()
+}
+
+fn create_published_messages() -> IntCounterVec {
+    let counter_opts = Opts::new("published_messages_total", "Messages published to topic");
+
+    let lables = &["topic"];
+
+    IntCounterVec::new(counter_opts, lables).unwrap()
+}
+
+fn create_queue_messages_counter(name: &str, help: &str) -> IntCounterVec {
+    let counter_opts = Opts::new(name, help);
+
+    let lables = &["topic", "queue"];
+
+    IntCounterVec::new(counter_opts, lables).unwrap()
+}
+
+fn create_delivery_round_trip() -> HistogramVec {
+    let histogram_opts = HistogramOpts::new(
+        "delivery_round_trip_seconds",
+        "Time between sending a package to subscriber and its confirmation",
+    )
+    .buckets(vec![
+        0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0,
+    ]);
+
+    let lables = &["topic", "queue"];
+
+    HistogramVec::new(histogram_opts, lables).unwrap()
+}
+
+fn create_persistence_duration(name: &str, help: &str) -> HistogramVec {
+    let histogram_opts = HistogramOpts::new(name, help).buckets(vec![
+        0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+    ]);
+
+    let lables = &["topic"];
+
+    HistogramVec::new(histogram_opts, lables).unwrap()
+}
+
 fn create_topics_without_queues() -> IntGauge {
     IntGauge::new("topics_without_queues", "Topics without queues").unwrap()
 }
+
+fn create_session_received_packets() -> IntCounterVec {
+    let counter_opts = Opts::new(
+        "session_received_packets_total",
+        "Packets received from sessions with the name by packet type",
+    );
+
+    let lables = &["session", "packet"];
+
+    IntCounterVec::new(counter_opts, lables).unwrap()
+}
+
+fn create_session_counter(name: &str, help: &str) -> IntCounterVec {
+    let counter_opts = Opts::new(name, help);
+
+    let lables = &["session"];
+
+    IntCounterVec::new(counter_opts, lables).unwrap()
+}
+
+fn create_session_seconds_counter(name: &str, help: &str) -> CounterVec {
+    let counter_opts = Opts::new(name, help);
+
+    let lables = &["session"];
+
+    CounterVec::new(counter_opts, lables).unwrap()
+}
@@ -1,10 +1,20 @@
 use std::sync::Arc;
 
-use super::AppContext;
+use super::{logs::SystemProcess, AppContext};
 
 pub async fn execute(app: Arc<AppContext>) {
     empty_persistence_queues(app.clone()).await;
     make_last_topcis_and_queues_persist(app.clone()).await;
+
+    if let Err(err) = app.metrics_history.save().await {
+        app.logs.add_error(
+            None,
+            SystemProcess::System,
+            "shutdown".to_string(),
+            "Can not save metrics history".to_string(),
+            Some(err),
+        );
+    }
 }
 
 async fn empty_persistence_queues(app: Arc<AppContext>) {
@@ -15,20 +25,46 @@ async fn empty_persistence_queues(app: Arc<AppContext>) {
         };
 
         while metrics.persist_size > 0 {
-            println!(
-                "Topic {} has {} messages to persist. Doing Force Persist",
-                topic.topic_id, metrics.persist_size
+            app.logs.add_info(
+                Some(topic.topic_id.to_string()),
+                SystemProcess::System,
+                "shutdown".to_string(),
+                format!(
+                    "Topic {} has {} messages to persist. Doing Force Persist",
+                    topic.topic_id, metrics.persist_size
+                ),
+                None,
             );
 
             crate::operations::save_messages_for_topic(&app, &topic).await;
         }
 
-        println!("Topic {} has no messages to persist.", topic.topic_id);
+        app.logs.add_info(
+            Some(topic.topic_id.to_string()),
+            SystemProcess::System,
+            "shutdown".to_string(),
+            format!("Topic {} has no messages to persist.", topic.topic_id),
+            None,
+        );
     }
 }
 
 async fn make_last_topcis_and_queues_persist(app: Arc<AppContext>) {
-    println!("Making final topics and queues snapshot save");
+    app.logs.add_info(
+        None,
+        SystemProcess::System,
+        "shutdown".to_string(),
+        "Making final topics and queues snapshot save".to_string(),
+        None,
+    );
+
     crate::operations::persist_topics_and_queues(&app).await;
-    println!("Final topics and queues snapshot save is done");
+
+    app.logs.add_info(
+        None,
+        SystemProcess::System,
+        "shutdown".to_string(),
+        "Final topics and queues snapshot save is done".to_string(),
+        None,
+    );
 }
@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use rust_extensions::date_time::{AtomicDateTimeAsMicroseconds, DateTimeAsMicroseconds};
+
+const MISSED_TICKS_TO_BE_DEAD: u32 = 3;
+const MIN_DEAD_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub struct TimerHeartbeat {
+    pub name: &'static str,
+    interval: Duration,
+    last_tick: AtomicDateTimeAsMicroseconds,
+}
+
+impl TimerHeartbeat {
+    pub fn new(name: &'static str, interval: Duration) -> Self {
+        Self {
+            name,
+            interval,
+            last_tick: AtomicDateTimeAsMicroseconds::now(),
+        }
+    }
+
+    pub fn tick(&self) {
+        self.last_tick.update(DateTimeAsMicroseconds::now());
+    }
+
+    #[cfg(test)]
+    pub fn set_last_tick(&self, value: DateTimeAsMicroseconds) {
+        self.last_tick.update(value);
+    }
+
+    pub fn get_last_tick(&self) -> DateTimeAsMicroseconds {
+        self.last_tick.as_date_time()
+    }
+
+    pub fn is_alive(&self, now: DateTimeAsMicroseconds) -> bool {
+        let dead_timeout = (self.interval * MISSED_TICKS_TO_BE_DEAD).max(MIN_DEAD_TIMEOUT);
+
+        now.duration_since(self.get_last_tick())
+            .as_positive_or_zero()
+            <= dead_timeout
+    }
+}
+
+pub struct TimerHeartbeats {
+    pub metrics: TimerHeartbeat,
+    pub persist: TimerHeartbeat,
+    pub gc: TimerHeartbeat,
+    pub dead_subscribers: TimerHeartbeat,
+}
+
+impl TimerHeartbeats {
+    pub fn new(persist_timer_interval: Duration) -> Self {
+        Self {
+            metrics: TimerHeartbeat::new("Metrics", Duration::from_secs(1)),
+            persist: TimerHeartbeat::new("PersistTopicsAndQueues", persist_timer_interval),
+            gc: TimerHeartbeat::new("GC", persist_timer_interval),
+            dead_subscribers: TimerHeartbeat::new("DeadSubscribers", Duration::from_secs(10)),
+        }
+    }
+
+    pub fn get_all(&self) -> [&TimerHeartbeat; 4] {
+        [
+            &self.metrics,
+            &self.persist,
+            &self.gc,
+            &self.dead_subscribers,
+        ]
+    }
+
+    ///Persistence timer waits for the persistence service. A slow persistence must not restart the service
+    pub fn get_for_liveness(&self) -> [&TimerHeartbeat; 3] {
+        [&self.metrics, &self.gc, &self.dead_subscribers]
+    }
+}
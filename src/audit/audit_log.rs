@@ -0,0 +1,239 @@
+use std::{collections::VecDeque, sync::Arc, sync::Mutex};
+
+use rust_extensions::date_time::DateTimeAsMicroseconds;
+use tokio::{
+    io::AsyncWriteExt,
+    sync::mpsc::{UnboundedReceiver, UnboundedSender},
+};
+
+use crate::app::logs::{Logs, SystemProcess};
+
+use super::{AuditCaller, AuditRecord};
+
+const MAX_RECORDS_IN_MEMORY: usize = 10_000;
+
+struct AuditLogData {
+    records: VecDeque<AuditRecord>,
+    next_id: u64,
+}
+
+pub struct AuditLog {
+    pub trust_user_header: bool,
+    data: Mutex<AuditLogData>,
+    file_sender: Option<UnboundedSender<String>>,
+}
+
+impl AuditLog {
+    pub fn new(file_name: Option<String>, trust_user_header: bool, logs: Arc<Logs>) -> Self {
+        //Records are written to the file by a separate task. Http actions do not wait for the disk
+        let file_sender = file_name.map(|file_name| {
+            let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+            tokio::spawn(audit_file_writer(file_name, receiver, logs));
+            sender
+        });
+
+        Self {
+            trust_user_header,
+            data: Mutex::new(AuditLogData {
+                records: VecDeque::new(),
+                next_id: 1,
+            }),
+            file_sender,
+        }
+    }
+
+    pub fn add(
+        &self,
+        caller: AuditCaller,
+        action: &str,
+        target: String,
+        before: Option<String>,
+        after: Option<String>,
+    ) {
+        let mut data = self.data.lock().unwrap();
+
+        let record = AuditRecord {
+            id: data.next_id,
+            time: DateTimeAsMicroseconds::now(),
+            caller,
+            action: action.to_string(),
+            target,
+            before,
+            after,
+        };
+
+        data.next_id += 1;
+
+        if let Some(file_sender) = &self.file_sender {
+            let _ = file_sender.send(record.to_json_line());
+        }
+
+        data.records.push_back(record);
+
+        while data.records.len() > MAX_RECORDS_IN_MEMORY {
+            data.records.pop_front();
+        }
+    }
+
+    pub fn get(
+        &self,
+        action: Option<&str>,
+        target: Option<&str>,
+        limit: usize,
+    ) -> Vec<AuditRecord> {
+        let data = self.data.lock().unwrap();
+
+        data.records
+            .iter()
+            .rev()
+            .filter(|record| match action {
+                Some(action) => record.action.eq_ignore_ascii_case(action),
+                None => true,
+            })
+            .filter(|record| match target {
+                Some(target) => record.target.contains(target),
+                None => true,
+            })
+            .take(limit)
+            .cloned()
+            .collect()
+    }
+}
+
+async fn audit_file_writer(
+    file_name: String,
+    mut receiver: UnboundedReceiver<String>,
+    logs: Arc<Logs>,
+) {
+    let file = tokio::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(file_name.as_str())
+        .await;
+
+    let mut file = match file {
+        Ok(file) => file,
+        Err(err) => {
+            logs.add_error(
+                None,
+                SystemProcess::System,
+                "audit_log".to_string(),
+                format!("Can not open audit file {}", file_name),
+                Some(format!("{:?}", err)),
+            );
+            return;
+        }
+    };
+
+    while let Some(line) = receiver.recv().await {
+        let mut lines = line;
+        lines.push('\n');
+
+        while let Ok(line) = receiver.try_recv() {
+            lines.push_str(line.as_str());
+            lines.push('\n');
+        }
+
+        let mut result = file.write_all(lines.as_bytes()).await;
+
+        if result.is_ok() {
+            result = file.flush().await;
+        }
+
+        if let Err(err) = result {
+            logs.add_error(
+                None,
+                SystemProcess::System,
+                "audit_log".to_string(),
+                format!("Can not write to audit file {}", file_name),
+                Some(format!("{:?}. Records: {}", err, lines)),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::{app::logs::LogSinks, settings::SettingsModel};
+
+    use super::*;
+
+    fn create_logs() -> Arc<Logs> {
+        let settings = SettingsModel::create_test_settings(16);
+        Arc::new(Logs::new(LogSinks::new(&settings.log_sinks)))
+    }
+
+    fn caller() -> AuditCaller {
+        AuditCaller {
+            ip: "127.0.0.1".to_string(),
+            identity: Some("admin".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_records_are_returned_newest_first_and_filtered() {
+        let audit = AuditLog::new(None, false, create_logs());
+
+        audit.add(
+            caller(),
+            "DeleteQueue",
+            "topic/queue".to_string(),
+            Some("1-10".to_string()),
+            None,
+        );
+        audit.add(caller(), "KickSession", "5".to_string(), None, None);
+        audit.add(
+            caller(),
+            "DeleteQueue",
+            "topic/queue2".to_string(),
+            None,
+            None,
+        );
+
+        let result = audit.get(None, None, 10);
+        assert_eq!(
+            vec![3, 2, 1],
+            result.iter().map(|r| r.id).collect::<Vec<_>>()
+        );
+
+        let result = audit.get(Some("deletequeue"), Some("topic/"), 1);
+        assert_eq!(1, result.len());
+        assert_eq!("topic/queue2", result[0].target);
+    }
+
+    #[tokio::test]
+    async fn test_records_are_appended_to_file() {
+        let file_name = std::env::temp_dir().join(format!("audit-{}.log", uuid::Uuid::new_v4()));
+        let file_name = file_name.to_str().unwrap().to_string();
+
+        let audit = AuditLog::new(Some(file_name.clone()), false, create_logs());
+
+        audit.add(caller(), "KickSession", "5".to_string(), None, None);
+        audit.add(caller(), "KickSession", "6".to_string(), None, None);
+
+        let mut lines = Vec::new();
+
+        for _ in 0..100 {
+            let content = tokio::fs::read_to_string(file_name.as_str())
+                .await
+                .unwrap_or_default();
+
+            lines = content.lines().map(|line| line.to_string()).collect();
+
+            if lines.len() == 2 {
+                break;
+            }
+
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        assert_eq!(2, lines.len());
+
+        let json: serde_json::Value = serde_json::from_str(lines[1].as_str()).unwrap();
+        assert_eq!("6", json["target"]);
+
+        let _ = tokio::fs::remove_file(file_name.as_str()).await;
+    }
+}
@@ -0,0 +1,51 @@
+use rust_extensions::date_time::DateTimeAsMicroseconds;
+use serde::Serialize;
+
+#[derive(Debug, Clone)]
+pub struct AuditCaller {
+    pub ip: String,
+    pub identity: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AuditRecord {
+    pub id: u64,
+    pub time: DateTimeAsMicroseconds,
+    pub caller: AuditCaller,
+    pub action: String,
+    pub target: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+#[derive(Serialize)]
+struct JsonAuditRecord<'s> {
+    id: u64,
+    time: String,
+    ip: &'s str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    identity: Option<&'s str>,
+    action: &'s str,
+    target: &'s str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    before: Option<&'s str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    after: Option<&'s str>,
+}
+
+impl AuditRecord {
+    pub fn to_json_line(&self) -> String {
+        let item = JsonAuditRecord {
+            id: self.id,
+            time: self.time.to_rfc3339(),
+            ip: self.caller.ip.as_str(),
+            identity: self.caller.identity.as_deref(),
+            action: self.action.as_str(),
+            target: self.target.as_str(),
+            before: self.before.as_deref(),
+            after: self.after.as_deref(),
+        };
+
+        serde_json::to_string(&item).unwrap()
+    }
+}
@@ -0,0 +1,4 @@
+mod audit_log;
+mod audit_record;
+pub use audit_log::AuditLog;
+pub use audit_record::{AuditCaller, AuditRecord};
@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use rust_extensions::{date_time::DateTimeAsMicroseconds, MyTimerTick};
+
+use crate::app::AppContext;
+
+pub struct AlertsTimer {
+    app: Arc<AppContext>,
+}
+
+impl AlertsTimer {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+#[async_trait::async_trait]
+impl MyTimerTick for AlertsTimer {
+    async fn tick(&self) {
+        let now = DateTimeAsMicroseconds::now();
+
+        let conditions = crate::operations::alerts::collect_conditions(&self.app, now).await;
+
+        let events = self.app.alerts.evaluate(conditions, now);
+
+        crate::operations::alerts::notify(&self.app, events);
+    }
+}
@@ -21,10 +21,23 @@ impl MyTimerTick for DeadSubscribersKickerTimer {
 
         for topic in topics {
             if let Some(dead_subscribers) = topic
-                .find_subscribers_dead_on_delivery(self.app.delivery_timeout)
+                .find_subscribers_dead_on_delivery(self.app.get_delivery_timeout())
                 .await
             {
                 for dead_subscriber in dead_subscribers {
+                    let events = self.app.alerts.subscriber_is_kicked(
+                        topic.topic_id.as_str(),
+                        dead_subscriber.queue_id.as_str(),
+                        format!(
+                            "Connection {} with subscriber {} is kicked. No delivery confirmation for {:?}",
+                            dead_subscriber.session.id,
+                            dead_subscriber.subscriber_id,
+                            dead_subscriber.duration
+                        ),
+                    );
+
+                    crate::operations::alerts::notify(&self.app, events);
+
                     self.app.logs.add_info(
                         Some(topic.topic_id.to_string()),
                         crate::app::logs::SystemProcess::Timer,
@@ -40,5 +53,7 @@ impl MyTimerTick for DeadSubscribersKickerTimer {
                 }
             }
         }
+
+        self.app.timer_heartbeats.dead_subscribers.tick();
     }
 }
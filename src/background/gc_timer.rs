@@ -29,5 +29,6 @@ impl MyTimerTick for GcTimer {
         }
 
         crate::operations::gc_http_connections(self.app.as_ref()).await;
+        self.app.timer_heartbeats.gc.tick();
     }
 }
@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use rust_extensions::MyTimerTick;
+
+use crate::app::AppContext;
+
+pub struct MetricsHistorySaveTimer {
+    app: Arc<AppContext>,
+}
+
+impl MetricsHistorySaveTimer {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+#[async_trait::async_trait]
+impl MyTimerTick for MetricsHistorySaveTimer {
+    async fn tick(&self) {
+        if let Err(err) = self.app.metrics_history.save().await {
+            self.app.logs.add_error(
+                None,
+                crate::app::logs::SystemProcess::Timer,
+                "save_metrics_history".to_string(),
+                "Can not save metrics history".to_string(),
+                Some(err),
+            );
+        }
+    }
+}
@@ -1,29 +1,111 @@
-use std::sync::Arc;
-
-use rust_extensions::MyTimerTick;
-
-use crate::app::AppContext;
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use rust_extensions::{date_time::DateTimeAsMicroseconds, MyTimerTick};
+use sysinfo::SystemExt;
+
+use crate::{
+    app::AppContext,
+    http::controllers::status::{
+        index_models::{
+            get_active_alerts, get_persistence_version, StatusJsonResult, SystemStatusModel,
+        },
+        models::{
+            queue_model::QueuesJsonResult,
+            session_model::SessionsJsonResult,
+            topic_model::{TopicJsonContract, TopicsJsonResult},
+        },
+    },
+    topics::QueueLag,
+};
 
 pub struct MetricsTimer {
     app: Arc<AppContext>,
+    sys_info: Mutex<sysinfo::System>,
 }
 
 impl MetricsTimer {
     pub fn new(app: Arc<AppContext>) -> Self {
-        Self { app }
+        Self {
+            app,
+            sys_info: Mutex::new(sysinfo::System::new()),
+        }
+    }
+
+    fn get_system_status(&self) -> SystemStatusModel {
+        let mut sys_info = self.sys_info.lock().unwrap();
+        sys_info.refresh_memory();
+        SystemStatusModel::from_sys_info(&sys_info)
+    }
+
+    async fn collect_delivery_metrics(&self, now: DateTimeAsMicroseconds) {
+        let mut delivery_rates = Vec::new();
+
+        for topic in self.app.topic_list.get_all().await {
+            let topic_data = topic.get_access().await;
+
+            for queue in topic_data.queues.get_all() {
+                let mut queue_delivered_amount = 0;
+
+                if let Some(subscribers) = queue.subscribers.get_all() {
+                    for subscriber in subscribers {
+                        queue_delivered_amount += subscriber.metrics.delivered_amount.value;
+                    }
+                }
+
+                delivery_rates.push((
+                    topic.topic_id.to_string(),
+                    queue.queue_id.to_string(),
+                    queue_delivered_amount,
+                ));
+            }
+        }
+
+        for (topic_id, queue_id, delivered_amount) in delivery_rates {
+            self.app
+                .metrics_history
+                .put_delivery_rate(topic_id.as_str(), queue_id.as_str(), now, delivered_amount)
+                .await;
+        }
     }
 }
 
 #[async_trait::async_trait]
 impl MyTimerTick for MetricsTimer {
     async fn tick(&self) {
+        let now = DateTimeAsMicroseconds::now();
+
+        self.collect_delivery_metrics(now).await;
+
         self.app.topic_list.one_second_tick().await;
         self.app.sessions.one_second_tick().await;
 
         let mut permanent_queues_without_subscribers = 0;
         let mut topics_without_queues = 0;
 
-        for topic in self.app.topic_list.get_all().await {
+        let (snapshot_id, topics) = self.app.topic_list.get_all_with_snapshot_id().await;
+
+        let mut status = if self.app.status_events.has_viewers() {
+            Some(StatusJsonResult {
+                topics: TopicsJsonResult {
+                    snapshot_id,
+                    items: Vec::new(),
+                },
+                queues: HashMap::new(),
+                sessions: SessionsJsonResult::new(self.app.as_ref()).await,
+                system: self.get_system_status(),
+                persistence_version: get_persistence_version(self.app.as_ref()).await,
+                alerts: get_active_alerts(self.app.as_ref()),
+            })
+        } else {
+            None
+        };
+
+        let mut publish_rates = Vec::new();
+
+        for topic in topics {
             let mut topic_data = topic.get_access().await;
 
             let mut queues_count = 0;
@@ -37,6 +119,12 @@ impl MyTimerTick for MetricsTimer {
                     queue_size,
                 );
 
+                self.app.prometheus.update_queue_lag(
+                    topic.topic_id.as_str(),
+                    queue.queue_id.as_str(),
+                    &QueueLag::new(&topic_data, queue, now),
+                );
+
                 let is_permanent = match &queue.queue_type {
                     my_service_bus_shared::queue::TopicQueueType::Permanent => true,
                     my_service_bus_shared::queue::TopicQueueType::DeleteOnDisconnect => false,
@@ -61,6 +149,34 @@ impl MyTimerTick for MetricsTimer {
                 .update_topic_size_metrics(topic.topic_id.as_str(), &metrics);
 
             topic_data.metrics.one_second_tick(&metrics);
+
+            publish_rates.push((
+                topic.topic_id.to_string(),
+                topic_data.metrics.messages_per_second,
+            ));
+
+            if let Some(status) = &mut status {
+                status.queues.insert(
+                    topic_data.topic_id.to_string(),
+                    QueuesJsonResult::new(&topic_data),
+                );
+
+                status
+                    .topics
+                    .items
+                    .push(TopicJsonContract::new(&topic_data));
+            }
+        }
+
+        for (topic_id, messages_per_second) in publish_rates {
+            self.app
+                .metrics_history
+                .put_publish_rate(topic_id.as_str(), now, messages_per_second)
+                .await;
+        }
+
+        if let Some(status) = status {
+            self.app.status_events.publish(status);
         }
 
         self.app
@@ -70,5 +186,7 @@ impl MyTimerTick for MetricsTimer {
         self.app
             .prometheus
             .update_topics_without_queues(topics_without_queues);
+
+        self.app.timer_heartbeats.metrics.tick();
     }
 }
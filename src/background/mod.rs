@@ -1,10 +1,18 @@
+mod alerts_timer;
 mod dead_subscribers_kicker;
 mod gc_timer;
 mod immediatly_persist_event_loop;
+mod metrics_history_save_timer;
 mod metrics_timer;
 mod persist_topics_and_queues;
+mod settings_reload_timer;
+mod telemetry_export_timer;
+pub use alerts_timer::AlertsTimer;
 pub use dead_subscribers_kicker::DeadSubscribersKickerTimer;
 pub use gc_timer::GcTimer;
 pub use immediatly_persist_event_loop::ImmediatlyPersistEventLoop;
+pub use metrics_history_save_timer::MetricsHistorySaveTimer;
 pub use metrics_timer::MetricsTimer;
 pub use persist_topics_and_queues::PersistTopicsAndQueuesTimer;
+pub use settings_reload_timer::SettingsReloadTimer;
+pub use telemetry_export_timer::TelemetryExportTimer;
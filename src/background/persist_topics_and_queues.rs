@@ -18,5 +18,6 @@ impl PersistTopicsAndQueuesTimer {
 impl MyTimerTick for PersistTopicsAndQueuesTimer {
     async fn tick(&self) {
         crate::operations::persist_topics_and_queues(&self.app).await;
+        self.app.timer_heartbeats.persist.tick();
     }
 }
@@ -0,0 +1,51 @@
+use std::{sync::atomic::Ordering, sync::Arc, time::SystemTime};
+
+use rust_extensions::MyTimerTick;
+use tokio::sync::Mutex;
+
+use crate::{app::AppContext, settings::SettingsModel};
+
+pub struct SettingsReloadTimer {
+    app: Arc<AppContext>,
+    modified: Mutex<Option<SystemTime>>,
+}
+
+impl SettingsReloadTimer {
+    pub async fn new(app: Arc<AppContext>) -> Self {
+        let modified = SettingsModel::get_modified_time(&app.cli_args).await;
+
+        Self {
+            app,
+            modified: Mutex::new(modified),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl MyTimerTick for SettingsReloadTimer {
+    async fn tick(&self) {
+        let modified = SettingsModel::get_modified_time(&self.app.cli_args).await;
+
+        let file_is_changed = {
+            let mut write_access = self.modified.lock().await;
+            let file_is_changed = modified.is_some() && *write_access != modified;
+            *write_access = modified;
+            file_is_changed
+        };
+
+        if self
+            .app
+            .settings_reload_requested
+            .swap(false, Ordering::SeqCst)
+        {
+            let _ = crate::operations::reload_settings(self.app.as_ref(), "SIGHUP").await;
+            return;
+        }
+
+        if file_is_changed {
+            let _ =
+                crate::operations::reload_settings(self.app.as_ref(), "settings file is changed")
+                    .await;
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use std::{sync::Arc, time::Duration};
+
+use rust_extensions::MyTimerTick;
+
+use crate::app::AppContext;
+
+const EXPORT_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub struct TelemetryExportTimer {
+    app: Arc<AppContext>,
+}
+
+impl TelemetryExportTimer {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+#[async_trait::async_trait]
+impl MyTimerTick for TelemetryExportTimer {
+    async fn tick(&self) {
+        let endpoint = match self.app.telemetry.get_endpoint() {
+            Some(endpoint) => endpoint,
+            None => return,
+        };
+
+        let spans = self.app.telemetry.get_spans_to_export();
+
+        if spans.is_empty() {
+            return;
+        }
+
+        let spans_amount = spans.len();
+
+        let export_future = crate::telemetry::export_spans(
+            endpoint,
+            self.app.telemetry.service_name.as_str(),
+            spans,
+        );
+
+        let err = match tokio::time::timeout(EXPORT_TIMEOUT, export_future).await {
+            Ok(Ok(())) => return,
+            Ok(Err(err)) => err,
+            Err(_) => format!("Timeout {:?}", EXPORT_TIMEOUT),
+        };
+
+        self.app.logs.add_error(
+            None,
+            crate::app::logs::SystemProcess::Timer,
+            "export_spans".to_string(),
+            format!("Can not export {} spans to {}", spans_amount, endpoint),
+            Some(err),
+        );
+    }
+}
@@ -0,0 +1,28 @@
+use std::{net::SocketAddr, sync::Arc};
+
+use crate::{
+    app::{logs::SystemProcess, AppContext},
+    my_service_bus_grpc::my_service_bus_grpc_service_server::MyServiceBusGrpcServiceServer,
+};
+
+use super::MyServiceBusGrpcService;
+
+pub fn start(app: Arc<AppContext>, addr: SocketAddr) {
+    tokio::spawn(async move {
+        let service = MyServiceBusGrpcService::new(app.clone());
+
+        let result = tonic::transport::Server::builder()
+            .add_service(MyServiceBusGrpcServiceServer::new(service))
+            .serve(addr)
+            .await;
+
+        if let Err(err) = result {
+            app.logs.add_fatal_error(
+                SystemProcess::System,
+                "GrpcServer".to_string(),
+                format!("Grpc server stopped. Err: {:?}", err),
+                Some(format!("Addr:{}", addr)),
+            );
+        }
+    });
+}
@@ -18,6 +18,7 @@ impl From<&TopicSnapshot> for TopicAndQueuesSnapshotGrpcModel {
             topic_id: src.topic_id.to_string(),
             message_id: src.message_id,
             queue_snapshots: src.queues.iter().map(|itm| itm.into()).collect(),
+            read_only: src.read_only,
         }
     }
 }
@@ -32,6 +33,7 @@ impl From<TopicAndQueuesSnapshotGrpcModel> for TopicSnapshot {
                 .into_iter()
                 .map(|itm| itm.into())
                 .collect(),
+            read_only: src.read_only,
         }
     }
 }
@@ -42,6 +44,7 @@ impl From<&TopicQueueSnapshot> for QueueSnapshotGrpcModel {
             queue_id: src.queue_id.to_string(),
             queue_type: src.queue_type.into_u8() as i32,
             ranges: src.ranges.iter().map(|itm| itm.into()).collect(),
+            paused: src.paused,
         }
     }
 }
@@ -52,6 +55,7 @@ impl From<QueueSnapshotGrpcModel> for TopicQueueSnapshot {
             queue_id: src.queue_id.to_string(),
             queue_type: TopicQueueType::from_u8(src.queue_type as u8),
             ranges: src.ranges.into_iter().map(|itm| itm.into()).collect(),
+            paused: src.paused,
         }
     }
 }
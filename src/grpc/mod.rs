@@ -1 +1,7 @@
+mod grpc_server;
 pub mod mappers;
+mod my_service_bus_grpc_service;
+pub mod service_mappers;
+
+pub use grpc_server::start;
+pub use my_service_bus_grpc_service::MyServiceBusGrpcService;
@@ -0,0 +1,446 @@
+use std::sync::Arc;
+
+use my_service_bus_tcp_shared::MessageToPublishTcpContract;
+use tokio_stream::{wrappers::UnboundedReceiverStream, Stream, StreamExt};
+use tonic::{Request, Response, Status, Streaming};
+
+use crate::{
+    app::{logs::SystemProcess, AppContext},
+    my_service_bus_grpc::{
+        my_service_bus_grpc_service_server::MyServiceBusGrpcService as MyServiceBusGrpcServiceTrait,
+        subscribe_grpc_request::Command, ConfirmDeliveryGrpcCommand, ConfirmationTypeGrpcEnum,
+        PublishGrpcRequest, PublishStreamGrpcResponse, SubscribeGrpcCommand, SubscribeGrpcRequest,
+        SubscribeGrpcResponse,
+    },
+    operations::{self, OperationFailResult},
+    sessions::{GrpcConnectionData, GrpcDeliverySender, MyServiceBusSession},
+};
+
+pub struct MyServiceBusGrpcService {
+    app: Arc<AppContext>,
+}
+
+impl MyServiceBusGrpcService {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+
+    async fn create_session<T>(
+        &self,
+        request: &Request<T>,
+        sender: Option<GrpcDeliverySender>,
+    ) -> Arc<MyServiceBusSession> {
+        let name = get_metadata(request, "session-name");
+        let version = get_metadata(request, "client-version");
+
+        self.app
+            .sessions
+            .add_grpc(GrpcConnectionData::new(
+                name,
+                version,
+                request.remote_addr(),
+                None,
+                sender,
+                self.app.logs.clone(),
+            ))
+            .await
+    }
+
+    // Unary publishes of the same client share one session which is collected after inactivity
+    async fn get_publisher_session<T>(&self, request: &Request<T>) -> Arc<MyServiceBusSession> {
+        let name = get_metadata(request, "session-name");
+        let version = get_metadata(request, "client-version");
+        let addr = request.remote_addr();
+
+        let publisher_key = format!(
+            "{}|{}|{}",
+            name.as_deref().unwrap_or_default(),
+            version.as_deref().unwrap_or_default(),
+            addr.map(|addr| addr.ip().to_string()).unwrap_or_default()
+        );
+
+        self.app
+            .sessions
+            .get_or_add_grpc_publisher(publisher_key.as_str(), || {
+                GrpcConnectionData::new(
+                    name,
+                    version,
+                    addr,
+                    Some(publisher_key.clone()),
+                    None,
+                    self.app.logs.clone(),
+                )
+            })
+            .await
+    }
+
+    async fn remove_session(&self, session: &MyServiceBusSession) {
+        session.disconnect().await;
+        if let Some(session) = self.app.sessions.remove_grpc(session.id).await {
+            operations::sessions::disconnect(self.app.as_ref(), session.as_ref()).await;
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl MyServiceBusGrpcServiceTrait for MyServiceBusGrpcService {
+    type SubscribeStream = UnboundedReceiverStream<Result<SubscribeGrpcResponse, Status>>;
+
+    async fn publish(&self, request: Request<PublishGrpcRequest>) -> Result<Response<()>, Status> {
+        let session = self.get_publisher_session(&request).await;
+
+        publish(&self.app, &session, request.into_inner()).await?;
+
+        Ok(Response::new(()))
+    }
+
+    async fn publish_stream(
+        &self,
+        request: Request<Streaming<PublishGrpcRequest>>,
+    ) -> Result<Response<PublishStreamGrpcResponse>, Status> {
+        let session = self.create_session(&request, None).await;
+
+        let mut stream = request.into_inner();
+
+        let mut response = PublishStreamGrpcResponse {
+            published_requests: 0,
+            published_messages: 0,
+        };
+
+        let mut result = Ok(());
+
+        while let Some(item) = stream.next().await {
+            match item {
+                Ok(item) => {
+                    let messages_amount = item.messages.len() as i64;
+
+                    if let Err(err) = publish(&self.app, &session, item).await {
+                        result = Err(err.into());
+                        break;
+                    }
+
+                    response.published_requests += 1;
+                    response.published_messages += messages_amount;
+                }
+                Err(status) => {
+                    result = Err(status);
+                    break;
+                }
+            }
+        }
+
+        self.remove_session(session.as_ref()).await;
+
+        result?;
+
+        Ok(Response::new(response))
+    }
+
+    async fn subscribe(
+        &self,
+        request: Request<Streaming<SubscribeGrpcRequest>>,
+    ) -> Result<Response<Self::SubscribeStream>, Status> {
+        let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+
+        let session = self.create_session(&request, Some(sender.clone())).await;
+
+        tokio::spawn(serve_subscribe_stream(
+            self.app.clone(),
+            session,
+            request.into_inner(),
+            sender,
+        ));
+
+        Ok(Response::new(UnboundedReceiverStream::new(receiver)))
+    }
+}
+
+async fn serve_subscribe_stream<
+    TStream: Stream<Item = Result<SubscribeGrpcRequest, Status>> + Unpin,
+>(
+    app: Arc<AppContext>,
+    session: Arc<MyServiceBusSession>,
+    mut stream: TStream,
+    sender: GrpcDeliverySender,
+) {
+    while let Some(item) = stream.next().await {
+        let command = match item {
+            Ok(item) => item.command,
+            Err(_) => break,
+        };
+
+        let result = match command {
+            Some(Command::Subscribe(cmd)) => subscribe(&app, &session, cmd).await,
+            Some(Command::Confirm(cmd)) => {
+                confirm_delivery(&app, cmd).await.map_err(|err| err.into())
+            }
+            None => Ok(()),
+        };
+
+        if let Err(status) = result {
+            app.logs.add_error(
+                None,
+                SystemProcess::DeliveryOperation,
+                "Grpc Subscribe".to_string(),
+                format!("Err: {:?}", status),
+                Some(format!("SessionId:{}", session.id)),
+            );
+
+            let _ = sender.send(Err(status));
+            break;
+        }
+    }
+
+    session.disconnect().await;
+    if let Some(session) = app.sessions.remove_grpc(session.id).await {
+        operations::sessions::disconnect(app.as_ref(), session.as_ref()).await;
+    }
+}
+
+async fn subscribe(
+    app: &Arc<AppContext>,
+    session: &Arc<MyServiceBusSession>,
+    cmd: SubscribeGrpcCommand,
+) -> Result<(), Status> {
+    let queue_type = super::service_mappers::to_topic_queue_type(cmd.queue_type)?;
+
+    operations::subscriber::subscribe_to_queue(
+        app,
+        cmd.topic_id,
+        cmd.queue_id,
+        queue_type,
+        session,
+    )
+    .await?;
+
+    Ok(())
+}
+
+async fn publish(
+    app: &Arc<AppContext>,
+    session: &MyServiceBusSession,
+    request: PublishGrpcRequest,
+) -> Result<(), OperationFailResult> {
+    let messages: Vec<MessageToPublishTcpContract> =
+        request.messages.into_iter().map(|itm| itm.into()).collect();
+
+    if let crate::sessions::SessionConnection::Grpc(data) = &session.connection {
+        data.update_read_amount(messages.iter().map(|itm| itm.content.len()).sum());
+    }
+
+    operations::publisher::publish(
+        app,
+        request.topic_id.as_str(),
+        messages,
+        request.persist_immediately,
+        session,
+    )
+    .await
+}
+
+async fn confirm_delivery(
+    app: &Arc<AppContext>,
+    cmd: ConfirmDeliveryGrpcCommand,
+) -> Result<(), OperationFailResult> {
+    let topic_id = cmd.topic_id.as_str();
+    let queue_id = cmd.queue_id.as_str();
+
+    if cmd.confirmation_type == ConfirmationTypeGrpcEnum::AllFail as i32 {
+        return operations::delivery_confirmation::all_fail(
+            app,
+            topic_id,
+            queue_id,
+            cmd.confirmation_id,
+        )
+        .await;
+    }
+
+    if cmd.confirmation_type == ConfirmationTypeGrpcEnum::SomeConfirmed as i32 {
+        return operations::delivery_confirmation::some_messages_are_confirmed(
+            app,
+            topic_id,
+            queue_id,
+            cmd.confirmation_id,
+            super::service_mappers::to_queue_with_intervals(cmd.delivered),
+        )
+        .await;
+    }
+
+    if cmd.confirmation_type == ConfirmationTypeGrpcEnum::Intermediary as i32 {
+        return operations::delivery_confirmation::intermediary_confirm(
+            app,
+            topic_id,
+            queue_id,
+            cmd.confirmation_id,
+            super::service_mappers::to_queue_with_intervals(cmd.delivered),
+        )
+        .await;
+    }
+
+    operations::delivery_confirmation::all_confirmed(app, topic_id, queue_id, cmd.confirmation_id)
+        .await
+}
+
+fn get_metadata<T>(request: &Request<T>, key: &str) -> Option<String> {
+    let value = request.metadata().get(key)?;
+    let value = value.to_str().ok()?;
+    Some(value.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use my_service_bus_tcp_shared::MessageToPublishTcpContract;
+
+    use crate::{
+        my_service_bus_grpc::{MessageToPublishGrpcModel, QueueTypeGrpcEnum},
+        sessions::{SessionId, TestConnectionData},
+        settings::{CliArgs, SettingsModel},
+    };
+
+    use super::*;
+
+    const TOPIC_NAME: &str = "test-topic";
+    const QUEUE_NAME: &str = "test-queue";
+
+    async fn create_app() -> Arc<AppContext> {
+        let settings = SettingsModel::create_test_settings(16);
+        let app = Arc::new(AppContext::new(&settings, CliArgs::default()).await);
+
+        crate::operations::publisher::create_topic_if_not_exists(&app, None, TOPIC_NAME)
+            .await
+            .unwrap();
+
+        app
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_grpc_publish() {
+        let app = create_app().await;
+
+        let service = MyServiceBusGrpcService::new(app.clone());
+
+        for _ in 0..2 {
+            let request = PublishGrpcRequest {
+                topic_id: TOPIC_NAME.to_string(),
+                persist_immediately: false,
+                messages: vec![
+                    MessageToPublishGrpcModel {
+                        content: vec![0u8, 1u8, 2u8],
+                        headers: vec![],
+                    },
+                    MessageToPublishGrpcModel {
+                        content: vec![3u8, 4u8, 5u8],
+                        headers: vec![],
+                    },
+                ],
+            };
+
+            service.publish(Request::new(request)).await.unwrap();
+        }
+
+        let topic = app.topic_list.get(TOPIC_NAME).await.unwrap();
+        assert_eq!(4, topic.get_message_id().await);
+
+        // Unary publishes of the same client share one session
+        let (_, sessions) = app.sessions.get_snapshot().await;
+        assert_eq!(1, sessions.len());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_grpc_subscribe_and_confirm() {
+        const PUBLISHER_SESSION_ID: SessionId = 13;
+
+        let app = create_app().await;
+
+        let publisher = app
+            .sessions
+            .add_test(TestConnectionData::new(PUBLISHER_SESSION_ID, "127.0.0.1"))
+            .await;
+
+        let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+        let (request_sender, request_receiver) = tokio::sync::mpsc::unbounded_channel();
+
+        let session = app
+            .sessions
+            .add_grpc(GrpcConnectionData::new(
+                Some("grpc-client".to_string()),
+                None,
+                None,
+                None,
+                Some(sender.clone()),
+                app.logs.clone(),
+            ))
+            .await;
+
+        let subscribe_task = tokio::spawn(serve_subscribe_stream(
+            app.clone(),
+            session,
+            UnboundedReceiverStream::new(request_receiver),
+            sender,
+        ));
+
+        request_sender
+            .send(Ok(SubscribeGrpcRequest {
+                command: Some(Command::Subscribe(SubscribeGrpcCommand {
+                    topic_id: TOPIC_NAME.to_string(),
+                    queue_id: QUEUE_NAME.to_string(),
+                    queue_type: QueueTypeGrpcEnum::Permanent as i32,
+                })),
+            }))
+            .unwrap();
+
+        let topic = app.topic_list.get(TOPIC_NAME).await.unwrap();
+
+        while topic.get_access().await.queues.get(QUEUE_NAME).is_none() {
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+
+        let messages = vec![
+            MessageToPublishTcpContract {
+                headers: None,
+                content: vec![0u8, 1u8, 2u8],
+            },
+            MessageToPublishTcpContract {
+                headers: None,
+                content: vec![3u8, 4u8, 5u8],
+            },
+        ];
+
+        crate::operations::publisher::publish(&app, TOPIC_NAME, messages, false, &publisher)
+            .await
+            .unwrap();
+
+        let delivery = receiver.recv().await.unwrap().unwrap().delivery.unwrap();
+
+        assert_eq!(TOPIC_NAME, delivery.topic_id);
+        assert_eq!(QUEUE_NAME, delivery.queue_id);
+        assert_eq!(2, delivery.messages.len());
+
+        request_sender
+            .send(Ok(SubscribeGrpcRequest {
+                command: Some(Command::Confirm(ConfirmDeliveryGrpcCommand {
+                    topic_id: TOPIC_NAME.to_string(),
+                    queue_id: QUEUE_NAME.to_string(),
+                    confirmation_id: delivery.confirmation_id,
+                    confirmation_type: ConfirmationTypeGrpcEnum::AllConfirmed as i32,
+                    delivered: vec![],
+                })),
+            }))
+            .unwrap();
+
+        drop(request_sender);
+        subscribe_task.await.unwrap();
+
+        {
+            let topic_data = topic.get_access().await;
+            let queue = topic_data.queues.get(QUEUE_NAME).unwrap();
+
+            assert_eq!(0, queue.get_on_delivery());
+            assert_eq!(0, queue.get_queue_size());
+        }
+
+        let (_, sessions) = app.sessions.get_snapshot().await;
+        assert_eq!(1, sessions.len());
+        assert_eq!(PUBLISHER_SESSION_ID, sessions[0].id);
+    }
+}
@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+
+use my_service_bus_shared::{
+    queue::TopicQueueType,
+    queue_with_intervals::{QueueIndexRange, QueueWithIntervals},
+};
+use my_service_bus_tcp_shared::{MessageToPublishTcpContract, TcpContract};
+
+use crate::{my_service_bus_grpc::*, operations::OperationFailResult};
+
+// Packets which can not be sent over gRPC are returned back by name
+pub fn to_subscribe_grpc_response(
+    tcp_contract: TcpContract,
+) -> Result<Result<SubscribeGrpcResponse, tonic::Status>, &'static str> {
+    match tcp_contract {
+        TcpContract::NewMessages {
+            topic_id,
+            queue_id,
+            confirmation_id,
+            messages,
+        } => {
+            let messages = messages
+                .into_iter()
+                .map(|itm| DeliveredMessageGrpcModel {
+                    message_id: itm.id,
+                    attempt_no: itm.attempt_no,
+                    content: itm.content,
+                    headers: to_headers_grpc_model(itm.headers),
+                })
+                .collect();
+
+            Ok(Ok(SubscribeGrpcResponse {
+                delivery: Some(MessagesDeliveryGrpcEvent {
+                    topic_id,
+                    queue_id,
+                    confirmation_id,
+                    messages,
+                }),
+            }))
+        }
+        TcpContract::Reject { message } => Ok(Err(tonic::Status::aborted(message))),
+        other => Err(crate::tcp::incoming_packets::get_contract_name(&other)),
+    }
+}
+
+fn to_headers_grpc_model(src: Option<HashMap<String, String>>) -> Vec<MessageHeaderGrpcModel> {
+    match src {
+        Some(headers) => headers
+            .into_iter()
+            .map(|(key, value)| MessageHeaderGrpcModel { key, value })
+            .collect(),
+        None => vec![],
+    }
+}
+
+impl From<MessageToPublishGrpcModel> for MessageToPublishTcpContract {
+    fn from(src: MessageToPublishGrpcModel) -> Self {
+        let headers = if src.headers.len() == 0 {
+            None
+        } else {
+            let mut result = HashMap::new();
+            for itm in src.headers {
+                result.insert(itm.key, itm.value);
+            }
+            Some(result)
+        };
+
+        Self {
+            headers,
+            content: src.content,
+        }
+    }
+}
+
+pub fn to_topic_queue_type(src: i32) -> Result<TopicQueueType, tonic::Status> {
+    if src == QueueTypeGrpcEnum::Permanent as i32 {
+        return Ok(TopicQueueType::Permanent);
+    }
+
+    if src == QueueTypeGrpcEnum::DeleteOnDisconnect as i32 {
+        return Ok(TopicQueueType::DeleteOnDisconnect);
+    }
+
+    if src == QueueTypeGrpcEnum::PermanentWithSingleConnection as i32 {
+        return Ok(TopicQueueType::PermanentWithSingleConnection);
+    }
+
+    Err(tonic::Status::invalid_argument(format!(
+        "Invalid queue type {}",
+        src
+    )))
+}
+
+pub fn to_queue_with_intervals(src: Vec<QueueIndexRangeGrpcModel>) -> QueueWithIntervals {
+    let ranges = src
+        .into_iter()
+        .map(|itm| QueueIndexRange {
+            from_id: itm.from_id,
+            to_id: itm.to_id,
+        })
+        .collect();
+
+    QueueWithIntervals::restore(ranges)
+}
+
+impl From<OperationFailResult> for tonic::Status {
+    fn from(src: OperationFailResult) -> Self {
+        match src {
+            OperationFailResult::TopicNotFound { topic_id } => {
+                tonic::Status::not_found(format!("Topic {} is not found", topic_id))
+            }
+            OperationFailResult::QueueNotFound { queue_id } => {
+                tonic::Status::not_found(format!("Queue {} is not found", queue_id))
+            }
+            OperationFailResult::TopicIsReadOnly { topic_id } => {
+                tonic::Status::failed_precondition(format!("Topic {} is read-only", topic_id))
+            }
+            OperationFailResult::ShuttingDown => {
+                tonic::Status::unavailable("Service bus is shutting down")
+            }
+            _ => tonic::Status::failed_precondition(format!("{:?}", src)),
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
+use my_http_server_swagger::http_route;
+
+use std::sync::Arc;
+
+use super::models::{AlertJsonModel, AlertsJsonResult, GetAlertsInputContract};
+use crate::app::AppContext;
+
+const DEFAULT_LIMIT: usize = 100;
+
+#[http_route(
+    method: "GET",
+    route: "/Alerts",
+    controller: "Alerts",
+    description: "Firing alerts and history of alert events. Newest first",
+    input_data: "GetAlertsInputContract",
+    result: [
+        {status_code: 200, description: "Alerts", model: "AlertsJsonResult"},
+    ]
+)]
+pub struct GetAlertsAction {
+    app: Arc<AppContext>,
+}
+
+impl GetAlertsAction {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+async fn handle_request(
+    action: &GetAlertsAction,
+    input_data: GetAlertsInputContract,
+    _ctx: &mut HttpContext,
+) -> Result<HttpOkResult, HttpFailResult> {
+    let result = AlertsJsonResult {
+        active: action
+            .app
+            .alerts
+            .get_active()
+            .into_iter()
+            .map(AlertJsonModel::new)
+            .collect(),
+        history: action
+            .app
+            .alerts
+            .get_history(input_data.limit.unwrap_or(DEFAULT_LIMIT))
+            .into_iter()
+            .map(AlertJsonModel::new)
+            .collect(),
+    };
+
+    HttpOutput::as_json(result).into_ok_result(true).into()
+}
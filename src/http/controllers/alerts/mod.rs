@@ -0,0 +1,4 @@
+mod get_alerts_action;
+mod models;
+pub use get_alerts_action::GetAlertsAction;
+pub use models::AlertJsonModel;
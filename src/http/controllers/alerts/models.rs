@@ -0,0 +1,44 @@
+use my_http_server_swagger::{MyHttpInput, MyHttpObjectStructure};
+use serde::{Deserialize, Serialize};
+
+use crate::alerts::AlertEvent;
+
+#[derive(MyHttpInput)]
+pub struct GetAlertsInputContract {
+    #[http_query(name = "limit"; description = "Max amount of history events. 100 by default")]
+    pub limit: Option<usize>,
+}
+
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct AlertJsonModel {
+    pub id: u64,
+    pub rule: String,
+    #[serde(rename = "type")]
+    pub rule_type: String,
+    pub target: String,
+    pub status: String,
+    pub message: String,
+    pub started: String,
+    pub resolved: Option<String>,
+}
+
+impl AlertJsonModel {
+    pub fn new(src: AlertEvent) -> Self {
+        Self {
+            id: src.id,
+            rule: src.rule,
+            rule_type: src.rule_type.to_string(),
+            target: src.target,
+            status: src.status.as_str().to_string(),
+            message: src.message,
+            started: src.started.to_rfc3339(),
+            resolved: src.resolved.map(|resolved| resolved.to_rfc3339()),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct AlertsJsonResult {
+    pub active: Vec<AlertJsonModel>,
+    pub history: Vec<AlertJsonModel>,
+}
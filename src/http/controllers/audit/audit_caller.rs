@@ -0,0 +1,66 @@
+use my_http_server::HttpContext;
+
+use crate::{app::AppContext, audit::AuditCaller};
+
+const IDENTITY_HEADER: &str = "x-user";
+const HTTP_SESSION_HEADER: &str = "authorization";
+
+pub async fn get_audit_caller(app: &AppContext, ctx: &HttpContext) -> AuditCaller {
+    //X-Forwarded-For can be forged by any caller. The socket peer is recorded instead
+    let ip = ctx.request.addr.ip().to_string();
+
+    create_audit_caller(app, ip, |name| ctx.request.get_optional_header(name)).await
+}
+
+async fn create_audit_caller<'s>(
+    app: &AppContext,
+    ip: String,
+    get_header: impl Fn(&str) -> Option<&'s str>,
+) -> AuditCaller {
+    //The header can be forged by any caller. It is trusted only behind a proxy which sets it
+    if app.audit.trust_user_header {
+        if let Some(identity) = get_header(IDENTITY_HEADER) {
+            return AuditCaller {
+                ip,
+                identity: Some(identity.to_string()),
+            };
+        }
+    }
+
+    let mut identity = None;
+
+    if let Some(session_id) = get_header(HTTP_SESSION_HEADER) {
+        if let Some(session) = app.sessions.get_http(session_id).await {
+            identity = session.get_name_and_client_version().await.0;
+        }
+    }
+
+    AuditCaller { ip, identity }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::settings::{CliArgs, SettingsModel};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_forged_headers_are_not_recorded() {
+        let settings = SettingsModel::create_test_settings(16);
+        let app = AppContext::new(&settings, CliArgs::default()).await;
+
+        let mut headers = HashMap::new();
+        headers.insert("x-forwarded-for", "10.0.0.1");
+        headers.insert(IDENTITY_HEADER, "admin");
+
+        let caller = create_audit_caller(&app, "192.168.1.5".to_string(), |name| {
+            headers.get(name).copied()
+        })
+        .await;
+
+        assert_eq!("192.168.1.5", caller.ip);
+        assert_eq!(None, caller.identity);
+    }
+}
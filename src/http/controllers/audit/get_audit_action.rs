@@ -0,0 +1,46 @@
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
+use my_http_server_swagger::http_route;
+
+use std::sync::Arc;
+
+use super::models::{AuditRecordJsonModel, GetAuditInputContract};
+use crate::app::AppContext;
+
+const DEFAULT_LIMIT: usize = 100;
+
+#[http_route(
+    method: "GET",
+    route: "/Audit",
+    controller: "Audit",
+    description: "Administrative actions. Newest first",
+    input_data: "GetAuditInputContract",
+    result: [
+        {status_code: 200, description: "Audit records", model_as_array: "AuditRecordJsonModel"},
+    ]
+)]
+pub struct GetAuditAction {
+    app: Arc<AppContext>,
+}
+
+impl GetAuditAction {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+async fn handle_request(
+    action: &GetAuditAction,
+    input_data: GetAuditInputContract,
+    _ctx: &mut HttpContext,
+) -> Result<HttpOkResult, HttpFailResult> {
+    let records = action.app.audit.get(
+        input_data.action.as_deref(),
+        input_data.target.as_deref(),
+        input_data.limit.unwrap_or(DEFAULT_LIMIT),
+    );
+
+    let result: Vec<AuditRecordJsonModel> =
+        records.into_iter().map(AuditRecordJsonModel::new).collect();
+
+    HttpOutput::as_json(result).into_ok_result(true).into()
+}
@@ -0,0 +1,5 @@
+mod audit_caller;
+mod get_audit_action;
+mod models;
+pub use audit_caller::get_audit_caller;
+pub use get_audit_action::GetAuditAction;
@@ -0,0 +1,41 @@
+use my_http_server_swagger::{MyHttpInput, MyHttpObjectStructure};
+use serde::{Deserialize, Serialize};
+
+use crate::audit::AuditRecord;
+
+#[derive(MyHttpInput)]
+pub struct GetAuditInputContract {
+    #[http_query(name = "action"; description = "Filter by action. For instance DeleteQueue")]
+    pub action: Option<String>,
+    #[http_query(name = "target"; description = "Filter by target. Records whose target contains the value are returned")]
+    pub target: Option<String>,
+    #[http_query(name = "limit"; description = "Max amount of records. 100 by default")]
+    pub limit: Option<usize>,
+}
+
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct AuditRecordJsonModel {
+    pub id: u64,
+    pub time: String,
+    pub ip: String,
+    pub identity: Option<String>,
+    pub action: String,
+    pub target: String,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+impl AuditRecordJsonModel {
+    pub fn new(src: AuditRecord) -> Self {
+        Self {
+            id: src.id,
+            time: src.time.to_rfc3339(),
+            ip: src.caller.ip,
+            identity: src.caller.identity,
+            action: src.action,
+            target: src.target,
+            before: src.before,
+            after: src.after,
+        }
+    }
+}
@@ -10,7 +10,34 @@ pub fn build(app: Arc<AppContext>) -> ControllersMiddleware {
     let topics_controller = Arc::new(super::topics::TopicsController::new(app.clone()));
 
     controllers.register_get_action(topics_controller.clone());
-    controllers.register_post_action(topics_controller);
+    controllers.register_post_action(topics_controller.clone());
+    controllers.register_delete_action(topics_controller);
+
+    controllers.register_get_action(Arc::new(super::topics::GetTopicMessagesAction::new(
+        app.clone(),
+    )));
+    controllers.register_get_action(Arc::new(super::topics::GetMessageTraceAction::new(
+        app.clone(),
+    )));
+    controllers.register_post_action(Arc::new(super::topics::SetTopicReadOnlyAction::new(
+        app.clone(),
+    )));
+
+    controllers.register_get_action(Arc::new(super::topology::GetTopologyDiffAction::new(
+        app.clone(),
+    )));
+
+    controllers.register_get_action(Arc::new(
+        super::metrics_history::GetMetricsHistoryAction::new(app.clone()),
+    ));
+
+    controllers.register_get_action(Arc::new(super::audit::GetAuditAction::new(app.clone())));
+
+    controllers.register_get_action(Arc::new(super::alerts::GetAlertsAction::new(app.clone())));
+
+    controllers.register_post_action(Arc::new(super::settings::ReloadSettingsAction::new(
+        app.clone(),
+    )));
 
     controllers.register_delete_action(Arc::new(super::sessions::DeleteSessionAction::new(
         app.clone(),
@@ -21,13 +48,29 @@ pub fn build(app: Arc<AppContext>) -> ControllersMiddleware {
 
     controllers.register_post_action(Arc::new(super::greeting::PingAction::new(app.clone())));
 
+    controllers.register_get_action(Arc::new(super::health::LiveAction::new(app.clone())));
+    controllers.register_get_action(Arc::new(super::health::ReadyAction::new(app.clone())));
+
     let status_controller = super::status::status_controller::StatusController::new(app.clone());
     controllers.register_get_action(Arc::new(status_controller));
 
     controllers.register_get_action(Arc::new(super::queues::GetQueuesAction::new(app.clone())));
+    controllers.register_get_action(Arc::new(super::queues::PeekQueueAction::new(app.clone())));
     controllers.register_post_action(Arc::new(super::queues::SetMessageIdAction::new(
         app.clone(),
     )));
+    controllers.register_post_action(Arc::new(super::queues::RequeueMessagesAction::new(
+        app.clone(),
+    )));
+    controllers.register_post_action(Arc::new(super::queues::SkipMessagesAction::new(
+        app.clone(),
+    )));
+    controllers.register_post_action(Arc::new(super::queues::PurgeQueueAction::new(app.clone())));
+    controllers.register_post_action(Arc::new(super::queues::PauseQueueAction::new(app.clone())));
+    controllers.register_post_action(Arc::new(super::queues::ResumeQueueAction::new(app.clone())));
+    controllers.register_post_action(Arc::new(super::queues::DeclareQueueAction::new(
+        app.clone(),
+    )));
 
     controllers
         .register_delete_action(Arc::new(super::queues::DeleteQueueAction::new(app.clone())));
@@ -0,0 +1,53 @@
+use my_http_server_swagger::MyHttpObjectStructure;
+use my_service_bus_shared::MySbMessageContent;
+use serde::{Deserialize, Serialize};
+
+pub const MAX_MESSAGES_AMOUNT: usize = 1000;
+pub const DEFAULT_MESSAGES_AMOUNT: usize = 20;
+
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct MessageHeaderJsonModel {
+    pub key: String,
+    pub value: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct MessageJsonModel {
+    pub id: i64,
+    pub time: String,
+    pub headers: Option<Vec<MessageHeaderJsonModel>>,
+    pub content: String,
+    #[serde(rename = "contentEncoding")]
+    pub content_encoding: String,
+}
+
+impl MessageJsonModel {
+    pub fn new(src: MySbMessageContent) -> Self {
+        let headers = src.headers.map(|headers| {
+            headers
+                .into_iter()
+                .map(|(key, value)| MessageHeaderJsonModel { key, value })
+                .collect()
+        });
+
+        let (content, content_encoding) = match String::from_utf8(src.content) {
+            Ok(content) => (content, "utf8"),
+            Err(err) => (base64::encode(err.into_bytes()), "base64"),
+        };
+
+        Self {
+            id: src.id,
+            time: src.time.to_rfc3339(),
+            headers,
+            content,
+            content_encoding: content_encoding.to_string(),
+        }
+    }
+}
+
+pub fn get_messages_amount(amount: Option<usize>) -> usize {
+    match amount {
+        Some(amount) => amount.min(MAX_MESSAGES_AMOUNT),
+        None => DEFAULT_MESSAGES_AMOUNT,
+    }
+}
@@ -1 +1,2 @@
 pub mod response;
+pub mod messages;
@@ -1,5 +1,5 @@
 use super::{super::contracts::response, models::EnableDebugInputModel};
-use crate::app::AppContext;
+use crate::{app::AppContext, http::controllers::audit::get_audit_caller};
 use async_trait::async_trait;
 
 use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
@@ -41,10 +41,20 @@ impl PostAction for DebugModeController {
     async fn handle_request(&self, ctx: &mut HttpContext) -> Result<HttpOkResult, HttpFailResult> {
         let input_data = EnableDebugInputModel::parse_http_input(ctx).await?;
 
+        let before = get_debug_topic_and_queue(self.app.as_ref()).await;
+
         self.app
             .set_debug_topic_and_queue(input_data.topic_id.as_ref(), input_data.queue_id.as_ref())
             .await;
 
+        self.app.audit.add(
+            get_audit_caller(self.app.as_ref(), ctx).await,
+            "EnableDebug",
+            format!("{}/{}", input_data.topic_id, input_data.queue_id),
+            before,
+            Some("Enabled".to_string()),
+        );
+
         HttpOutput::Empty.into_ok_result(true).into()
     }
 }
@@ -65,9 +75,27 @@ impl DeleteAction for DebugModeController {
         .into()
     }
 
-    async fn handle_request(&self, _ctx: &mut HttpContext) -> Result<HttpOkResult, HttpFailResult> {
+    async fn handle_request(&self, ctx: &mut HttpContext) -> Result<HttpOkResult, HttpFailResult> {
+        let before = get_debug_topic_and_queue(self.app.as_ref()).await;
+
         self.app.disable_debug_topic_and_queue().await;
 
+        self.app.audit.add(
+            get_audit_caller(self.app.as_ref(), ctx).await,
+            "DisableDebug",
+            before.clone().unwrap_or_default(),
+            before,
+            None,
+        );
+
         HttpOutput::Empty.into_ok_result(true).into()
     }
 }
+
+async fn get_debug_topic_and_queue(app: &AppContext) -> Option<String> {
+    let read_access = app.debug_topic_and_queue.read().await;
+
+    read_access
+        .as_ref()
+        .map(|debug| format!("{}/{}", debug.topic_id, debug.queue_id))
+}
@@ -0,0 +1,129 @@
+use std::time::Duration;
+
+use rust_extensions::date_time::DateTimeAsMicroseconds;
+
+use crate::app::{AppContext, TimerHeartbeat};
+
+use super::models::HealthCheckJsonModel;
+
+const PERSISTENCE_CHECK_TIMEOUT: Duration = Duration::from_secs(3);
+
+pub fn check_timers(
+    heartbeats: &[&TimerHeartbeat],
+    now: DateTimeAsMicroseconds,
+) -> Vec<HealthCheckJsonModel> {
+    heartbeats
+        .iter()
+        .map(|heartbeat| {
+            let name = format!("Timer{}", heartbeat.name);
+            let message = format!("Last tick: {}", heartbeat.get_last_tick().to_rfc3339());
+
+            if heartbeat.is_alive(now) {
+                HealthCheckJsonModel::ok(name.as_str(), Some(message))
+            } else {
+                HealthCheckJsonModel::fail(name.as_str(), message)
+            }
+        })
+        .collect()
+}
+
+pub fn check_initialized(app: &AppContext) -> HealthCheckJsonModel {
+    if app.states.is_shutting_down() {
+        return HealthCheckJsonModel::fail("Initialized", "Shutting down".to_string());
+    }
+
+    if app.states.is_initialized() {
+        HealthCheckJsonModel::ok("Initialized", None)
+    } else {
+        HealthCheckJsonModel::fail("Initialized", "Not initialized yet".to_string())
+    }
+}
+
+pub async fn check_persistence(app: &AppContext) -> HealthCheckJsonModel {
+    let result = tokio::time::timeout(
+        PERSISTENCE_CHECK_TIMEOUT,
+        app.messages_pages_repo.get_persistence_version(),
+    )
+    .await;
+
+    match result {
+        Ok(Some(version)) => HealthCheckJsonModel::ok("Persistence", Some(version)),
+        Ok(None) => {
+            HealthCheckJsonModel::fail("Persistence", "Persistence is not reachable".to_string())
+        }
+        Err(_) => HealthCheckJsonModel::fail(
+            "Persistence",
+            format!(
+                "Persistence did not answer within {:?}",
+                PERSISTENCE_CHECK_TIMEOUT
+            ),
+        ),
+    }
+}
+
+pub async fn check_persist_queue(app: &AppContext) -> HealthCheckJsonModel {
+    let mut persist_size = 0;
+
+    for topic in app.topic_list.get_all().await {
+        let topic_data = topic.get_access().await;
+        persist_size += topic_data.metrics.size_metrics.persist_size;
+    }
+
+    let message = format!(
+        "Messages to persist: {}. Max: {}",
+        persist_size, app.health_max_persist_queue_size
+    );
+
+    if persist_size <= app.health_max_persist_queue_size {
+        HealthCheckJsonModel::ok("PersistQueue", Some(message))
+    } else {
+        HealthCheckJsonModel::fail("PersistQueue", message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::TimerHeartbeats;
+
+    use super::super::models::HealthJsonResult;
+    use super::*;
+
+    fn get_status(heartbeats: &[&TimerHeartbeat], now: DateTimeAsMicroseconds) -> String {
+        HealthJsonResult::new(check_timers(heartbeats, now)).status
+    }
+
+    #[test]
+    fn test_stalled_persistence_fails_readiness_but_not_liveness() {
+        let heartbeats = TimerHeartbeats::new(Duration::from_secs(1));
+        let now = DateTimeAsMicroseconds::now();
+
+        assert_eq!("Ok", get_status(&heartbeats.get_for_liveness(), now));
+        assert_eq!("Ok", get_status(&heartbeats.get_all(), now));
+
+        let minute_ago = DateTimeAsMicroseconds::new(now.unix_microseconds - 60_000_000);
+
+        heartbeats.persist.set_last_tick(minute_ago);
+
+        assert_eq!("Ok", get_status(&heartbeats.get_for_liveness(), now));
+        assert_eq!("Fail", get_status(&heartbeats.get_all(), now));
+
+        heartbeats.gc.set_last_tick(minute_ago);
+
+        assert_eq!("Fail", get_status(&heartbeats.get_for_liveness(), now));
+    }
+
+    #[test]
+    fn test_timer_is_dead_after_missed_ticks() {
+        let heartbeats = TimerHeartbeats::new(Duration::from_secs(1));
+        let now = DateTimeAsMicroseconds::now();
+
+        heartbeats.dead_subscribers.set_last_tick(now);
+
+        // Dead subscribers timer ticks every 10 seconds, so it is dead after 3 missed ticks
+        let later = DateTimeAsMicroseconds::new(now.unix_microseconds + 29_000_000);
+        assert_eq!("Ok", get_status(&[&heartbeats.dead_subscribers], later));
+
+        let later = DateTimeAsMicroseconds::new(now.unix_microseconds + 31_000_000);
+        assert_eq!("Fail", get_status(&[&heartbeats.dead_subscribers], later));
+    }
+}
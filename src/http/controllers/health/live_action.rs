@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult};
+use my_http_server_swagger::http_route;
+use rust_extensions::date_time::DateTimeAsMicroseconds;
+
+use crate::app::AppContext;
+
+use super::models::HealthJsonResult;
+
+#[http_route(
+    method: "GET",
+    route: "/health/live",
+    controller: "Health",
+    description: "Liveness probe. Checks that the background timers are ticking",
+    result: [
+        {status_code: 200, description: "Service is alive", model: "HealthJsonResult"},
+        {status_code: 503, description: "Service is degraded", model: "HealthJsonResult"},
+    ]
+)]
+pub struct LiveAction {
+    app: Arc<AppContext>,
+}
+
+impl LiveAction {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+async fn handle_request(
+    action: &LiveAction,
+    _ctx: &mut HttpContext,
+) -> Result<HttpOkResult, HttpFailResult> {
+    let checks = super::health_checks::check_timers(
+        &action.app.timer_heartbeats.get_for_liveness(),
+        DateTimeAsMicroseconds::now(),
+    );
+
+    HealthJsonResult::new(checks).into_http_result()
+}
@@ -0,0 +1,6 @@
+mod health_checks;
+mod live_action;
+mod models;
+mod ready_action;
+pub use live_action::LiveAction;
+pub use ready_action::ReadyAction;
@@ -0,0 +1,69 @@
+use my_http_server::{HttpFailResult, HttpOkResult, HttpOutput, WebContentType};
+use my_http_server_swagger::MyHttpObjectStructure;
+use serde::{Deserialize, Serialize};
+
+const STATUS_OK: &str = "Ok";
+const STATUS_FAIL: &str = "Fail";
+
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct HealthCheckJsonModel {
+    pub name: String,
+    pub status: String,
+    pub message: Option<String>,
+}
+
+impl HealthCheckJsonModel {
+    pub fn ok(name: &str, message: Option<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            status: STATUS_OK.to_string(),
+            message,
+        }
+    }
+
+    pub fn fail(name: &str, message: String) -> Self {
+        Self {
+            name: name.to_string(),
+            status: STATUS_FAIL.to_string(),
+            message: Some(message),
+        }
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.status == STATUS_OK
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct HealthJsonResult {
+    pub status: String,
+    pub checks: Vec<HealthCheckJsonModel>,
+}
+
+impl HealthJsonResult {
+    pub fn new(checks: Vec<HealthCheckJsonModel>) -> Self {
+        let status = if checks.iter().all(|check| check.is_ok()) {
+            STATUS_OK
+        } else {
+            STATUS_FAIL
+        };
+
+        Self {
+            status: status.to_string(),
+            checks,
+        }
+    }
+
+    pub fn into_http_result(self) -> Result<HttpOkResult, HttpFailResult> {
+        if self.status == STATUS_OK {
+            return HttpOutput::as_json(self).into_ok_result(false).into();
+        }
+
+        Err(HttpFailResult {
+            content_type: WebContentType::Json,
+            status_code: 503,
+            content: serde_json::to_vec(&self).unwrap(),
+            write_telemetry: false,
+        })
+    }
+}
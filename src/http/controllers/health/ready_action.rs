@@ -0,0 +1,49 @@
+use std::sync::Arc;
+
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult};
+use my_http_server_swagger::http_route;
+use rust_extensions::date_time::DateTimeAsMicroseconds;
+
+use crate::app::AppContext;
+
+use super::{health_checks, models::HealthJsonResult};
+
+#[http_route(
+    method: "GET",
+    route: "/health/ready",
+    controller: "Health",
+    description: "Readiness probe. Checks initialization, persistence, persist queue and background timers",
+    result: [
+        {status_code: 200, description: "Service is ready", model: "HealthJsonResult"},
+        {status_code: 503, description: "Service is degraded", model: "HealthJsonResult"},
+    ]
+)]
+pub struct ReadyAction {
+    app: Arc<AppContext>,
+}
+
+impl ReadyAction {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+async fn handle_request(
+    action: &ReadyAction,
+    _ctx: &mut HttpContext,
+) -> Result<HttpOkResult, HttpFailResult> {
+    let app = action.app.as_ref();
+
+    let mut checks = vec![
+        health_checks::check_initialized(app),
+        health_checks::check_persistence(app).await,
+        health_checks::check_persist_queue(app).await,
+    ];
+
+    checks.extend(health_checks::check_timers(
+        &app.timer_heartbeats.get_all(),
+        DateTimeAsMicroseconds::now(),
+    ));
+
+    HealthJsonResult::new(checks).into_http_result()
+}
@@ -0,0 +1,86 @@
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
+
+use my_http_server_swagger::http_route;
+use rust_extensions::date_time::DateTimeAsMicroseconds;
+
+use std::sync::Arc;
+
+use super::models::{
+    GetMetricsHistoryInputContract, MetricPointJsonModel, MetricsHistoryJsonResult,
+};
+use crate::{
+    app::AppContext,
+    metric_data::{get_unix_seconds, MetricsResolution},
+};
+
+#[http_route(
+    method: "GET",
+    route: "/Metrics/History",
+    controller: "Metrics",
+    description: "Publish rate of a topic or delivery rate of a queue for the time range",
+    input_data: "GetMetricsHistoryInputContract",
+    result: [
+        {status_code: 200, description: "Messages per second", model: "MetricsHistoryJsonResult"},
+        {status_code: 403, description: "Invalid resolution"},
+    ]
+)]
+pub struct GetMetricsHistoryAction {
+    app: Arc<AppContext>,
+}
+
+impl GetMetricsHistoryAction {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+async fn handle_request(
+    action: &GetMetricsHistoryAction,
+    input_data: GetMetricsHistoryInputContract,
+    _ctx: &mut HttpContext,
+) -> Result<HttpOkResult, HttpFailResult> {
+    let resolution = match &input_data.resolution {
+        Some(resolution) => MetricsResolution::parse(resolution.as_str()).ok_or_else(|| {
+            HttpFailResult::as_forbidden(Some(format!(
+                "Invalid resolution {}. Supported values: 1s, 1m, 1h",
+                resolution
+            )))
+        })?,
+        None => MetricsResolution::Minute,
+    };
+
+    let from = input_data.from.unwrap_or(0);
+    let to = input_data
+        .to
+        .unwrap_or(get_unix_seconds(DateTimeAsMicroseconds::now()));
+
+    let points = match &input_data.queue_id {
+        Some(queue_id) => {
+            action
+                .app
+                .metrics_history
+                .get_delivery_rate(
+                    input_data.topic_id.as_str(),
+                    queue_id.as_str(),
+                    resolution,
+                    from,
+                    to,
+                )
+                .await
+        }
+        None => {
+            action
+                .app
+                .metrics_history
+                .get_publish_rate(input_data.topic_id.as_str(), resolution, from, to)
+                .await
+        }
+    };
+
+    let result = MetricsHistoryJsonResult {
+        resolution: resolution.as_str().to_string(),
+        points: points.iter().map(MetricPointJsonModel::new).collect(),
+    };
+
+    HttpOutput::as_json(result).into_ok_result(true).into()
+}
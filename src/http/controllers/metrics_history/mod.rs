@@ -0,0 +1,3 @@
+mod get_metrics_history_action;
+mod models;
+pub use get_metrics_history_action::GetMetricsHistoryAction;
@@ -0,0 +1,39 @@
+use my_http_server_swagger::{MyHttpInput, MyHttpObjectStructure};
+use serde::{Deserialize, Serialize};
+
+use crate::metric_data::MetricPoint;
+
+#[derive(MyHttpInput)]
+pub struct GetMetricsHistoryInputContract {
+    #[http_query(name = "topicId"; description = "Id of topic")]
+    pub topic_id: String,
+    #[http_query(name = "queueId"; description = "Id of queue. Delivery rate of the queue is returned if specified, otherwise publish rate of the topic")]
+    pub queue_id: Option<String>,
+    #[http_query(name = "resolution"; description = "1s, 1m or 1h. 1m by default")]
+    pub resolution: Option<String>,
+    #[http_query(name = "from"; description = "Unix time in seconds. Everything we have if not specified")]
+    pub from: Option<i64>,
+    #[http_query(name = "to"; description = "Unix time in seconds. Now if not specified")]
+    pub to: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct MetricsHistoryJsonResult {
+    pub resolution: String,
+    pub points: Vec<MetricPointJsonModel>,
+}
+
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct MetricPointJsonModel {
+    pub time: i64,
+    pub value: f64,
+}
+
+impl MetricPointJsonModel {
+    pub fn new(src: &MetricPoint) -> Self {
+        Self {
+            time: src.time,
+            value: src.get_value(),
+        }
+    }
+}
@@ -1,13 +1,19 @@
+pub mod alerts;
+pub mod audit;
 pub mod builder;
 mod contracts;
 pub mod debug;
 pub mod extensions;
 pub mod greeting;
+pub mod health;
 mod home_controller;
 pub mod logs;
+pub mod metrics_history;
 pub mod prometheus_controller;
 pub mod publisher;
 pub mod queues;
 pub mod sessions;
+pub mod settings;
 pub mod status;
 pub mod topics;
+pub mod topology;
@@ -60,7 +60,7 @@ async fn handle_request(
         http_input.topic_id.as_str(),
         messages_to_publish,
         false,
-        session.id,
+        &session,
     )
     .await?;
 
@@ -1,4 +1,10 @@
-use my_http_server_swagger::MyHttpInput;
+use my_http_server::HttpFailResult;
+use my_http_server_swagger::{MyHttpInput, MyHttpObjectStructure};
+use my_service_bus_shared::{
+    queue_with_intervals::{QueueIndexRange, QueueWithIntervals},
+    MessageId,
+};
+use serde::{Deserialize, Serialize};
 
 #[derive(MyHttpInput)]
 pub struct GetListOfQueuesInputContract {
@@ -23,3 +29,124 @@ pub struct SetQueueMessageIdInputContract {
     #[http_query(name="messageId"; description = "Message id")]
     pub message_id: i64,
 }
+
+#[derive(MyHttpInput)]
+pub struct PeekQueueInputContract {
+    #[http_query(name="topicId"; description = "Id of topic")]
+    pub topic_id: String,
+    #[http_query(name="queueId"; description = "Id of queue")]
+    pub queue_id: String,
+    #[http_query(name="amount"; description = "Max amount of messages to return")]
+    pub amount: Option<usize>,
+}
+
+#[derive(MyHttpInput)]
+pub struct QueueMessagesRangeInputContract {
+    #[http_query(name="topicId"; description = "Id of topic")]
+    pub topic_id: String,
+    #[http_query(name="queueId"; description = "Id of queue")]
+    pub queue_id: String,
+    #[http_query(name="fromId"; description = "First message id of the range")]
+    pub from_id: Option<i64>,
+    #[http_query(name="toId"; description = "Last message id of the range")]
+    pub to_id: Option<i64>,
+    #[http_query(name="messageIds"; description = "Comma separated list of message ids")]
+    pub message_ids: Option<String>,
+}
+
+impl QueueMessagesRangeInputContract {
+    pub fn get_ids(&self) -> Result<QueueWithIntervals, HttpFailResult> {
+        let mut result = QueueWithIntervals::new();
+
+        if let Some(from_id) = self.from_id {
+            let to_id = self.to_id.unwrap_or(from_id);
+
+            if to_id < from_id {
+                return Err(HttpFailResult::as_forbidden(Some(format!(
+                    "toId {} is less than fromId {}",
+                    to_id, from_id
+                ))));
+            }
+
+            result.merge_with(&QueueWithIntervals::restore(vec![QueueIndexRange {
+                from_id,
+                to_id,
+            }]));
+        }
+
+        if let Some(message_ids) = &self.message_ids {
+            let mut ids = Vec::new();
+
+            for id in message_ids.split(',') {
+                let id = id.trim();
+
+                if id.is_empty() {
+                    continue;
+                }
+
+                match id.parse::<MessageId>() {
+                    Ok(id) => ids.push(id),
+                    Err(_) => {
+                        return Err(HttpFailResult::as_forbidden(Some(format!(
+                            "Invalid message id {}",
+                            id
+                        ))));
+                    }
+                }
+            }
+
+            ids.sort();
+
+            for id in ids {
+                result.enqueue(id);
+            }
+        }
+
+        if result.len() == 0 {
+            return Err(HttpFailResult::as_forbidden(Some(
+                "Either fromId or messageIds must be specified".to_string(),
+            )));
+        }
+
+        Ok(result)
+    }
+}
+
+#[derive(MyHttpInput)]
+pub struct PurgeQueueInputContract {
+    #[http_query(name="topicId"; description = "Id of topic")]
+    pub topic_id: String,
+    #[http_query(name="queueId"; description = "Id of queue")]
+    pub queue_id: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct PurgeQueueResponse {
+    #[serde(rename = "purgedAmount")]
+    pub purged_amount: usize,
+}
+
+#[derive(MyHttpInput)]
+pub struct DeclareQueueInputContract {
+    #[http_query(name="topicId"; description = "Id of topic")]
+    pub topic_id: String,
+    #[http_query(name="queueId"; description = "Id of queue")]
+    pub queue_id: String,
+    #[http_query(name="queueType"; description = "Permanent or PermanentWithSingleConnection. Permanent by default")]
+    pub queue_type: Option<String>,
+    #[http_query(name="fromMessageId"; description = "Enqueue already published messages starting from this id")]
+    pub from_message_id: Option<i64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct DeclareQueueResponse {
+    pub created: bool,
+}
+
+#[derive(MyHttpInput)]
+pub struct PauseQueueInputContract {
+    #[http_query(name="topicId"; description = "Id of topic")]
+    pub topic_id: String,
+    #[http_query(name="queueId"; description = "Id of queue")]
+    pub queue_id: String,
+}
@@ -0,0 +1,73 @@
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
+
+use my_http_server_swagger::http_route;
+use my_service_bus_shared::queue::TopicQueueType;
+
+use std::sync::Arc;
+
+use super::*;
+
+use crate::{app::AppContext, http::controllers::audit::get_audit_caller};
+
+#[http_route(
+    method: "POST",
+    route: "/Queues/Declare",
+    controller: "Queues",
+    description: "Declare permanent queue before the first subscriber is connected",
+    input_data: "DeclareQueueInputContract",
+    result: [
+        {status_code: 200, description: "Queue is declared", model: "DeclareQueueResponse"},
+        {status_code: 403, description: "Invalid topic, queue or queue type"},
+    ]
+)]
+pub struct DeclareQueueAction {
+    app: Arc<AppContext>,
+}
+
+impl DeclareQueueAction {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+async fn handle_request(
+    action: &DeclareQueueAction,
+    input_data: DeclareQueueInputContract,
+    ctx: &mut HttpContext,
+) -> Result<HttpOkResult, HttpFailResult> {
+    let queue_type = match &input_data.queue_type {
+        Some(queue_type) => {
+            crate::operations::queues::parse_queue_type(queue_type.as_str()).ok_or(
+                HttpFailResult::as_forbidden(Some(format!("Unknown queue type {}", queue_type))),
+            )?
+        }
+        None => TopicQueueType::Permanent,
+    };
+
+    let created = crate::operations::queues::declare_queue(
+        &action.app,
+        input_data.topic_id.as_str(),
+        input_data.queue_id.as_str(),
+        queue_type,
+        input_data.from_message_id,
+    )
+    .await?;
+
+    if created {
+        action.app.audit.add(
+            get_audit_caller(action.app.as_ref(), ctx).await,
+            "DeclareQueue",
+            format!("{}/{}", input_data.topic_id, input_data.queue_id),
+            None,
+            Some(format!(
+                "QueueType: {}. FromMessageId: {:?}",
+                input_data.queue_type.as_deref().unwrap_or("Permanent"),
+                input_data.from_message_id
+            )),
+        );
+    }
+
+    HttpOutput::as_json(DeclareQueueResponse { created })
+        .into_ok_result(true)
+        .into()
+}
@@ -6,7 +6,7 @@ use std::sync::Arc;
 
 use super::*;
 
-use crate::app::AppContext;
+use crate::{app::AppContext, http::controllers::audit::get_audit_caller};
 
 #[http_route(
     method: "DELETE",
@@ -32,14 +32,22 @@ impl DeleteQueueAction {
 async fn handle_request(
     action: &DeleteQueueAction,
     http_input: DeleteQueueInputContract,
-    _ctx: &mut HttpContext,
+    ctx: &mut HttpContext,
 ) -> Result<HttpOkResult, HttpFailResult> {
-    crate::operations::queues::delete_queue(
+    let ranges = crate::operations::queues::delete_queue(
         action.app.as_ref(),
         http_input.topic_id.as_str(),
         http_input.queue_id.as_str(),
     )
     .await?;
 
+    action.app.audit.add(
+        get_audit_caller(action.app.as_ref(), ctx).await,
+        "DeleteQueue",
+        format!("{}/{}", http_input.topic_id, http_input.queue_id),
+        ranges,
+        None,
+    );
+
     HttpOutput::Empty.into_ok_result(true).into()
 }
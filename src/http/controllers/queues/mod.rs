@@ -1,8 +1,22 @@
 mod contracts;
+mod declare_queue_action;
 mod delete_queue_action;
 mod get_list_of_queues_action;
+mod pause_queue_action;
+mod peek_queue_action;
+mod purge_queue_action;
+mod requeue_messages_action;
+mod resume_queue_action;
 mod set_message_id_action;
+mod skip_messages_action;
 pub use contracts::*;
+pub use declare_queue_action::DeclareQueueAction;
 pub use delete_queue_action::DeleteQueueAction;
 pub use get_list_of_queues_action::GetQueuesAction;
+pub use pause_queue_action::PauseQueueAction;
+pub use peek_queue_action::PeekQueueAction;
+pub use purge_queue_action::PurgeQueueAction;
+pub use requeue_messages_action::RequeueMessagesAction;
+pub use resume_queue_action::ResumeQueueAction;
 pub use set_message_id_action::SetMessageIdAction;
+pub use skip_messages_action::SkipMessagesAction;
@@ -0,0 +1,51 @@
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
+
+use my_http_server_swagger::http_route;
+
+use std::sync::Arc;
+
+use super::*;
+
+use crate::{
+    app::AppContext,
+    http::controllers::contracts::messages::{get_messages_amount, MessageJsonModel},
+};
+
+#[http_route(
+    method: "GET",
+    route: "/Queues/Peek",
+    controller: "Queues",
+    description: "Peek next messages of the queue without delivering them",
+    input_data: "PeekQueueInputContract",
+    result: [
+        {status_code: 200, description: "List of messages", model_as_array: "MessageJsonModel"},
+        {status_code: 403, description: "Topic or queue not found"},
+    ]
+)]
+pub struct PeekQueueAction {
+    app: Arc<AppContext>,
+}
+
+impl PeekQueueAction {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+async fn handle_request(
+    action: &PeekQueueAction,
+    input_data: PeekQueueInputContract,
+    _ctx: &mut HttpContext,
+) -> Result<HttpOkResult, HttpFailResult> {
+    let messages = crate::operations::messages_browser::peek_queue_messages(
+        action.app.as_ref(),
+        input_data.topic_id.as_str(),
+        input_data.queue_id.as_str(),
+        get_messages_amount(input_data.amount),
+    )
+    .await?;
+
+    let result: Vec<MessageJsonModel> = messages.into_iter().map(MessageJsonModel::new).collect();
+
+    HttpOutput::as_json(result).into_ok_result(true).into()
+}
@@ -0,0 +1,55 @@
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
+
+use my_http_server_swagger::http_route;
+
+use std::sync::Arc;
+
+use super::*;
+
+use crate::{app::AppContext, http::controllers::audit::get_audit_caller};
+
+#[http_route(
+    method: "POST",
+    route: "/Queues/Purge",
+    controller: "Queues",
+    description: "Drop all the messages of the queue including messages on delivery",
+    input_data: "PurgeQueueInputContract",
+    result: [
+        {status_code: 200, description: "Amount of dropped messages", model: "PurgeQueueResponse"},
+        {status_code: 403, description: "Topic or queue not found"},
+    ]
+)]
+pub struct PurgeQueueAction {
+    app: Arc<AppContext>,
+}
+
+impl PurgeQueueAction {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+async fn handle_request(
+    action: &PurgeQueueAction,
+    input_data: PurgeQueueInputContract,
+    ctx: &mut HttpContext,
+) -> Result<HttpOkResult, HttpFailResult> {
+    let purged_amount = crate::operations::queues::purge_queue(
+        &action.app,
+        input_data.topic_id.as_str(),
+        input_data.queue_id.as_str(),
+    )
+    .await?;
+
+    action.app.audit.add(
+        get_audit_caller(action.app.as_ref(), ctx).await,
+        "PurgeQueue",
+        format!("{}/{}", input_data.topic_id, input_data.queue_id),
+        None,
+        Some(format!("Dropped {} messages", purged_amount)),
+    );
+
+    HttpOutput::as_json(PurgeQueueResponse { purged_amount })
+        .into_ok_result(true)
+        .into()
+}
@@ -0,0 +1,56 @@
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
+
+use my_http_server_swagger::http_route;
+
+use std::sync::Arc;
+
+use super::*;
+
+use crate::{app::AppContext, http::controllers::audit::get_audit_caller};
+
+#[http_route(
+    method: "POST",
+    route: "/Queues/Requeue",
+    controller: "Queues",
+    description: "Put a range or a list of messages back to the queue",
+    input_data: "QueueMessagesRangeInputContract",
+    result: [
+        {status_code: 202, description: "Operation is succesfull"},
+        {status_code: 403, description: "Topic or queue not found"},
+    ]
+)]
+pub struct RequeueMessagesAction {
+    app: Arc<AppContext>,
+}
+
+impl RequeueMessagesAction {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+async fn handle_request(
+    action: &RequeueMessagesAction,
+    input_data: QueueMessagesRangeInputContract,
+    ctx: &mut HttpContext,
+) -> Result<HttpOkResult, HttpFailResult> {
+    let ids = input_data.get_ids()?;
+
+    let change = crate::operations::queues::requeue_messages(
+        &action.app,
+        input_data.topic_id.as_str(),
+        input_data.queue_id.as_str(),
+        ids,
+    )
+    .await?;
+
+    action.app.audit.add(
+        get_audit_caller(action.app.as_ref(), ctx).await,
+        "RequeueMessages",
+        format!("{}/{}", input_data.topic_id, input_data.queue_id),
+        Some(change.before),
+        Some(change.after),
+    );
+
+    HttpOutput::Empty.into_ok_result(true).into()
+}
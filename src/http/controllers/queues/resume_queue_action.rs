@@ -0,0 +1,54 @@
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
+
+use my_http_server_swagger::http_route;
+
+use std::sync::Arc;
+
+use super::*;
+
+use crate::{app::AppContext, http::controllers::audit::get_audit_caller};
+
+#[http_route(
+    method: "POST",
+    route: "/Queues/Resume",
+    controller: "Queues",
+    description: "Resume delivery of the paused queue",
+    input_data: "PauseQueueInputContract",
+    result: [
+        {status_code: 202, description: "Operation is succesfull"},
+        {status_code: 403, description: "Topic or queue not found"},
+    ]
+)]
+pub struct ResumeQueueAction {
+    app: Arc<AppContext>,
+}
+
+impl ResumeQueueAction {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+async fn handle_request(
+    action: &ResumeQueueAction,
+    input_data: PauseQueueInputContract,
+    ctx: &mut HttpContext,
+) -> Result<HttpOkResult, HttpFailResult> {
+    let prev = crate::operations::queues::set_queue_paused(
+        &action.app,
+        input_data.topic_id.as_str(),
+        input_data.queue_id.as_str(),
+        false,
+    )
+    .await?;
+
+    action.app.audit.add(
+        get_audit_caller(action.app.as_ref(), ctx).await,
+        "ResumeQueue",
+        format!("{}/{}", input_data.topic_id, input_data.queue_id),
+        Some(format!("Paused: {}", prev)),
+        Some(format!("Paused: {}", false)),
+    );
+
+    HttpOutput::Empty.into_ok_result(true).into()
+}
@@ -6,7 +6,7 @@ use std::sync::Arc;
 
 use super::*;
 
-use crate::app::AppContext;
+use crate::{app::AppContext, http::controllers::audit::get_audit_caller};
 
 #[http_route(
     method: "POST",
@@ -32,10 +32,9 @@ impl SetMessageIdAction {
 async fn handle_request(
     action: &SetMessageIdAction,
     input_data: SetQueueMessageIdInputContract,
-    _ctx: &mut HttpContext,
+    ctx: &mut HttpContext,
 ) -> Result<HttpOkResult, HttpFailResult> {
-
-    crate::operations::queues::set_message_id(
+    let change = crate::operations::queues::set_message_id(
         action.app.as_ref(),
         input_data.topic_id.as_str(),
         input_data.queue_id.as_str(),
@@ -43,5 +42,13 @@ async fn handle_request(
     )
     .await?;
 
+    action.app.audit.add(
+        get_audit_caller(action.app.as_ref(), ctx).await,
+        "SetMessageId",
+        format!("{}/{}", input_data.topic_id, input_data.queue_id),
+        Some(change.before),
+        Some(change.after),
+    );
+
     HttpOutput::Empty.into_ok_result(true).into()
 }
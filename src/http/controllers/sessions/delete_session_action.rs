@@ -6,7 +6,7 @@ use my_http_server_controllers::controllers::{
 use std::sync::Arc;
 
 use super::{super::contracts::response, *};
-use crate::app::AppContext;
+use crate::{app::AppContext, http::controllers::audit::get_audit_caller};
 pub struct DeleteSessionAction {
     app: Arc<AppContext>,
 }
@@ -43,7 +43,22 @@ impl DeleteAction for DeleteSessionAction {
 
         match self.app.sessions.get(input_data.connection_id).await {
             Some(session) => {
+                let (name, version) = session.get_name_and_client_version().await;
+
                 session.disconnect().await;
+
+                self.app.audit.add(
+                    get_audit_caller(self.app.as_ref(), ctx).await,
+                    "KickSession",
+                    input_data.connection_id.to_string(),
+                    Some(format!(
+                        "Name: {}. Version: {}",
+                        name.as_deref().unwrap_or("unknown"),
+                        version.as_deref().unwrap_or("unknown")
+                    )),
+                    None,
+                );
+
                 HttpOutput::Empty.into_ok_result(true).into()
             }
             None => Err(HttpFailResult::as_not_found(
@@ -0,0 +1,2 @@
+mod reload_settings_action;
+pub use reload_settings_action::ReloadSettingsAction;
@@ -0,0 +1,44 @@
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
+
+use my_http_server_swagger::http_route;
+
+use std::sync::Arc;
+
+use crate::{app::AppContext, http::controllers::audit::get_audit_caller};
+
+#[http_route(
+    method: "POST",
+    route: "/Settings/Reload",
+    controller: "Settings",
+    description: "Reload settings file and apply changed values without restart",
+    result: [
+        {status_code: 200, description: "List of applied changes", model_as_array: "String"},
+        {status_code: 403, description: "Settings file can not be read"},
+    ]
+)]
+pub struct ReloadSettingsAction {
+    app: Arc<AppContext>,
+}
+
+impl ReloadSettingsAction {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+async fn handle_request(
+    action: &ReloadSettingsAction,
+    ctx: &mut HttpContext,
+) -> Result<HttpOkResult, HttpFailResult> {
+    let changes = crate::operations::reload_settings(action.app.as_ref(), "http request").await?;
+
+    action.app.audit.add(
+        get_audit_caller(action.app.as_ref(), ctx).await,
+        "ReloadSettings",
+        "Settings".to_string(),
+        None,
+        Some(changes.join("; ")),
+    );
+
+    HttpOutput::as_json(changes).into_ok_result(true).into()
+}
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use crate::app::AppContext;
+use crate::{app::AppContext, http::controllers::alerts::AlertJsonModel};
 
 use serde::{Deserialize, Serialize};
 use sysinfo::SystemExt;
@@ -17,6 +17,23 @@ pub struct SystemStatusModel {
     totalmem: u64,
 }
 
+impl SystemStatusModel {
+    pub fn new() -> Self {
+        let mut sys_info = sysinfo::System::new_all();
+
+        sys_info.refresh_all();
+
+        Self::from_sys_info(&sys_info)
+    }
+
+    pub fn from_sys_info(sys_info: &sysinfo::System) -> Self {
+        Self {
+            totalmem: sys_info.total_memory(),
+            usedmem: sys_info.used_memory(),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct StatusJsonResult {
     pub topics: TopicsJsonResult,
@@ -25,14 +42,11 @@ pub struct StatusJsonResult {
     pub system: SystemStatusModel,
     #[serde(rename = "persistenceVersion")]
     pub persistence_version: String,
+    pub alerts: Vec<AlertJsonModel>,
 }
 
 impl StatusJsonResult {
     pub async fn new(app: &AppContext) -> Self {
-        let mut sys_info = sysinfo::System::new_all();
-
-        sys_info.refresh_all();
-
         let (snapshot_id, all_topics) = app.topic_list.get_all_with_snapshot_id().await;
 
         let mut queues = HashMap::new();
@@ -54,20 +68,26 @@ impl StatusJsonResult {
             topics.items.push(TopicJsonContract::new(&topic_data));
         }
 
-        let persistence_version = {
-            let read_access = app.persistence_version.lock().await;
-            read_access.to_string()
-        };
-
         Self {
             topics,
             queues,
             sessions,
-            system: SystemStatusModel {
-                totalmem: sys_info.total_memory(),
-                usedmem: sys_info.used_memory(),
-            },
-            persistence_version,
+            system: SystemStatusModel::new(),
+            persistence_version: get_persistence_version(app).await,
+            alerts: get_active_alerts(app),
         }
     }
 }
+
+pub async fn get_persistence_version(app: &AppContext) -> String {
+    let read_access = app.persistence_version.lock().await;
+    read_access.to_string()
+}
+
+pub fn get_active_alerts(app: &AppContext) -> Vec<AlertJsonModel> {
+    app.alerts
+        .get_active()
+        .into_iter()
+        .map(AlertJsonModel::new)
+        .collect()
+}
@@ -1,3 +1,3 @@
-mod index_models;
-mod models;
+pub mod index_models;
+pub mod models;
 pub mod status_controller;
@@ -1,4 +1,9 @@
-use crate::{queues::TopicQueue, topics::TopicData};
+use rust_extensions::date_time::DateTimeAsMicroseconds;
+
+use crate::{
+    queues::TopicQueue,
+    topics::{QueueLag, TopicData},
+};
 
 use my_http_server_swagger::MyHttpObjectStructure;
 use serde::{Deserialize, Serialize};
@@ -17,10 +22,14 @@ impl QueuesJsonResult {
             queues: Vec::new(),
         };
 
+        let now = DateTimeAsMicroseconds::now();
+
         for topic_queue in topic_data.queues.get_all() {
+            let lag = QueueLag::new(topic_data, topic_queue, now);
+
             result
                 .queues
-                .push(QueueJsonContract::from_queue(topic_queue));
+                .push(QueueJsonContract::from_queue(topic_queue, &lag));
         }
 
         result
@@ -35,16 +44,24 @@ pub struct QueueJsonContract {
     size: i64,
     #[serde(rename = "onDelivery")]
     on_delivery: i64,
+    paused: bool,
+    #[serde(rename = "lagMessages")]
+    lag_messages: i64,
+    #[serde(rename = "lagSeconds")]
+    lag_seconds: Option<i64>,
     data: Vec<QueueIndex>,
 }
 
 impl QueueJsonContract {
-    pub fn from_queue(topic_queue: &TopicQueue) -> Self {
+    pub fn from_queue(topic_queue: &TopicQueue, lag: &QueueLag) -> Self {
         Self {
             id: topic_queue.queue_id.to_string(),
             queue_type: topic_queue.queue_type.into_u8(),
             size: topic_queue.get_queue_size(),
             on_delivery: topic_queue.get_on_delivery(),
+            paused: topic_queue.paused,
+            lag_messages: lag.messages,
+            lag_seconds: lag.seconds,
             data: QueueIndex::get_queue_snapshot(topic_queue),
         }
     }
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use crate::{
     app::AppContext,
     sessions::{MyServiceBusSession, SessionId},
@@ -23,6 +25,14 @@ pub struct SessionJsonResult {
     pub read_per_sec: usize,
     #[serde(rename = "writtenPerSec")]
     pub written_per_sec: usize,
+    pub packets: BTreeMap<String, usize>,
+    pub published: usize,
+    pub rejects: usize,
+    pub delivered: usize,
+    pub confirmations: usize,
+    pub confirmed: usize,
+    #[serde(rename = "avgConfirmationMicroseconds")]
+    pub avg_confirmation_microseconds: u64,
 }
 
 impl SessionJsonResult {
@@ -30,6 +40,7 @@ impl SessionJsonResult {
         let now = DateTimeAsMicroseconds::now();
 
         let session_metrics_data = session.get_metrics().await;
+        let statistics = session.statistics.get_snapshot();
 
         let name = if let Some(name) = session_metrics_data.name {
             name
@@ -53,6 +64,17 @@ impl SessionJsonResult {
             written_size: session_metrics_data.connection_metrics.written,
             read_per_sec: session_metrics_data.connection_metrics.read_per_sec,
             written_per_sec: session_metrics_data.connection_metrics.written_per_sec,
+            packets: statistics
+                .packets
+                .iter()
+                .map(|(packet, amount)| (packet.to_string(), *amount))
+                .collect(),
+            published: statistics.published,
+            rejects: statistics.rejects,
+            delivered: statistics.delivered,
+            confirmations: statistics.confirmations,
+            confirmed: statistics.confirmed,
+            avg_confirmation_microseconds: statistics.get_avg_confirmation_microseconds(),
         }
     }
 }
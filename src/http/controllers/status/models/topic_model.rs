@@ -30,6 +30,8 @@ pub struct TopicJsonContract {
     pub publish_history: Vec<i32>,
     pub publishers: Vec<TopicPublisherJsonModel>,
     pub subscribers: Vec<TopicQueueSubscriberJsonModel>,
+    #[serde(rename = "readOnly")]
+    pub read_only: bool,
 }
 
 impl TopicJsonContract {
@@ -84,6 +86,7 @@ impl TopicJsonContract {
                 })
                 .collect(),
             subscribers,
+            read_only: topic_data.read_only,
         }
     }
 }
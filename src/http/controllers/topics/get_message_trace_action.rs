@@ -0,0 +1,46 @@
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
+
+use my_http_server_swagger::http_route;
+
+use std::sync::Arc;
+
+use super::models::{GetMessageTraceInputContract, MessageTraceJsonResult};
+use crate::app::AppContext;
+
+#[http_route(
+    method: "GET",
+    route: "/Topics/MessageTrace",
+    controller: "Topics",
+    description: "Where the message is right now: RAM, persist queue, queues and subscribers having it on delivery",
+    input_data: "GetMessageTraceInputContract",
+    result: [
+        {status_code: 200, description: "Message state", model: "MessageTraceJsonResult"},
+        {status_code: 403, description: "Topic or message not found"},
+    ]
+)]
+pub struct GetMessageTraceAction {
+    app: Arc<AppContext>,
+}
+
+impl GetMessageTraceAction {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+async fn handle_request(
+    action: &GetMessageTraceAction,
+    input_data: GetMessageTraceInputContract,
+    _ctx: &mut HttpContext,
+) -> Result<HttpOkResult, HttpFailResult> {
+    let trace = crate::operations::message_trace::trace_message(
+        action.app.as_ref(),
+        input_data.topic_id.as_str(),
+        input_data.message_id,
+    )
+    .await?;
+
+    let result = MessageTraceJsonResult::new(trace).await;
+
+    HttpOutput::as_json(result).into_ok_result(true).into()
+}
@@ -0,0 +1,50 @@
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
+
+use my_http_server_swagger::http_route;
+
+use std::sync::Arc;
+
+use super::models::GetTopicMessagesInputContract;
+use crate::{
+    app::AppContext,
+    http::controllers::contracts::messages::{get_messages_amount, MessageJsonModel},
+};
+
+#[http_route(
+    method: "GET",
+    route: "/Topics/Messages",
+    controller: "Topics",
+    description: "Browse topic messages without affecting queues",
+    input_data: "GetTopicMessagesInputContract",
+    result: [
+        {status_code: 200, description: "List of messages", model_as_array: "MessageJsonModel"},
+        {status_code: 403, description: "Topic not found"},
+    ]
+)]
+pub struct GetTopicMessagesAction {
+    app: Arc<AppContext>,
+}
+
+impl GetTopicMessagesAction {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+async fn handle_request(
+    action: &GetTopicMessagesAction,
+    input_data: GetTopicMessagesInputContract,
+    _ctx: &mut HttpContext,
+) -> Result<HttpOkResult, HttpFailResult> {
+    let messages = crate::operations::messages_browser::get_topic_messages(
+        action.app.as_ref(),
+        input_data.topic_id.as_str(),
+        input_data.from_message_id,
+        get_messages_amount(input_data.amount),
+    )
+    .await?;
+
+    let result: Vec<MessageJsonModel> = messages.into_iter().map(MessageJsonModel::new).collect();
+
+    HttpOutput::as_json(result).into_ok_result(true).into()
+}
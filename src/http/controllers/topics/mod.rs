@@ -1,5 +1,11 @@
+mod get_message_trace_action;
+mod get_messages_action;
 mod models;
+mod set_read_only_action;
 
 mod topics_controller;
 
+pub use get_message_trace_action::GetMessageTraceAction;
+pub use get_messages_action::GetTopicMessagesAction;
+pub use set_read_only_action::SetTopicReadOnlyAction;
 pub use topics_controller::TopicsController;
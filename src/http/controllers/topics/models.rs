@@ -1,4 +1,4 @@
-use crate::topics::Topic;
+use crate::{operations::message_trace::MessageTrace, topics::Topic};
 
 use my_http_server_swagger::{MyHttpInput, MyHttpObjectStructure};
 use serde::{Deserialize, Serialize};
@@ -14,13 +14,18 @@ pub struct JsonTopicResult {
     pub id: String,
     #[serde(rename = "messageId")]
     pub message_id: i64,
+    #[serde(rename = "readOnly")]
+    pub read_only: bool,
 }
 
 impl JsonTopicResult {
     pub async fn new(topic: &Topic) -> Self {
+        let topic_data = topic.get_access().await;
+
         Self {
             id: topic.topic_id.to_string(),
-            message_id: topic.get_message_id().await,
+            message_id: topic_data.message_id,
+            read_only: topic_data.read_only,
         }
     }
 }
@@ -30,3 +35,109 @@ pub struct CreateTopicRequestContract {
     #[http_query(name = "topicId"; description = "Id of topic")]
     pub topic_id: String,
 }
+
+#[derive(Debug, MyHttpInput)]
+pub struct DeleteTopicRequestContract {
+    #[http_query(name = "topicId"; description = "Id of topic")]
+    pub topic_id: String,
+    #[http_query(name = "force"; description = "Delete even with attached subscribers or unpersisted messages. Sessions of the subscribers are disconnected")]
+    pub force: Option<bool>,
+    #[http_query(name = "deletePersistedPages"; description = "Delete topic pages from persistence")]
+    pub delete_persisted_pages: Option<bool>,
+}
+
+#[derive(MyHttpInput)]
+pub struct GetTopicMessagesInputContract {
+    #[http_query(name = "topicId"; description = "Id of topic")]
+    pub topic_id: String,
+    #[http_query(name = "fromMessageId"; description = "First message id. Last messages are returned if not specified")]
+    pub from_message_id: Option<i64>,
+    #[http_query(name = "amount"; description = "Max amount of messages to return")]
+    pub amount: Option<usize>,
+}
+
+#[derive(MyHttpInput)]
+pub struct SetTopicReadOnlyInputContract {
+    #[http_query(name = "topicId"; description = "Id of topic")]
+    pub topic_id: String,
+    #[http_query(name = "readOnly"; description = "Reject publishing to the topic")]
+    pub read_only: bool,
+}
+
+#[derive(MyHttpInput)]
+pub struct GetMessageTraceInputContract {
+    #[http_query(name = "topicId"; description = "Id of topic")]
+    pub topic_id: String,
+    #[http_query(name = "messageId"; description = "Id of message")]
+    pub message_id: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct MessageTraceJsonResult {
+    #[serde(rename = "topicMessageId")]
+    pub topic_message_id: i64,
+    #[serde(rename = "inRam")]
+    pub in_ram: bool,
+    pub time: Option<String>,
+    pub size: Option<usize>,
+    #[serde(rename = "waitingToPersist")]
+    pub waiting_to_persist: Option<bool>,
+    pub queues: Vec<MessageQueueTraceJsonModel>,
+}
+
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct MessageQueueTraceJsonModel {
+    #[serde(rename = "queueId")]
+    pub queue_id: String,
+    pub state: String,
+    #[serde(rename = "deliveryAttempts")]
+    pub delivery_attempts: i32,
+    #[serde(rename = "onDelivery")]
+    pub on_delivery: Vec<MessageOnDeliveryJsonModel>,
+}
+
+#[derive(Serialize, Deserialize, Debug, MyHttpObjectStructure)]
+pub struct MessageOnDeliveryJsonModel {
+    #[serde(rename = "subscriberId")]
+    pub subscriber_id: i64,
+    #[serde(rename = "sessionId")]
+    pub session_id: i64,
+    #[serde(rename = "sessionName")]
+    pub session_name: Option<String>,
+}
+
+impl MessageTraceJsonResult {
+    pub async fn new(src: MessageTrace) -> Self {
+        let mut queues = Vec::with_capacity(src.queues.len());
+
+        for queue in src.queues {
+            let mut on_delivery = Vec::with_capacity(queue.on_delivery.len());
+
+            for itm in queue.on_delivery {
+                let (session_name, _) = itm.session.get_name_and_client_version().await;
+
+                on_delivery.push(MessageOnDeliveryJsonModel {
+                    subscriber_id: itm.subscriber_id,
+                    session_id: itm.session.id,
+                    session_name,
+                });
+            }
+
+            queues.push(MessageQueueTraceJsonModel {
+                queue_id: queue.queue_id,
+                state: queue.state.as_str().to_string(),
+                delivery_attempts: queue.delivery_attempts,
+                on_delivery,
+            });
+        }
+
+        Self {
+            topic_message_id: src.topic_message_id,
+            in_ram: src.in_ram.is_some(),
+            time: src.in_ram.as_ref().map(|itm| itm.time.to_rfc3339()),
+            size: src.in_ram.as_ref().map(|itm| itm.size),
+            waiting_to_persist: src.in_ram.as_ref().map(|itm| itm.waiting_to_persist),
+            queues,
+        }
+    }
+}
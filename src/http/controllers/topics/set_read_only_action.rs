@@ -0,0 +1,52 @@
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
+
+use my_http_server_swagger::http_route;
+
+use std::sync::Arc;
+
+use super::models::SetTopicReadOnlyInputContract;
+use crate::{app::AppContext, http::controllers::audit::get_audit_caller};
+
+#[http_route(
+    method: "POST",
+    route: "/Topics/ReadOnly",
+    controller: "Topics",
+    description: "Enable or disable read-only mode of the topic",
+    input_data: "SetTopicReadOnlyInputContract",
+    result: [
+        {status_code: 202, description: "Operation is succesfull"},
+        {status_code: 403, description: "Topic not found"},
+    ]
+)]
+pub struct SetTopicReadOnlyAction {
+    app: Arc<AppContext>,
+}
+
+impl SetTopicReadOnlyAction {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+async fn handle_request(
+    action: &SetTopicReadOnlyAction,
+    input_data: SetTopicReadOnlyInputContract,
+    ctx: &mut HttpContext,
+) -> Result<HttpOkResult, HttpFailResult> {
+    let prev = crate::operations::topics::set_read_only(
+        &action.app,
+        input_data.topic_id.as_str(),
+        input_data.read_only,
+    )
+    .await?;
+
+    action.app.audit.add(
+        get_audit_caller(action.app.as_ref(), ctx).await,
+        "SetTopicReadOnly",
+        input_data.topic_id.to_string(),
+        Some(format!("ReadOnly: {}", prev)),
+        Some(format!("ReadOnly: {}", input_data.read_only)),
+    );
+
+    HttpOutput::Empty.into_ok_result(true).into()
+}
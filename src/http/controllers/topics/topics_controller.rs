@@ -1,14 +1,16 @@
 use async_trait::async_trait;
-use my_http_server_controllers::controllers::actions::PostAction;
+use my_http_server_controllers::controllers::actions::{DeleteAction, PostAction};
 use my_http_server_controllers::controllers::documentation::out_results::HttpResult;
 use std::sync::Arc;
 
 use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput};
 
-use crate::app::AppContext;
+use crate::{app::AppContext, http::controllers::audit::get_audit_caller};
 
 use super::super::contracts::response;
-use super::models::{CreateTopicRequestContract, JsonTopicResult, JsonTopicsResult};
+use super::models::{
+    CreateTopicRequestContract, DeleteTopicRequestContract, JsonTopicResult, JsonTopicsResult,
+};
 
 use my_http_server_controllers::controllers::{
     actions::GetAction, documentation::HttpActionDescription,
@@ -94,3 +96,53 @@ impl PostAction for TopicsController {
             .into()
     }
 }
+
+#[async_trait]
+impl DeleteAction for TopicsController {
+    fn get_route(&self) -> &str {
+        "/Topics"
+    }
+
+    fn get_description(&self) -> Option<HttpActionDescription> {
+        HttpActionDescription {
+            controller_name: "Topics",
+            description: "Delete topic",
+
+            input_params: DeleteTopicRequestContract::get_input_params().into(),
+
+            results: vec![
+                response::empty("Topic is deleted"),
+                response::topic_or_queue_not_found(),
+            ],
+        }
+        .into()
+    }
+
+    async fn handle_request(&self, ctx: &mut HttpContext) -> Result<HttpOkResult, HttpFailResult> {
+        let input_data = DeleteTopicRequestContract::parse_http_input(ctx).await?;
+
+        let force = input_data.force.unwrap_or(false);
+        let delete_persisted_pages = input_data.delete_persisted_pages.unwrap_or(false);
+
+        crate::operations::topics::delete_topic(
+            &self.app,
+            input_data.topic_id.as_str(),
+            force,
+            delete_persisted_pages,
+        )
+        .await?;
+
+        self.app.audit.add(
+            get_audit_caller(self.app.as_ref(), ctx).await,
+            "DeleteTopic",
+            input_data.topic_id,
+            None,
+            Some(format!(
+                "Force: {}. DeletePersistedPages: {}",
+                force, delete_persisted_pages
+            )),
+        );
+
+        HttpOutput::Empty.into_ok_result(true).into()
+    }
+}
@@ -0,0 +1,58 @@
+use my_http_server::{HttpContext, HttpFailResult, HttpOkResult, HttpOutput, WebContentType};
+
+use my_http_server_swagger::http_route;
+
+use std::sync::Arc;
+
+use crate::{app::AppContext, topology::TopologyJson};
+
+#[http_route(
+    method: "GET",
+    route: "/Topology/Diff",
+    controller: "Topology",
+    description: "Dry run. Shows the difference between the topology file and the live state",
+    result: [
+        {status_code: 200, description: "Topology difference"},
+        {status_code: 400, description: "Topology is invalid"},
+        {status_code: 403, description: "Topology is not configured or can not be read"},
+    ]
+)]
+pub struct GetTopologyDiffAction {
+    app: Arc<AppContext>,
+}
+
+impl GetTopologyDiffAction {
+    pub fn new(app: Arc<AppContext>) -> Self {
+        Self { app }
+    }
+}
+
+async fn handle_request(
+    action: &GetTopologyDiffAction,
+    _ctx: &mut HttpContext,
+) -> Result<HttpOkResult, HttpFailResult> {
+    let topology = match &action.app.topology_file {
+        Some(topology_file) => TopologyJson::read_from_file(topology_file.as_str())
+            .await
+            .map_err(|err| HttpFailResult::as_forbidden(Some(err)))?,
+        None => match &action.app.topology {
+            Some(topology) => topology.clone(),
+            None => {
+                return Err(HttpFailResult::as_forbidden(Some(
+                    "Topology is not configured".to_string(),
+                )))
+            }
+        },
+    };
+
+    let diff = crate::topology::get_diff(action.app.as_ref(), &topology)
+        .await
+        .map_err(|err| HttpFailResult {
+            content_type: WebContentType::Text,
+            status_code: 400,
+            content: err.into_bytes(),
+            write_telemetry: false,
+        })?;
+
+    HttpOutput::as_json(diff).into_ok_result(true).into()
+}
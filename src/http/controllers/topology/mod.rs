@@ -0,0 +1,2 @@
+mod get_topology_diff_action;
+pub use get_topology_diff_action::GetTopologyDiffAction;
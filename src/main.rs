@@ -1,8 +1,9 @@
 use app::AppContext;
 
 use background::{
-    DeadSubscribersKickerTimer, GcTimer, ImmediatlyPersistEventLoop, MetricsTimer,
-    PersistTopicsAndQueuesTimer,
+    AlertsTimer, DeadSubscribersKickerTimer, GcTimer, ImmediatlyPersistEventLoop,
+    MetricsHistorySaveTimer, MetricsTimer, PersistTopicsAndQueuesTimer, SettingsReloadTimer,
+    TelemetryExportTimer,
 };
 use my_service_bus_tcp_shared::{ConnectionAttributes, MySbTcpSerializer};
 use my_tcp_sockets::TcpServer;
@@ -12,33 +13,77 @@ use tcp::socket_loop::TcpServerEvents;
 use std::time::Duration;
 use std::{net::SocketAddr, sync::Arc};
 
+mod alerts;
+mod amqp;
 mod app;
+mod audit;
 
 mod errors;
 mod grpc;
 mod http;
 mod messages_page;
 mod metric_data;
+mod mqtt;
 mod operations;
 mod persistence;
 mod queue_subscribers;
 mod queues;
 mod sessions;
 mod settings;
+mod streaming;
 mod tcp;
+mod telemetry;
 
 mod background;
 mod topics;
+mod topology;
 mod utils;
 pub mod persistence_grpc {
     tonic::include_proto!("persistence");
 }
 
+pub mod my_service_bus_grpc {
+    tonic::include_proto!("myservicebus");
+}
+
 #[tokio::main]
 async fn main() {
-    let settings = settings::SettingsModel::read().await;
+    let cli_args = settings::CliArgs::parse();
+
+    if cli_args.check_config {
+        let mut notes = Vec::new();
+        let result = settings::SettingsModel::try_read(&cli_args, &mut notes).await;
+
+        for note in notes {
+            println!("{}", note);
+        }
+
+        match result {
+            Ok(_) => {
+                println!("Settings are valid");
+                return;
+            }
+            Err(err) => {
+                println!("Settings are invalid: {}", err);
+                std::process::exit(1);
+            }
+        }
+    }
 
-    let app = Arc::new(AppContext::new(&settings).await);
+    let mut settings_notes = Vec::new();
+    let settings = settings::SettingsModel::read(&cli_args, &mut settings_notes).await;
+
+    let app = Arc::new(AppContext::new(&settings, cli_args).await);
+
+    for note in settings_notes {
+        app.logs.add_info(
+            None,
+            app::logs::SystemProcess::Init,
+            "read_settings".to_string(),
+            note,
+            None,
+        );
+    }
 
     app.immediatly_persist_event_loop
         .register_event_loop(Arc::new(ImmediatlyPersistEventLoop::new(app.clone())))
@@ -69,15 +114,50 @@ async fn main() {
 
     crate::http::start_up::setup_server(app.clone());
 
+    if let Some(streaming_port) = settings.streaming_port {
+        crate::streaming::start(
+            app.clone(),
+            SocketAddr::from(([0, 0, 0, 0], streaming_port)),
+        );
+    }
+
+    if let Some(grpc_port) = settings.grpc_port {
+        crate::grpc::start(app.clone(), SocketAddr::from(([0, 0, 0, 0], grpc_port)));
+    }
+
+    if let Some(amqp_port) = settings.amqp_port {
+        crate::amqp::start(app.clone(), SocketAddr::from(([0, 0, 0, 0], amqp_port)));
+    }
+
+    if let Some(mqtt_port) = settings.mqtt_port {
+        crate::mqtt::start(app.clone(), SocketAddr::from(([0, 0, 0, 0], mqtt_port)));
+    }
+
     let mut metrics_timer = MyTimer::new(Duration::from_secs(1));
     metrics_timer.register_timer("Metrics", Arc::new(MetricsTimer::new(app.clone())));
+    metrics_timer.register_timer(
+        "SettingsReload",
+        Arc::new(SettingsReloadTimer::new(app.clone()).await),
+    );
+
+    //Export and alert timers wait for remote services. They do not share a timer with the liveness heartbeats
+    let mut telemetry_timer = MyTimer::new(Duration::from_secs(1));
+    telemetry_timer.register_timer(
+        "TelemetryExport",
+        Arc::new(TelemetryExportTimer::new(app.clone())),
+    );
 
-    let mut persist_and_gc_timer = MyTimer::new(settings.persist_timer_interval);
-    persist_and_gc_timer.register_timer(
+    let mut alerts_timer = MyTimer::new(Duration::from_secs(1));
+    alerts_timer.register_timer("Alerts", Arc::new(AlertsTimer::new(app.clone())));
+
+    let mut persist_timer = MyTimer::new(settings.persist_timer_interval);
+    persist_timer.register_timer(
         "PersistTopicsAndQueues",
         Arc::new(PersistTopicsAndQueuesTimer::new(app.clone())),
     );
-    persist_and_gc_timer.register_timer("GC", Arc::new(GcTimer::new(app.clone())));
+
+    let mut gc_timer = MyTimer::new(settings.persist_timer_interval);
+    gc_timer.register_timer("GC", Arc::new(GcTimer::new(app.clone())));
 
     let mut dead_subscribers = MyTimer::new(Duration::from_secs(10));
     dead_subscribers.register_timer(
@@ -85,9 +165,29 @@ async fn main() {
         Arc::new(DeadSubscribersKickerTimer::new(app.clone())),
     );
 
+    let mut metrics_history_timer = MyTimer::new(Duration::from_secs(60));
+    metrics_history_timer.register_timer(
+        "MetricsHistorySave",
+        Arc::new(MetricsHistorySaveTimer::new(app.clone())),
+    );
+
     metrics_timer.start(app.clone(), app.logs.clone());
-    persist_and_gc_timer.start(app.clone(), app.logs.clone());
+    persist_timer.start(app.clone(), app.logs.clone());
+    gc_timer.start(app.clone(), app.logs.clone());
     dead_subscribers.start(app.clone(), app.logs.clone());
+
+    if app.telemetry.is_enabled() {
+        telemetry_timer.start(app.clone(), app.logs.clone());
+    }
+
+    if app.alerts.is_enabled() {
+        alerts_timer.start(app.clone(), app.logs.clone());
+    }
+
+    if app.metrics_history.is_persisted() {
+        metrics_history_timer.start(app.clone(), app.logs.clone());
+    }
+
     app.immediatly_persist_event_loop
         .start(app.clone(), app.logs.clone())
         .await;
@@ -98,6 +198,12 @@ async fn main() {
     )
     .unwrap();
 
+    signal_hook::flag::register(
+        signal_hook::consts::SIGHUP,
+        app.settings_reload_requested.clone(),
+    )
+    .unwrap();
+
     shut_down_task(app).await;
 
     for task in tasks {
@@ -108,7 +214,13 @@ async fn main() {
 async fn shut_down_task(app: Arc<AppContext>) {
     app.states.wait_until_shutdown().await;
 
-    println!("Shut down detected. Waiting for 1 second to deliver all messages");
+    app.logs.add_info(
+        None,
+        crate::app::logs::SystemProcess::System,
+        "shutdown".to_string(),
+        "Shut down detected. Waiting for 1 second to deliver all messages".to_string(),
+        None,
+    );
     let duration = Duration::from_secs(1);
     tokio::time::sleep(duration).await;
 
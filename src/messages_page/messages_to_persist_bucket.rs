@@ -4,16 +4,22 @@ pub struct MessagesToPersistBucket {
     messages_to_persist: Option<Vec<MessageProtobufModel>>,
     pub id: usize,
     pub first_message_id: MessageId,
+    pub not_found: Vec<MessageId>,
 }
 
 impl MessagesToPersistBucket {
-    pub fn new(id: usize, messages_to_persist: Vec<MessageProtobufModel>) -> Self {
+    pub fn new(
+        id: usize,
+        messages_to_persist: Vec<MessageProtobufModel>,
+        not_found: Vec<MessageId>,
+    ) -> Self {
         let first_message_id = messages_to_persist[0].message_id;
 
         Self {
             messages_to_persist: Some(messages_to_persist),
             first_message_id,
             id,
+            not_found,
         }
     }
 
@@ -24,9 +24,10 @@ impl SubPageData {
         }
     }
 
-    pub fn compile_messages_to_persist(&mut self, topic_id: &str) -> MessagesToPersistBucket {
+    pub fn compile_messages_to_persist(&mut self) -> MessagesToPersistBucket {
         let mut messages_to_persist = Vec::new();
         let mut ids = QueueWithIntervals::new();
+        let mut not_found = Vec::new();
 
         while let Some(message_id) = self.messages_to_persist.dequeue() {
             if let Some(msg) = self.sub_page.get_message(message_id) {
@@ -34,10 +35,7 @@ impl SubPageData {
                 messages_to_persist.push(model);
                 ids.enqueue(message_id);
             } else {
-                println!(
-                    "Topic:{}. Somehow we can not find message {} to persist",
-                    topic_id, message_id
-                );
+                not_found.push(message_id);
             }
         }
 
@@ -45,7 +43,7 @@ impl SubPageData {
         self.persist_id += 1;
 
         self.on_persistence.insert(persist_id, ids);
-        MessagesToPersistBucket::new(persist_id, messages_to_persist)
+        MessagesToPersistBucket::new(persist_id, messages_to_persist, not_found)
     }
 
     pub fn commit_persisted_messages(
@@ -62,6 +60,11 @@ impl SubPageData {
         }
     }
 
+    ///Messages which are sent to persistence and are not confirmed yet
+    pub fn get_on_persistence(&self) -> impl Iterator<Item = &QueueWithIntervals> {
+        self.on_persistence.values()
+    }
+
     pub fn can_be_gced(&self) -> bool {
         self.messages_to_persist.len() == 0 && self.on_persistence.len() == 0
     }
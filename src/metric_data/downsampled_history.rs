@@ -0,0 +1,184 @@
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+const SECONDS_TO_KEEP: usize = 60 * 60;
+const MINUTES_TO_KEEP: usize = 60 * 24;
+const HOURS_TO_KEEP: usize = 24 * 30;
+
+#[derive(Clone, Copy)]
+pub enum MetricsResolution {
+    Second,
+    Minute,
+    Hour,
+}
+
+impl MetricsResolution {
+    pub fn parse(src: &str) -> Option<Self> {
+        match src {
+            "1s" => Some(MetricsResolution::Second),
+            "1m" => Some(MetricsResolution::Minute),
+            "1h" => Some(MetricsResolution::Hour),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MetricsResolution::Second => "1s",
+            MetricsResolution::Minute => "1m",
+            MetricsResolution::Hour => "1h",
+        }
+    }
+
+    pub fn get_seconds(&self) -> i64 {
+        match self {
+            MetricsResolution::Second => 1,
+            MetricsResolution::Minute => 60,
+            MetricsResolution::Hour => 60 * 60,
+        }
+    }
+
+    fn get_max_points(&self) -> usize {
+        match self {
+            MetricsResolution::Second => SECONDS_TO_KEEP,
+            MetricsResolution::Minute => MINUTES_TO_KEEP,
+            MetricsResolution::Hour => HOURS_TO_KEEP,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy)]
+pub struct MetricPoint {
+    pub time: i64,
+    pub sum: i64,
+    pub samples: u32,
+}
+
+impl MetricPoint {
+    pub fn get_value(&self) -> f64 {
+        if self.samples == 0 {
+            return 0.0;
+        }
+
+        self.sum as f64 / self.samples as f64
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+pub struct DownsampledHistory {
+    seconds: VecDeque<MetricPoint>,
+    minutes: VecDeque<MetricPoint>,
+    hours: VecDeque<MetricPoint>,
+}
+
+impl DownsampledHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn put(&mut self, unix_seconds: i64, value: i64) {
+        put(
+            &mut self.seconds,
+            MetricsResolution::Second,
+            unix_seconds,
+            value,
+        );
+        put(
+            &mut self.minutes,
+            MetricsResolution::Minute,
+            unix_seconds,
+            value,
+        );
+        put(
+            &mut self.hours,
+            MetricsResolution::Hour,
+            unix_seconds,
+            value,
+        );
+    }
+
+    pub fn get(&self, resolution: MetricsResolution, from: i64, to: i64) -> Vec<MetricPoint> {
+        let points = match resolution {
+            MetricsResolution::Second => &self.seconds,
+            MetricsResolution::Minute => &self.minutes,
+            MetricsResolution::Hour => &self.hours,
+        };
+
+        points
+            .iter()
+            .filter(|point| point.time >= from && point.time <= to)
+            .copied()
+            .collect()
+    }
+}
+
+fn put(
+    points: &mut VecDeque<MetricPoint>,
+    resolution: MetricsResolution,
+    unix_seconds: i64,
+    value: i64,
+) {
+    let time = unix_seconds - unix_seconds % resolution.get_seconds();
+
+    if let Some(last) = points.back_mut() {
+        if last.time == time {
+            last.sum += value;
+            last.samples += 1;
+            return;
+        }
+
+        //Clock went back. We do not rewrite the history
+        if last.time > time {
+            return;
+        }
+    }
+
+    if points.len() == resolution.get_max_points() {
+        points.pop_front();
+    }
+
+    points.push_back(MetricPoint {
+        time,
+        sum: value,
+        samples: 1,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_values_are_downsampled() {
+        let mut history = DownsampledHistory::new();
+
+        for second in 0..120 {
+            history.put(second, second % 2 * 10);
+        }
+
+        assert_eq!(120, history.get(MetricsResolution::Second, 0, 200).len());
+
+        let minutes = history.get(MetricsResolution::Minute, 0, 200);
+        assert_eq!(2, minutes.len());
+        assert_eq!(60, minutes[1].time);
+        assert_eq!(5.0, minutes[1].get_value());
+
+        let hours = history.get(MetricsResolution::Hour, 0, 200);
+        assert_eq!(1, hours.len());
+        assert_eq!(120, hours[0].samples);
+    }
+
+    #[test]
+    fn test_old_points_are_dropped() {
+        let mut history = DownsampledHistory::new();
+
+        for second in 0..SECONDS_TO_KEEP as i64 + 10 {
+            history.put(second, 1);
+        }
+
+        let seconds = history.get(MetricsResolution::Second, 0, i64::MAX);
+        assert_eq!(SECONDS_TO_KEEP, seconds.len());
+        assert_eq!(10, seconds[0].time);
+    }
+}
@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+
+use rust_extensions::date_time::DateTimeAsMicroseconds;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use super::{DownsampledHistory, MetricPoint, MetricsResolution};
+
+#[derive(Serialize, Deserialize, Default)]
+struct TopicMetricsHistory {
+    publish: DownsampledHistory,
+    queues: HashMap<String, DownsampledHistory>,
+}
+
+pub struct MetricsHistoryStore {
+    topics: Mutex<HashMap<String, TopicMetricsHistory>>,
+    file_name: Option<String>,
+}
+
+impl MetricsHistoryStore {
+    pub fn new(file_name: Option<String>) -> Self {
+        Self {
+            topics: Mutex::new(HashMap::new()),
+            file_name,
+        }
+    }
+
+    pub fn is_persisted(&self) -> bool {
+        self.file_name.is_some()
+    }
+
+    pub async fn put_publish_rate(
+        &self,
+        topic_id: &str,
+        now: DateTimeAsMicroseconds,
+        value: usize,
+    ) {
+        let mut write_access = self.topics.lock().await;
+
+        write_access
+            .entry(topic_id.to_string())
+            .or_default()
+            .publish
+            .put(get_unix_seconds(now), value as i64);
+    }
+
+    pub async fn put_delivery_rate(
+        &self,
+        topic_id: &str,
+        queue_id: &str,
+        now: DateTimeAsMicroseconds,
+        value: usize,
+    ) {
+        let mut write_access = self.topics.lock().await;
+
+        write_access
+            .entry(topic_id.to_string())
+            .or_default()
+            .queues
+            .entry(queue_id.to_string())
+            .or_default()
+            .put(get_unix_seconds(now), value as i64);
+    }
+
+    pub async fn get_publish_rate(
+        &self,
+        topic_id: &str,
+        resolution: MetricsResolution,
+        from: i64,
+        to: i64,
+    ) -> Vec<MetricPoint> {
+        let read_access = self.topics.lock().await;
+
+        match read_access.get(topic_id) {
+            Some(topic) => topic.publish.get(resolution, from, to),
+            None => vec![],
+        }
+    }
+
+    pub async fn get_delivery_rate(
+        &self,
+        topic_id: &str,
+        queue_id: &str,
+        resolution: MetricsResolution,
+        from: i64,
+        to: i64,
+    ) -> Vec<MetricPoint> {
+        let read_access = self.topics.lock().await;
+
+        match read_access
+            .get(topic_id)
+            .and_then(|topic| topic.queues.get(queue_id))
+        {
+            Some(queue) => queue.get(resolution, from, to),
+            None => vec![],
+        }
+    }
+
+    pub async fn remove_topic(&self, topic_id: &str) {
+        let mut write_access = self.topics.lock().await;
+        write_access.remove(topic_id);
+    }
+
+    pub async fn remove_queue(&self, topic_id: &str, queue_id: &str) {
+        let mut write_access = self.topics.lock().await;
+
+        if let Some(topic) = write_access.get_mut(topic_id) {
+            topic.queues.remove(queue_id);
+        }
+    }
+
+    pub async fn save(&self) -> Result<(), String> {
+        let file_name = match &self.file_name {
+            Some(file_name) => file_name,
+            None => return Ok(()),
+        };
+
+        let content = {
+            let read_access = self.topics.lock().await;
+            serde_json::to_vec(&*read_access)
+                .map_err(|err| format!("Can not serialize metrics history: {:?}", err))?
+        };
+
+        //Write to a temp file first so a crash during the save never leaves a truncated history
+        let temp_file_name = format!("{}.tmp", file_name);
+
+        tokio::fs::write(temp_file_name.as_str(), content)
+            .await
+            .map_err(|err| {
+                format!(
+                    "Can not write metrics history to {}: {:?}",
+                    temp_file_name, err
+                )
+            })?;
+
+        tokio::fs::rename(temp_file_name.as_str(), file_name)
+            .await
+            .map_err(|err| {
+                format!(
+                    "Can not rename {} to {}: {:?}",
+                    temp_file_name, file_name, err
+                )
+            })
+    }
+
+    pub async fn load(&self) -> Result<(), String> {
+        let file_name = match &self.file_name {
+            Some(file_name) => file_name,
+            None => return Ok(()),
+        };
+
+        let content = match tokio::fs::read(file_name).await {
+            Ok(content) => content,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(err) => {
+                return Err(format!(
+                    "Can not read metrics history from {}: {:?}",
+                    file_name, err
+                ))
+            }
+        };
+
+        let topics: HashMap<String, TopicMetricsHistory> = serde_json::from_slice(&content)
+            .map_err(|err| format!("Can not parse metrics history {}: {:?}", file_name, err))?;
+
+        let mut write_access = self.topics.lock().await;
+        *write_access = topics;
+
+        Ok(())
+    }
+}
+
+pub fn get_unix_seconds(now: DateTimeAsMicroseconds) -> i64 {
+    now.unix_microseconds / 1_000_000
+}
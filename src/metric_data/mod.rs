@@ -1,5 +1,9 @@
+mod downsampled_history;
 mod metric_one_second;
 mod metrics_history;
+mod metrics_history_store;
 
+pub use downsampled_history::{DownsampledHistory, MetricPoint, MetricsResolution};
 pub use metric_one_second::MetricOneSecond;
 pub use metrics_history::MetricsHistory;
+pub use metrics_history_store::{get_unix_seconds, MetricsHistoryStore};
@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+#[derive(Clone)]
+pub struct MqttSubscription {
+    pub topic_id: String,
+    pub queue_id: String,
+    pub qos: u8,
+}
+
+pub struct MqttConfirmation {
+    pub topic_id: String,
+    pub queue_id: String,
+    pub confirmation_id: i64,
+    pub delivered: bool,
+}
+
+struct DeliveryPackage {
+    topic_id: String,
+    queue_id: String,
+    confirmation_id: i64,
+    pending: usize,
+}
+
+pub struct MqttDeliveries {
+    subscriptions: HashMap<String, MqttSubscription>,
+    last_packet_id: u16,
+    on_delivery: HashMap<u16, String>,
+    packages: HashMap<String, DeliveryPackage>,
+}
+
+impl MqttDeliveries {
+    pub fn new() -> Self {
+        Self {
+            subscriptions: HashMap::new(),
+            last_packet_id: 0,
+            on_delivery: HashMap::new(),
+            packages: HashMap::new(),
+        }
+    }
+
+    pub fn add_subscription(&mut self, subscription: MqttSubscription) {
+        self.subscriptions
+            .insert(subscription.topic_id.to_string(), subscription);
+    }
+
+    pub fn remove_subscription(&mut self, topic_id: &str) -> Option<MqttSubscription> {
+        self.subscriptions.remove(topic_id)
+    }
+
+    pub fn get_subscription(&self, topic_id: &str) -> Option<&MqttSubscription> {
+        self.subscriptions.get(topic_id)
+    }
+
+    pub fn get_subscriptions(&self) -> Vec<MqttSubscription> {
+        self.subscriptions.values().cloned().collect()
+    }
+
+    pub fn register_delivery(
+        &mut self,
+        subscription: &MqttSubscription,
+        confirmation_id: i64,
+        amount: usize,
+    ) -> Option<Vec<u16>> {
+        if subscription.qos == 0 {
+            return Some(vec![0; amount]);
+        }
+
+        if self.on_delivery.len() + amount > u16::MAX as usize {
+            return None;
+        }
+
+        let package_key = format!(
+            "{}/{}/{}",
+            subscription.topic_id, subscription.queue_id, confirmation_id
+        );
+
+        let mut packet_ids = Vec::with_capacity(amount);
+
+        for _ in 0..amount {
+            let packet_id = self.get_next_packet_id()?;
+            self.on_delivery.insert(packet_id, package_key.to_string());
+            packet_ids.push(packet_id);
+        }
+
+        self.packages.insert(
+            package_key,
+            DeliveryPackage {
+                topic_id: subscription.topic_id.to_string(),
+                queue_id: subscription.queue_id.to_string(),
+                confirmation_id,
+                pending: amount,
+            },
+        );
+
+        Some(packet_ids)
+    }
+
+    pub fn confirm(&mut self, packet_id: u16) -> Option<MqttConfirmation> {
+        let package_key = self.on_delivery.remove(&packet_id)?;
+
+        let package = self.packages.get_mut(&package_key)?;
+        package.pending -= 1;
+
+        if package.pending > 0 {
+            return None;
+        }
+
+        let package = self.packages.remove(&package_key)?;
+
+        Some(MqttConfirmation {
+            topic_id: package.topic_id,
+            queue_id: package.queue_id,
+            confirmation_id: package.confirmation_id,
+            delivered: true,
+        })
+    }
+
+    fn get_next_packet_id(&mut self) -> Option<u16> {
+        for _ in 0..u16::MAX {
+            self.last_packet_id = self.last_packet_id.wrapping_add(1);
+
+            if self.last_packet_id != 0 && !self.on_delivery.contains_key(&self.last_packet_id) {
+                return Some(self.last_packet_id);
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delivery_is_rejected_when_packet_ids_are_exhausted() {
+        let mut deliveries = MqttDeliveries::new();
+
+        let subscription = MqttSubscription {
+            topic_id: "topic".to_string(),
+            queue_id: "queue".to_string(),
+            qos: 1,
+        };
+
+        let packet_ids = deliveries.register_delivery(&subscription, 1, u16::MAX as usize - 1);
+        assert_eq!(u16::MAX as usize - 1, packet_ids.unwrap().len());
+
+        assert!(deliveries.register_delivery(&subscription, 2, 2).is_none());
+        assert_eq!(
+            1,
+            deliveries
+                .register_delivery(&subscription, 3, 1)
+                .unwrap()
+                .len()
+        );
+        assert!(deliveries.register_delivery(&subscription, 4, 1).is_none());
+    }
+}
@@ -0,0 +1,10 @@
+mod deliveries;
+mod mqtt_clients;
+mod mqtt_connection;
+mod mqtt_server;
+pub mod packets;
+
+pub use deliveries::{MqttConfirmation, MqttDeliveries, MqttSubscription};
+pub use mqtt_clients::MqttClients;
+pub use mqtt_server::start;
+pub use packets::serialize_publish;
@@ -0,0 +1,87 @@
+use std::{collections::HashMap, sync::Arc};
+
+use tokio::sync::Mutex;
+
+use crate::sessions::{MyServiceBusSession, SessionId};
+
+use super::MqttSubscription;
+
+struct MqttClientsData {
+    active: HashMap<String, Arc<MyServiceBusSession>>,
+    persistent_subscriptions: HashMap<String, Vec<MqttSubscription>>,
+}
+
+pub struct MqttClients {
+    data: Mutex<MqttClientsData>,
+}
+
+impl MqttClients {
+    pub fn new() -> Self {
+        Self {
+            data: Mutex::new(MqttClientsData {
+                active: HashMap::new(),
+                persistent_subscriptions: HashMap::new(),
+            }),
+        }
+    }
+
+    pub async fn connect(
+        &self,
+        client_id: &str,
+        session: Arc<MyServiceBusSession>,
+        clean_session: bool,
+    ) -> (
+        Option<Arc<MyServiceBusSession>>,
+        Option<Vec<MqttSubscription>>,
+    ) {
+        let mut write_access = self.data.lock().await;
+
+        let kicked_session = write_access.active.insert(client_id.to_string(), session);
+
+        if clean_session {
+            write_access.persistent_subscriptions.remove(client_id);
+            return (kicked_session, None);
+        }
+
+        let subscriptions = match &kicked_session {
+            Some(kicked_session) => Some(
+                kicked_session
+                    .connection
+                    .unwrap_as_mqtt()
+                    .deliveries
+                    .lock()
+                    .unwrap()
+                    .get_subscriptions(),
+            ),
+            None => write_access.persistent_subscriptions.remove(client_id),
+        };
+
+        (kicked_session, subscriptions)
+    }
+
+    pub async fn disconnect(
+        &self,
+        client_id: &str,
+        session_id: SessionId,
+        subscriptions: Option<Vec<MqttSubscription>>,
+    ) {
+        let mut write_access = self.data.lock().await;
+
+        let is_active = match write_access.active.get(client_id) {
+            Some(session) => session.id == session_id,
+            None => false,
+        };
+
+        if !is_active {
+            return;
+        }
+
+        write_access.active.remove(client_id);
+
+        if let Some(subscriptions) = subscriptions {
+            write_access
+                .persistent_subscriptions
+                .insert(client_id.to_string(), subscriptions);
+        }
+    }
+}
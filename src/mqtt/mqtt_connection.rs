@@ -0,0 +1,462 @@
+use std::{net::SocketAddr, sync::Arc, time::Duration};
+
+use my_service_bus_shared::queue::TopicQueueType;
+use my_service_bus_tcp_shared::MessageToPublishTcpContract;
+use tokio::{
+    io::AsyncWriteExt,
+    net::{tcp::OwnedWriteHalf, TcpStream},
+    sync::mpsc::UnboundedReceiver,
+};
+
+use crate::{
+    app::{logs::SystemProcess, AppContext},
+    operations,
+    sessions::{MqttConnectionData, MyServiceBusSession},
+};
+
+use super::{
+    packets::{self, MqttConnect, MqttError, MqttPacket, MqttPublish},
+    MqttClients, MqttConfirmation, MqttSubscription,
+};
+
+const MAX_PACKET_SIZE: usize = 4 * 1024 * 1024;
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub async fn serve(
+    app: Arc<AppContext>,
+    clients: Arc<MqttClients>,
+    tcp_stream: TcpStream,
+    addr: SocketAddr,
+) {
+    let (mut read_half, mut write_half) = tcp_stream.into_split();
+
+    let connect = match tokio::time::timeout(
+        CONNECT_TIMEOUT,
+        packets::read_packet(&mut read_half, MAX_PACKET_SIZE),
+    )
+    .await
+    {
+        Ok(Ok((MqttPacket::Connect(connect), _))) => connect,
+        Ok(Ok(_)) => {
+            log_connection_error(&app, "First packet must be CONNECT".to_string(), addr);
+            return;
+        }
+        Ok(Err(err)) => {
+            log_connection_error(&app, format!("{:?}", err), addr);
+            return;
+        }
+        Err(_) => return,
+    };
+
+    let connect = match validate_connect(connect) {
+        Ok(connect) => connect,
+        Err(return_code) => {
+            let _ = write_half
+                .write_all(&packets::connack(false, return_code))
+                .await;
+            return;
+        }
+    };
+
+    let (sender, receiver) = tokio::sync::mpsc::unbounded_channel();
+    let (confirmations_sender, confirmations_receiver) = tokio::sync::mpsc::unbounded_channel();
+
+    let session = app
+        .sessions
+        .add_mqtt(MqttConnectionData::new(
+            connect.client_id.to_string(),
+            connect.protocol_level,
+            addr,
+            sender,
+            confirmations_sender,
+        ))
+        .await;
+
+    tokio::spawn(write_loop(write_half, receiver));
+    tokio::spawn(confirmations_loop(app.clone(), confirmations_receiver));
+
+    let (kicked_session, restored_subscriptions) = clients
+        .connect(
+            connect.client_id.as_str(),
+            session.clone(),
+            connect.clean_session,
+        )
+        .await;
+
+    if let Some(kicked_session) = kicked_session {
+        kicked_session.disconnect().await;
+    }
+
+    let connection = MqttConnection {
+        app: app.clone(),
+        session: session.clone(),
+        queue_type: if connect.clean_session {
+            TopicQueueType::DeleteOnDisconnect
+        } else {
+            TopicQueueType::Permanent
+        },
+    };
+
+    connection.send(packets::connack(
+        restored_subscriptions.is_some(),
+        packets::CONNACK_ACCEPTED,
+    ));
+
+    if let Some(subscriptions) = restored_subscriptions {
+        for subscription in subscriptions {
+            connection
+                .subscribe(subscription.topic_id, subscription.qos)
+                .await;
+        }
+    }
+
+    let read_timeout = if connect.keep_alive > 0 {
+        Some(Duration::from_millis(connect.keep_alive as u64 * 1500))
+    } else {
+        None
+    };
+
+    loop {
+        let packet = match read_timeout {
+            Some(read_timeout) => {
+                let read_future = packets::read_packet(&mut read_half, MAX_PACKET_SIZE);
+                match tokio::time::timeout(read_timeout, read_future).await {
+                    Ok(packet) => packet,
+                    Err(_) => Err(MqttError::Protocol(
+                        "Keep alive timeout is expired".to_string(),
+                    )),
+                }
+            }
+            None => packets::read_packet(&mut read_half, MAX_PACKET_SIZE).await,
+        };
+
+        let result = match packet {
+            Ok((packet, size)) => {
+                connection.get_data().update_read_amount(size);
+                connection.handle_packet(packet).await
+            }
+            Err(err) => Err(err),
+        };
+
+        match result {
+            Ok(true) => {}
+            Ok(false) => break,
+            Err(MqttError::Io(_)) => break,
+            Err(MqttError::Protocol(reason)) => {
+                app.logs.add_error(
+                    None,
+                    SystemProcess::TcpSocket,
+                    "MqttConnection".to_string(),
+                    reason,
+                    Some(format!(
+                        "SessionId:{}. ClientId:{}",
+                        session.id, connect.client_id
+                    )),
+                );
+                break;
+            }
+        }
+    }
+
+    let persistent_subscriptions = if connect.clean_session {
+        None
+    } else {
+        Some(
+            connection
+                .get_data()
+                .deliveries
+                .lock()
+                .unwrap()
+                .get_subscriptions(),
+        )
+    };
+
+    clients
+        .disconnect(
+            connect.client_id.as_str(),
+            session.id,
+            persistent_subscriptions,
+        )
+        .await;
+
+    session.disconnect().await;
+    if let Some(session) = app.sessions.remove_mqtt(session.id).await {
+        operations::sessions::disconnect(app.as_ref(), session.as_ref()).await;
+    }
+}
+
+fn validate_connect(mut connect: MqttConnect) -> Result<MqttConnect, u8> {
+    if connect.protocol_level != 3 && connect.protocol_level != 4 {
+        return Err(packets::CONNACK_UNACCEPTABLE_PROTOCOL_VERSION);
+    }
+
+    if connect.client_id.is_empty() {
+        if !connect.clean_session {
+            return Err(packets::CONNACK_IDENTIFIER_REJECTED);
+        }
+
+        connect.client_id = format!("mqtt-{}", uuid::Uuid::new_v4());
+    }
+
+    Ok(connect)
+}
+
+fn log_connection_error(app: &AppContext, message: String, addr: SocketAddr) {
+    app.logs.add_error(
+        None,
+        SystemProcess::TcpSocket,
+        "MqttConnect".to_string(),
+        message,
+        Some(format!("Addr:{}", addr)),
+    );
+}
+
+async fn write_loop(mut write_half: OwnedWriteHalf, mut receiver: UnboundedReceiver<Vec<u8>>) {
+    while let Some(payload) = receiver.recv().await {
+        if payload.is_empty() {
+            break;
+        }
+
+        if write_half.write_all(&payload).await.is_err() {
+            break;
+        }
+    }
+
+    let _ = write_half.shutdown().await;
+}
+
+async fn confirmations_loop(
+    app: Arc<AppContext>,
+    mut receiver: UnboundedReceiver<MqttConfirmation>,
+) {
+    while let Some(confirmation) = receiver.recv().await {
+        apply_confirmation(&app, confirmation).await;
+    }
+}
+
+async fn apply_confirmation(app: &Arc<AppContext>, confirmation: MqttConfirmation) {
+    let result = if confirmation.delivered {
+        operations::delivery_confirmation::all_confirmed(
+            app,
+            confirmation.topic_id.as_str(),
+            confirmation.queue_id.as_str(),
+            confirmation.confirmation_id,
+        )
+        .await
+    } else {
+        operations::delivery_confirmation::all_fail(
+            app,
+            confirmation.topic_id.as_str(),
+            confirmation.queue_id.as_str(),
+            confirmation.confirmation_id,
+        )
+        .await
+    };
+
+    if let Err(err) = result {
+        app.logs.add_error(
+            Some(confirmation.topic_id),
+            SystemProcess::DeliveryOperation,
+            "MqttConfirmation".to_string(),
+            format!("Err: {:?}", err),
+            Some(format!(
+                "QueueId:{}. ConfirmationId:{}",
+                confirmation.queue_id, confirmation.confirmation_id
+            )),
+        );
+    }
+}
+
+struct MqttConnection {
+    app: Arc<AppContext>,
+    session: Arc<MyServiceBusSession>,
+    queue_type: TopicQueueType,
+}
+
+impl MqttConnection {
+    fn get_data(&self) -> &MqttConnectionData {
+        self.session.connection.unwrap_as_mqtt()
+    }
+
+    fn send(&self, payload: Vec<u8>) {
+        self.get_data().send_payload(payload);
+    }
+
+    async fn handle_packet(&self, packet: MqttPacket) -> Result<bool, MqttError> {
+        match packet {
+            MqttPacket::Connect(_) => {
+                return Err(MqttError::Protocol(
+                    "CONNECT packet is received twice".to_string(),
+                ));
+            }
+            MqttPacket::Publish(publish) => {
+                self.publish(publish).await?;
+            }
+            MqttPacket::PubAck { packet_id } => {
+                let confirmation = self
+                    .get_data()
+                    .deliveries
+                    .lock()
+                    .unwrap()
+                    .confirm(packet_id);
+
+                if let Some(confirmation) = confirmation {
+                    apply_confirmation(&self.app, confirmation).await;
+                }
+            }
+            MqttPacket::Subscribe { packet_id, topics } => {
+                let mut return_codes = Vec::with_capacity(topics.len());
+
+                for (topic_filter, qos) in topics {
+                    return_codes.push(self.subscribe(topic_filter, qos).await);
+                }
+
+                self.send(packets::suback(packet_id, &return_codes));
+            }
+            MqttPacket::Unsubscribe { packet_id, topics } => {
+                for topic_filter in topics {
+                    self.unsubscribe(topic_filter.as_str()).await;
+                }
+
+                self.send(packets::unsuback(packet_id));
+            }
+            MqttPacket::PingReq => {
+                self.send(packets::pingresp());
+            }
+            MqttPacket::Disconnect => {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    async fn publish(&self, publish: MqttPublish) -> Result<(), MqttError> {
+        if publish.qos > 1 {
+            return Err(MqttError::Protocol(format!(
+                "QoS {} is not supported. Topic: {}",
+                publish.qos, publish.topic_name
+            )));
+        }
+
+        let message = MessageToPublishTcpContract {
+            headers: None,
+            content: publish.payload,
+        };
+
+        let result = operations::publisher::publish(
+            &self.app,
+            publish.topic_name.as_str(),
+            vec![message],
+            publish.qos == 1,
+            &self.session,
+        )
+        .await;
+
+        if let Err(err) = result {
+            if publish.qos == 0 {
+                self.app.logs.add_error(
+                    Some(publish.topic_name),
+                    SystemProcess::TopicOperation,
+                    "MqttPublish".to_string(),
+                    format!("Err: {:?}", err),
+                    Some(format!("SessionId:{}", self.session.id)),
+                );
+
+                return Ok(());
+            }
+
+            return Err(MqttError::Protocol(format!(
+                "Can not publish to topic {}. Err: {:?}",
+                publish.topic_name, err
+            )));
+        }
+
+        if let Some(packet_id) = publish.packet_id {
+            self.send(packets::puback(packet_id));
+        }
+
+        Ok(())
+    }
+
+    async fn subscribe(&self, topic_filter: String, qos: u8) -> u8 {
+        if topic_filter.contains('+') || topic_filter.contains('#') {
+            return packets::SUBACK_FAILURE;
+        }
+
+        let qos = qos.min(1);
+
+        let queue_id = self.get_data().client_id.to_string();
+
+        self.get_data()
+            .deliveries
+            .lock()
+            .unwrap()
+            .add_subscription(MqttSubscription {
+                topic_id: topic_filter.to_string(),
+                queue_id: queue_id.to_string(),
+                qos,
+            });
+
+        let result = operations::subscriber::subscribe_to_queue(
+            &self.app,
+            topic_filter.to_string(),
+            queue_id,
+            self.queue_type,
+            &self.session,
+        )
+        .await;
+
+        if let Err(err) = result {
+            self.get_data()
+                .deliveries
+                .lock()
+                .unwrap()
+                .remove_subscription(topic_filter.as_str());
+
+            self.app.logs.add_error(
+                Some(topic_filter),
+                SystemProcess::QueueOperation,
+                "MqttSubscribe".to_string(),
+                format!("Err: {:?}", err),
+                Some(format!("SessionId:{}", self.session.id)),
+            );
+
+            return packets::SUBACK_FAILURE;
+        }
+
+        qos
+    }
+
+    async fn unsubscribe(&self, topic_filter: &str) {
+        let subscription = self
+            .get_data()
+            .deliveries
+            .lock()
+            .unwrap()
+            .remove_subscription(topic_filter);
+
+        let subscription = match subscription {
+            Some(subscription) => subscription,
+            None => return,
+        };
+
+        let result = operations::subscriber::unsubscribe_from_queue(
+            &self.app,
+            subscription.topic_id.as_str(),
+            subscription.queue_id.as_str(),
+            self.session.id,
+        )
+        .await;
+
+        if let Err(err) = result {
+            self.app.logs.add_error(
+                Some(subscription.topic_id),
+                SystemProcess::QueueOperation,
+                "MqttUnsubscribe".to_string(),
+                format!("Err: {:?}", err),
+                Some(format!("SessionId:{}", self.session.id)),
+            );
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use std::{net::SocketAddr, sync::Arc};
+
+use tokio::net::TcpListener;
+
+use crate::app::{logs::SystemProcess, AppContext};
+
+use super::MqttClients;
+
+pub fn start(app: Arc<AppContext>, addr: SocketAddr) {
+    tokio::spawn(async move {
+        let listener = match TcpListener::bind(addr).await {
+            Ok(listener) => listener,
+            Err(err) => {
+                app.logs.add_fatal_error(
+                    SystemProcess::System,
+                    "MqttServer".to_string(),
+                    format!("Can not start Mqtt listener. Err: {:?}", err),
+                    Some(format!("Addr:{}", addr)),
+                );
+                return;
+            }
+        };
+
+        app.logs.add_info(
+            None,
+            SystemProcess::System,
+            "MqttServer".to_string(),
+            format!("Mqtt listener is started at {}", addr),
+            None,
+        );
+
+        let clients = Arc::new(MqttClients::new());
+
+        loop {
+            match listener.accept().await {
+                Ok((tcp_stream, remote_addr)) => {
+                    tokio::spawn(super::mqtt_connection::serve(
+                        app.clone(),
+                        clients.clone(),
+                        tcp_stream,
+                        remote_addr,
+                    ));
+                }
+                Err(err) => {
+                    app.logs.add_error(
+                        None,
+                        SystemProcess::TcpSocket,
+                        "MqttAccept".to_string(),
+                        format!("Err: {:?}", err),
+                        None,
+                    );
+                }
+            }
+        }
+    });
+}
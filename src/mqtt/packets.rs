@@ -0,0 +1,425 @@
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+pub const PACKET_CONNECT: u8 = 1;
+pub const PACKET_CONNACK: u8 = 2;
+pub const PACKET_PUBLISH: u8 = 3;
+pub const PACKET_PUBACK: u8 = 4;
+pub const PACKET_SUBSCRIBE: u8 = 8;
+pub const PACKET_SUBACK: u8 = 9;
+pub const PACKET_UNSUBSCRIBE: u8 = 10;
+pub const PACKET_UNSUBACK: u8 = 11;
+pub const PACKET_PINGREQ: u8 = 12;
+pub const PACKET_PINGRESP: u8 = 13;
+pub const PACKET_DISCONNECT: u8 = 14;
+
+pub const CONNACK_ACCEPTED: u8 = 0;
+pub const CONNACK_UNACCEPTABLE_PROTOCOL_VERSION: u8 = 1;
+pub const CONNACK_IDENTIFIER_REJECTED: u8 = 2;
+
+pub const SUBACK_FAILURE: u8 = 0x80;
+
+#[derive(Debug)]
+pub enum MqttError {
+    Io(std::io::Error),
+    Protocol(String),
+}
+
+impl From<std::io::Error> for MqttError {
+    fn from(src: std::io::Error) -> Self {
+        Self::Io(src)
+    }
+}
+
+pub struct MqttConnect {
+    pub protocol_level: u8,
+    pub client_id: String,
+    pub clean_session: bool,
+    pub keep_alive: u16,
+}
+
+pub struct MqttPublish {
+    pub qos: u8,
+    pub topic_name: String,
+    pub packet_id: Option<u16>,
+    pub payload: Vec<u8>,
+}
+
+pub enum MqttPacket {
+    Connect(MqttConnect),
+    Publish(MqttPublish),
+    PubAck {
+        packet_id: u16,
+    },
+    Subscribe {
+        packet_id: u16,
+        topics: Vec<(String, u8)>,
+    },
+    Unsubscribe {
+        packet_id: u16,
+        topics: Vec<String>,
+    },
+    PingReq,
+    Disconnect,
+}
+
+pub async fn read_packet<TReader: AsyncRead + Unpin>(
+    reader: &mut TReader,
+    max_packet_size: usize,
+) -> Result<(MqttPacket, usize), MqttError> {
+    let fixed_header = reader.read_u8().await?;
+    let remaining_length = read_remaining_length(reader).await?;
+
+    if remaining_length > max_packet_size {
+        return Err(MqttError::Protocol(format!(
+            "Packet size {} exceeds max packet size {}",
+            remaining_length, max_packet_size
+        )));
+    }
+
+    let mut payload = vec![0u8; remaining_length];
+    reader.read_exact(&mut payload).await?;
+
+    let packet = parse_packet(fixed_header, &payload)?;
+
+    Ok((packet, remaining_length + 2))
+}
+
+async fn read_remaining_length<TReader: AsyncRead + Unpin>(
+    reader: &mut TReader,
+) -> Result<usize, MqttError> {
+    let mut result = 0usize;
+    let mut multiplier = 1usize;
+
+    for _ in 0..4 {
+        let encoded_byte = reader.read_u8().await?;
+        result += (encoded_byte & 0x7F) as usize * multiplier;
+
+        if encoded_byte & 0x80 == 0 {
+            return Ok(result);
+        }
+
+        multiplier *= 128;
+    }
+
+    Err(MqttError::Protocol(
+        "Malformed remaining length".to_string(),
+    ))
+}
+
+fn parse_packet(fixed_header: u8, payload: &[u8]) -> Result<MqttPacket, MqttError> {
+    let packet_type = fixed_header >> 4;
+    let flags = fixed_header & 0x0F;
+
+    let mut reader = PayloadReader::new(payload);
+
+    let result = match packet_type {
+        PACKET_CONNECT => {
+            let protocol_name = reader.read_str()?;
+            let protocol_level = reader.read_u8()?;
+            let connect_flags = reader.read_u8()?;
+            let keep_alive = reader.read_u16()?;
+
+            if protocol_name != "MQTT" && protocol_name != "MQIsdp" {
+                return Err(MqttError::Protocol(format!(
+                    "Unsupported protocol name {}",
+                    protocol_name
+                )));
+            }
+
+            let client_id = reader.read_str()?;
+
+            if connect_flags & 0x04 > 0 {
+                let _will_topic = reader.read_str()?;
+                let _will_message = reader.read_binary()?;
+            }
+
+            MqttPacket::Connect(MqttConnect {
+                protocol_level,
+                client_id,
+                clean_session: connect_flags & 0x02 > 0,
+                keep_alive,
+            })
+        }
+        PACKET_PUBLISH => {
+            let qos = (flags >> 1) & 0x03;
+            let topic_name = reader.read_str()?;
+
+            let packet_id = if qos > 0 {
+                Some(reader.read_u16()?)
+            } else {
+                None
+            };
+
+            MqttPacket::Publish(MqttPublish {
+                qos,
+                topic_name,
+                packet_id,
+                payload: reader.read_to_end().to_vec(),
+            })
+        }
+        PACKET_PUBACK => MqttPacket::PubAck {
+            packet_id: reader.read_u16()?,
+        },
+        PACKET_SUBSCRIBE => {
+            let packet_id = reader.read_u16()?;
+            let mut topics = Vec::new();
+
+            while !reader.is_eof() {
+                let topic_filter = reader.read_str()?;
+                let qos = reader.read_u8()?;
+                topics.push((topic_filter, qos));
+            }
+
+            MqttPacket::Subscribe { packet_id, topics }
+        }
+        PACKET_UNSUBSCRIBE => {
+            let packet_id = reader.read_u16()?;
+            let mut topics = Vec::new();
+
+            while !reader.is_eof() {
+                topics.push(reader.read_str()?);
+            }
+
+            MqttPacket::Unsubscribe { packet_id, topics }
+        }
+        PACKET_PINGREQ => MqttPacket::PingReq,
+        PACKET_DISCONNECT => MqttPacket::Disconnect,
+        _ => {
+            return Err(MqttError::Protocol(format!(
+                "Unsupported packet type {}",
+                packet_type
+            )))
+        }
+    };
+
+    Ok(result)
+}
+
+struct PayloadReader<'s> {
+    payload: &'s [u8],
+    pos: usize,
+}
+
+impl<'s> PayloadReader<'s> {
+    fn new(payload: &'s [u8]) -> Self {
+        Self { payload, pos: 0 }
+    }
+
+    fn is_eof(&self) -> bool {
+        self.pos >= self.payload.len()
+    }
+
+    fn read_slice(&mut self, len: usize) -> Result<&'s [u8], MqttError> {
+        if self.pos + len > self.payload.len() {
+            return Err(MqttError::Protocol(format!(
+                "Payload is too short. Pos: {}, requested: {}, len: {}",
+                self.pos,
+                len,
+                self.payload.len()
+            )));
+        }
+
+        let result = &self.payload[self.pos..self.pos + len];
+        self.pos += len;
+        Ok(result)
+    }
+
+    fn read_to_end(&mut self) -> &'s [u8] {
+        let result = &self.payload[self.pos..];
+        self.pos = self.payload.len();
+        result
+    }
+
+    fn read_u8(&mut self) -> Result<u8, MqttError> {
+        Ok(self.read_slice(1)?[0])
+    }
+
+    fn read_u16(&mut self) -> Result<u16, MqttError> {
+        let slice = self.read_slice(2)?;
+        Ok(u16::from_be_bytes([slice[0], slice[1]]))
+    }
+
+    fn read_binary(&mut self) -> Result<&'s [u8], MqttError> {
+        let len = self.read_u16()? as usize;
+        self.read_slice(len)
+    }
+
+    fn read_str(&mut self) -> Result<String, MqttError> {
+        let slice = self.read_binary()?;
+        Ok(String::from_utf8_lossy(slice).to_string())
+    }
+}
+
+fn serialize_packet(dest: &mut Vec<u8>, fixed_header: u8, payload: &[u8]) {
+    dest.push(fixed_header);
+
+    let mut remaining_length = payload.len();
+
+    loop {
+        let mut encoded_byte = (remaining_length % 128) as u8;
+        remaining_length /= 128;
+
+        if remaining_length > 0 {
+            encoded_byte |= 0x80;
+        }
+
+        dest.push(encoded_byte);
+
+        if remaining_length == 0 {
+            break;
+        }
+    }
+
+    dest.extend_from_slice(payload);
+}
+
+pub fn connack(session_present: bool, return_code: u8) -> Vec<u8> {
+    let mut result = Vec::with_capacity(4);
+    serialize_packet(
+        &mut result,
+        PACKET_CONNACK << 4,
+        &[if session_present { 1 } else { 0 }, return_code],
+    );
+    result
+}
+
+pub fn puback(packet_id: u16) -> Vec<u8> {
+    let mut result = Vec::with_capacity(4);
+    serialize_packet(&mut result, PACKET_PUBACK << 4, &packet_id.to_be_bytes());
+    result
+}
+
+pub fn suback(packet_id: u16, return_codes: &[u8]) -> Vec<u8> {
+    let mut payload = packet_id.to_be_bytes().to_vec();
+    payload.extend_from_slice(return_codes);
+
+    let mut result = Vec::with_capacity(payload.len() + 2);
+    serialize_packet(&mut result, PACKET_SUBACK << 4, &payload);
+    result
+}
+
+pub fn unsuback(packet_id: u16) -> Vec<u8> {
+    let mut result = Vec::with_capacity(4);
+    serialize_packet(&mut result, PACKET_UNSUBACK << 4, &packet_id.to_be_bytes());
+    result
+}
+
+pub fn pingresp() -> Vec<u8> {
+    vec![PACKET_PINGRESP << 4, 0]
+}
+
+pub fn serialize_publish(
+    dest: &mut Vec<u8>,
+    topic_name: &str,
+    qos: u8,
+    packet_id: u16,
+    dup: bool,
+    content: &[u8],
+) {
+    let topic_name = topic_name.as_bytes();
+
+    let mut payload = Vec::with_capacity(topic_name.len() + content.len() + 4);
+    payload.extend_from_slice(&(topic_name.len() as u16).to_be_bytes());
+    payload.extend_from_slice(topic_name);
+
+    if qos > 0 {
+        payload.extend_from_slice(&packet_id.to_be_bytes());
+    }
+
+    payload.extend_from_slice(content);
+
+    let mut fixed_header = PACKET_PUBLISH << 4 | qos << 1;
+
+    if dup && qos > 0 {
+        fixed_header |= 0x08;
+    }
+
+    serialize_packet(dest, fixed_header, &payload);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn parse(src: &[u8]) -> Result<MqttPacket, MqttError> {
+        let mut reader = src;
+        let (packet, _) = read_packet(&mut reader, 1024).await?;
+        Ok(packet)
+    }
+
+    #[tokio::test]
+    async fn test_connect_is_parsed() {
+        let mut payload = vec![0, 4, b'M', b'Q', b'T', b'T', 4, 0x02, 0, 60, 0, 3];
+        payload.extend_from_slice(b"cli");
+
+        let mut src = Vec::new();
+        serialize_packet(&mut src, PACKET_CONNECT << 4, &payload);
+
+        match parse(&src).await.unwrap() {
+            MqttPacket::Connect(connect) => {
+                assert_eq!(4, connect.protocol_level);
+                assert_eq!("cli", connect.client_id);
+                assert!(connect.clean_session);
+                assert_eq!(60, connect.keep_alive);
+            }
+            _ => panic!("Should not be here"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_serialized_publish_is_parsed_back() {
+        let mut src = Vec::new();
+        serialize_publish(&mut src, "topic", 1, 7, false, &[1, 2, 3]);
+
+        match parse(&src).await.unwrap() {
+            MqttPacket::Publish(publish) => {
+                assert_eq!(1, publish.qos);
+                assert_eq!("topic", publish.topic_name);
+                assert_eq!(Some(7), publish.packet_id);
+                assert_eq!(vec![1, 2, 3], publish.payload);
+            }
+            _ => panic!("Should not be here"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_with_several_topics_is_parsed() {
+        let payload = [0, 5, 0, 1, b'a', 1, 0, 1, b'b', 0];
+
+        let mut src = Vec::new();
+        serialize_packet(&mut src, PACKET_SUBSCRIBE << 4 | 0x02, &payload);
+
+        match parse(&src).await.unwrap() {
+            MqttPacket::Subscribe { packet_id, topics } => {
+                assert_eq!(5, packet_id);
+                assert_eq!(vec![("a".to_string(), 1), ("b".to_string(), 0)], topics);
+            }
+            _ => panic!("Should not be here"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_truncated_packet_is_rejected() {
+        let payload = [0, 5, 0, 10, b'a'];
+
+        let mut src = Vec::new();
+        serialize_packet(&mut src, PACKET_SUBSCRIBE << 4 | 0x02, &payload);
+
+        assert!(matches!(parse(&src).await, Err(MqttError::Protocol(_))));
+    }
+
+    #[tokio::test]
+    async fn test_packet_over_max_size_is_rejected() {
+        let mut src = Vec::new();
+        serialize_packet(&mut src, PACKET_PUBLISH << 4, &[0; 2048]);
+
+        assert!(matches!(parse(&src).await, Err(MqttError::Protocol(_))));
+    }
+
+    #[tokio::test]
+    async fn test_malformed_remaining_length_is_rejected() {
+        let src = [PACKET_PINGREQ << 4, 0xFF, 0xFF, 0xFF, 0xFF, 0x01];
+
+        assert!(matches!(parse(&src).await, Err(MqttError::Protocol(_))));
+    }
+}
@@ -0,0 +1,152 @@
+use std::sync::Arc;
+
+use my_service_bus_shared::queue::TopicQueueType;
+use rust_extensions::date_time::DateTimeAsMicroseconds;
+
+use crate::{
+    alerts::{AlertCondition, AlertEvent, AlertRuleType, AlertStatus},
+    app::{logs::SystemProcess, AppContext},
+};
+
+struct QueueState {
+    topic_id: String,
+    queue_id: String,
+    queue_type: TopicQueueType,
+    size: i64,
+    subscribers: usize,
+}
+
+pub async fn collect_conditions(
+    app: &AppContext,
+    now: DateTimeAsMicroseconds,
+) -> Vec<AlertCondition> {
+    let mut queues = Vec::new();
+
+    for topic in app.topic_list.get_all().await {
+        let topic_data = topic.get_access().await;
+
+        for queue in topic_data.queues.get_all() {
+            queues.push(QueueState {
+                topic_id: topic.topic_id.to_string(),
+                queue_id: queue.queue_id.to_string(),
+                queue_type: queue.queue_type.clone(),
+                size: queue.get_queue_size(),
+                subscribers: queue.subscribers.get_amount(),
+            });
+        }
+    }
+
+    let persistence_is_failing =
+        app.alerts.persistence_is_failing() || !app.timer_heartbeats.persist.is_alive(now);
+
+    let mut result = Vec::new();
+
+    for (rule_index, rule) in app.alerts.get_rules().iter().enumerate() {
+        match rule.rule_type {
+            AlertRuleType::QueueSize { threshold } => {
+                for queue in &queues {
+                    if queue.size > threshold
+                        && rule.matches_queue(&queue.topic_id, &queue.queue_id)
+                    {
+                        result.push(AlertCondition {
+                            rule_index,
+                            target: format!("{}/{}", queue.topic_id, queue.queue_id),
+                            message: format!(
+                                "Queue size is {}. Threshold is {}",
+                                queue.size, threshold
+                            ),
+                        });
+                    }
+                }
+            }
+            AlertRuleType::NoSubscribers => {
+                for queue in &queues {
+                    if is_permanent(&queue.queue_type)
+                        && queue.subscribers == 0
+                        && rule.matches_queue(&queue.topic_id, &queue.queue_id)
+                    {
+                        result.push(AlertCondition {
+                            rule_index,
+                            target: format!("{}/{}", queue.topic_id, queue.queue_id),
+                            message: format!(
+                                "Permanent queue has no subscribers. Queue size is {}",
+                                queue.size
+                            ),
+                        });
+                    }
+                }
+            }
+            AlertRuleType::PersistenceFailing => {
+                if persistence_is_failing {
+                    result.push(AlertCondition {
+                        rule_index,
+                        target: "persistence".to_string(),
+                        message: "Topics, queues or messages can not be persisted".to_string(),
+                    });
+                }
+            }
+            AlertRuleType::SubscriberKicked => {}
+        }
+    }
+
+    result
+}
+
+pub fn notify(app: &Arc<AppContext>, events: Vec<AlertEvent>) {
+    for event in events {
+        let message = format!(
+            "Alert {} is {} for {}. {}",
+            event.rule,
+            event.status.as_str(),
+            event.target,
+            event.message
+        );
+
+        match event.status {
+            AlertStatus::Firing => app.logs.add_error(
+                None,
+                SystemProcess::System,
+                "alerts".to_string(),
+                message,
+                None,
+            ),
+            AlertStatus::Resolved => app.logs.add_info(
+                None,
+                SystemProcess::System,
+                "alerts".to_string(),
+                message,
+                None,
+            ),
+        }
+
+        for url in &app.alerts.webhooks {
+            let app = app.clone();
+            let url = url.to_string();
+            let event = event.clone();
+
+            tokio::spawn(async move {
+                let result =
+                    crate::alerts::send_webhook(url.as_str(), &app.alerts.service_name, &event)
+                        .await;
+
+                if let Err(err) = result {
+                    app.logs.add_error(
+                        None,
+                        SystemProcess::System,
+                        "alerts".to_string(),
+                        format!("Can not send alert {} to webhook {}", event.rule, url),
+                        Some(err),
+                    );
+                }
+            });
+        }
+    }
+}
+
+fn is_permanent(queue_type: &TopicQueueType) -> bool {
+    match queue_type {
+        TopicQueueType::Permanent => true,
+        TopicQueueType::PermanentWithSingleConnection => true,
+        TopicQueueType::DeleteOnDisconnect => false,
+    }
+}
@@ -3,6 +3,7 @@ use std::sync::Arc;
 
 use crate::{
     app::AppContext,
+    telemetry::TraceContext,
     topics::{Topic, TopicData},
 };
 
@@ -19,7 +20,7 @@ fn build_new_package_builder(
     topic_data: &mut TopicData,
 ) -> Option<SubscriberPackageBuilder> {
     for topic_queue in topic_data.queues.get_all_mut() {
-        if topic_queue.queue.len() == 0 {
+        if topic_queue.paused || topic_queue.queue.len() == 0 {
             continue;
         }
 
@@ -134,6 +135,15 @@ fn compile_and_deliver(
 
             if let Some(message_content) = sub_page.sub_page.get_message(message_id) {
                 let attempt_no = topic_queue.delivery_attempts.get(message_content.id);
+
+                if app.telemetry.is_enabled() {
+                    if let Some(trace_context) =
+                        TraceContext::from_headers(&message_content.headers)
+                    {
+                        package_builder.add_trace(message_content.id, trace_context);
+                    }
+                }
+
                 package_builder.add_message(message_content, attempt_no);
             } else {
                 if sub_page.sub_page.has_gced_messages() {
@@ -151,7 +161,7 @@ fn compile_and_deliver(
         }
     }
 
-    crate::operations::send_package::send_new_messages_to_deliver(package_builder, topic_data);
+    crate::operations::send_package::send_new_messages_to_deliver(app, package_builder, topic_data);
 }
 
 fn start_loading(
@@ -163,7 +173,11 @@ fn start_loading(
     package_builder: SubscriberPackageBuilder,
 ) {
     if package_builder.data_size() > 0 {
-        crate::operations::send_package::send_new_messages_to_deliver(package_builder, topic_data);
+        crate::operations::send_package::send_new_messages_to_deliver(
+            app,
+            package_builder,
+            topic_data,
+        );
 
         crate::operations::load_page_and_try_to_deliver_again(
             app,
@@ -195,7 +209,7 @@ mod tests {
 
     use crate::{
         sessions::{SessionId, TestConnectionData},
-        settings::SettingsModel,
+        settings::{CliArgs, SettingsModel},
     };
 
     use super::*;
@@ -209,7 +223,7 @@ mod tests {
 
         let settings = SettingsModel::create_test_settings(DELIVERY_SIZE);
 
-        let app = Arc::new(AppContext::new(&settings).await);
+        let app = Arc::new(AppContext::new(&settings, CliArgs::default()).await);
 
         let session = app
             .sessions
@@ -246,7 +260,7 @@ mod tests {
 
         let messages = vec![msg1, msg2];
 
-        crate::operations::publisher::publish(&app, TOPIC_NAME, messages, false, session.id)
+        crate::operations::publisher::publish(&app, TOPIC_NAME, messages, false, &session)
             .await
             .unwrap();
 
@@ -274,7 +288,7 @@ mod tests {
 
         let settings = SettingsModel::create_test_settings(DELIVERY_SIZE);
 
-        let app = Arc::new(AppContext::new(&settings).await);
+        let app = Arc::new(AppContext::new(&settings, CliArgs::default()).await);
 
         let session = app
             .sessions
@@ -319,6 +333,7 @@ mod tests {
                 QUEUE_NAME.to_string(),
                 TopicQueueType::Permanent,
                 queue_with_intervals,
+                false,
             );
         }
 
@@ -1,9 +1,14 @@
 use std::sync::Arc;
 
-use my_service_bus_shared::{queue_with_intervals::QueueWithIntervals, MySbMessageContent};
+use my_service_bus_shared::{
+    queue_with_intervals::QueueWithIntervals, MessageId, MySbMessageContent,
+};
 use my_service_bus_tcp_shared::{PacketProtVer, TcpContract};
 
-use crate::{queue_subscribers::SubscriberId, sessions::MyServiceBusSession, topics::Topic};
+use crate::{
+    queue_subscribers::SubscriberId, sessions::MyServiceBusSession, telemetry::TraceContext,
+    topics::Topic,
+};
 
 pub enum SendNewMessagesResult {
     Send {
@@ -11,6 +16,8 @@ pub enum SendNewMessagesResult {
         tcp_contract: TcpContract,
         queue_id: String,
         messages_on_delivery: QueueWithIntervals,
+        redelivered_amount: usize,
+        traces: Vec<(MessageId, TraceContext)>,
     },
     NothingToSend {
         queue_id: String,
@@ -24,6 +31,8 @@ pub struct SubscriberPackageBuilder {
     pub session: Arc<MyServiceBusSession>,
     pub subscriber_id: SubscriberId,
     messages_on_delivery: QueueWithIntervals,
+    redelivered_amount: usize,
+    traces: Vec<(MessageId, TraceContext)>,
     messages_count_position: usize,
     version: PacketProtVer,
 }
@@ -43,6 +52,8 @@ impl SubscriberPackageBuilder {
             subscriber_id,
             session,
             messages_on_delivery: QueueWithIntervals::new(),
+            redelivered_amount: 0,
+            traces: Vec::new(),
             messages_count_position: 0,
             version,
         }
@@ -76,6 +87,10 @@ impl SubscriberPackageBuilder {
     pub fn add_message(&mut self, message_content: &MySbMessageContent, attempt_no: i32) {
         self.messages_on_delivery.enqueue(message_content.id);
 
+        if attempt_no > 0 {
+            self.redelivered_amount += 1;
+        }
+
         let version = self.version.clone();
 
         let payload = self.get_or_create_payload();
@@ -88,6 +103,10 @@ impl SubscriberPackageBuilder {
         );
     }
 
+    pub fn add_trace(&mut self, message_id: MessageId, trace_context: TraceContext) {
+        self.traces.push((message_id, trace_context));
+    }
+
     pub fn get_result(self) -> SendNewMessagesResult {
         if let Some(mut payload) = self.payload {
             my_service_bus_tcp_shared::delivery_package_builder::update_amount_of_messages(
@@ -101,6 +120,8 @@ impl SubscriberPackageBuilder {
                 tcp_contract: TcpContract::Raw(payload),
                 queue_id: self.queue_id,
                 messages_on_delivery: self.messages_on_delivery,
+                redelivered_amount: self.redelivered_amount,
+                traces: self.traces,
             };
         }
 
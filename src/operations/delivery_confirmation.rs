@@ -1,8 +1,13 @@
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
-use my_service_bus_shared::queue_with_intervals::QueueWithIntervals;
+use my_service_bus_shared::{queue_with_intervals::QueueWithIntervals, MessageId};
+use rust_extensions::date_time::DateTimeAsMicroseconds;
 
-use crate::{app::AppContext, queue_subscribers::SubscriberId};
+use crate::{
+    app::AppContext,
+    queue_subscribers::{QueueSubscriberDeliveryState, SubscriberId},
+    queues::TopicQueue,
+};
 
 use super::OperationFailResult;
 
@@ -31,13 +36,33 @@ pub async fn all_confirmed(
                     queue_id: queue_id.to_string(),
                 })?;
 
-        if let Err(err) = topic_queue.confirmed_delivered(subscriber_id) {
-            app.logs.add_fatal_error(
-                crate::app::logs::SystemProcess::DeliveryOperation,
-                "confirm_delivery".to_string(),
-                format!("{:?}", err),
-                None,
-            );
+        let on_delivery = get_on_delivery_amount(topic_queue, subscriber_id);
+
+        confirmation_is_received(app, topic_queue, subscriber_id, on_delivery);
+        finish_delivery_spans(app, topic_queue, subscriber_id, DeliveryResult::All(true));
+        log_if_nothing_is_on_delivery(app, topic_queue, subscriber_id, "confirm_delivery");
+
+        match topic_queue.confirmed_delivered(subscriber_id) {
+            Ok(delivery_duration) => {
+                app.prometheus
+                    .messages_are_confirmed(topic_id, queue_id, on_delivery, 0);
+
+                if let Some(delivery_duration) = delivery_duration {
+                    app.prometheus.observe_delivery_round_trip(
+                        topic_id,
+                        queue_id,
+                        delivery_duration,
+                    );
+                }
+            }
+            Err(err) => {
+                app.logs.add_fatal_error(
+                    crate::app::logs::SystemProcess::DeliveryOperation,
+                    "confirm_delivery".to_string(),
+                    format!("{:?}", err),
+                    None,
+                );
+            }
         }
     }
 
@@ -71,6 +96,12 @@ pub async fn all_fail(
                     queue_id: queue_id.to_string(),
                 })?;
 
+        let on_delivery = get_on_delivery_amount(topic_queue, subscriber_id);
+
+        confirmation_is_received(app, topic_queue, subscriber_id, 0);
+        finish_delivery_spans(app, topic_queue, subscriber_id, DeliveryResult::All(false));
+        log_if_nothing_is_on_delivery(app, topic_queue, subscriber_id, "confirm_non_delivery");
+
         if let Err(err) = topic_queue.confirmed_non_delivered(subscriber_id) {
             app.logs.add_fatal_error(
                 crate::app::logs::SystemProcess::DeliveryOperation,
@@ -78,6 +109,9 @@ pub async fn all_fail(
                 format!("{:?}", err),
                 None,
             );
+        } else {
+            app.prometheus
+                .messages_are_confirmed(topic_id, queue_id, 0, on_delivery);
         }
     }
 
@@ -112,13 +146,29 @@ pub async fn intermediary_confirm(
                     queue_id: queue_id.to_string(),
                 })?;
 
-        if let Err(err) = topic_queue.intermediary_confirmed(subscriber_id, confirmed) {
-            app.logs.add_fatal_error(
-                crate::app::logs::SystemProcess::DeliveryOperation,
-                "some_messages_are_not_confirmed".to_string(),
-                format!("{:?}", err),
-                None,
-            );
+        let confirmed_amount = confirmed.len() as usize;
+
+        finish_delivery_spans(
+            app,
+            topic_queue,
+            subscriber_id,
+            DeliveryResult::Intermediary(&confirmed),
+        );
+
+        match topic_queue.intermediary_confirmed(subscriber_id, confirmed) {
+            Ok(not_on_delivery) => {
+                log_not_on_delivery(app, topic_queue, &not_on_delivery, "intermediary_confirm");
+                app.prometheus
+                    .messages_are_confirmed(topic_id, queue_id, confirmed_amount, 0);
+            }
+            Err(err) => {
+                app.logs.add_fatal_error(
+                    crate::app::logs::SystemProcess::DeliveryOperation,
+                    "some_messages_are_not_confirmed".to_string(),
+                    format!("{:?}", err),
+                    None,
+                );
+            }
         }
     }
 
@@ -152,13 +202,53 @@ pub async fn some_messages_are_confirmed(
                     queue_id: queue_id.to_string(),
                 })?;
 
-        if let Err(err) = topic_queue.confirmed_some_delivered(subscriber_id, confirmed_messages) {
-            app.logs.add_fatal_error(
-                crate::app::logs::SystemProcess::DeliveryOperation,
-                "some_messages_are_confirmed".to_string(),
-                format!("{:?}", err),
-                None,
-            );
+        let on_delivery = get_on_delivery_amount(topic_queue, subscriber_id);
+        let confirmed_amount = confirmed_messages.len() as usize;
+
+        confirmation_is_received(
+            app,
+            topic_queue,
+            subscriber_id,
+            confirmed_amount.min(on_delivery),
+        );
+        finish_delivery_spans(
+            app,
+            topic_queue,
+            subscriber_id,
+            DeliveryResult::Partial(&confirmed_messages),
+        );
+        log_if_nothing_is_on_delivery(
+            app,
+            topic_queue,
+            subscriber_id,
+            "some_messages_are_confirmed",
+        );
+
+        match topic_queue.confirmed_some_delivered(subscriber_id, confirmed_messages) {
+            Ok(not_on_delivery) => {
+                log_not_on_delivery(
+                    app,
+                    topic_queue,
+                    &not_on_delivery,
+                    "some_messages_are_confirmed",
+                );
+
+                let confirmed_amount = confirmed_amount.min(on_delivery);
+                app.prometheus.messages_are_confirmed(
+                    topic_id,
+                    queue_id,
+                    confirmed_amount,
+                    on_delivery - confirmed_amount,
+                );
+            }
+            Err(err) => {
+                app.logs.add_fatal_error(
+                    crate::app::logs::SystemProcess::DeliveryOperation,
+                    "some_messages_are_confirmed".to_string(),
+                    format!("{:?}", err),
+                    None,
+                );
+            }
         }
     }
 
@@ -166,3 +256,140 @@ pub async fn some_messages_are_confirmed(
 
     Ok(())
 }
+
+fn get_on_delivery_amount(topic_queue: &TopicQueue, subscriber_id: SubscriberId) -> usize {
+    match topic_queue.subscribers.get_by_id(subscriber_id) {
+        Some(subscriber) => subscriber.get_on_delivery_amount() as usize,
+        None => 0,
+    }
+}
+
+fn confirmation_is_received(
+    app: &AppContext,
+    topic_queue: &TopicQueue,
+    subscriber_id: SubscriberId,
+    confirmed: usize,
+) {
+    let subscriber = match topic_queue.subscribers.get_by_id(subscriber_id) {
+        Some(subscriber) => subscriber,
+        None => return,
+    };
+
+    if let QueueSubscriberDeliveryState::OnDelivery(_) = &subscriber.delivery_state {
+        let latency = DateTimeAsMicroseconds::now()
+            .duration_since(subscriber.metrics.start_delivery_time)
+            .as_positive_or_zero();
+
+        subscriber
+            .session
+            .statistics
+            .confirmation_is_received(confirmed, latency);
+
+        app.prometheus.session_confirmation_is_received(
+            subscriber.session.get_metrics_name().as_str(),
+            confirmed,
+            latency,
+        );
+    }
+}
+
+fn log_if_nothing_is_on_delivery(
+    app: &AppContext,
+    topic_queue: &TopicQueue,
+    subscriber_id: SubscriberId,
+    process_name: &str,
+) {
+    if let Some(subscriber) = topic_queue.subscribers.get_by_id(subscriber_id) {
+        if let QueueSubscriberDeliveryState::OnDelivery(_) = &subscriber.delivery_state {
+            return;
+        }
+
+        app.logs.add_info(
+            Some(topic_queue.topic_id.to_string()),
+            crate::app::logs::SystemProcess::DeliveryOperation,
+            process_name.to_string(),
+            format!(
+                "{}/{}: No messages on delivery at subscriber {}",
+                topic_queue.topic_id, topic_queue.queue_id, subscriber_id
+            ),
+            None,
+        );
+    }
+}
+
+fn log_not_on_delivery(
+    app: &AppContext,
+    topic_queue: &TopicQueue,
+    not_on_delivery: &[MessageId],
+    process_name: &str,
+) {
+    if not_on_delivery.is_empty() {
+        return;
+    }
+
+    app.logs.add_error(
+        Some(topic_queue.topic_id.to_string()),
+        crate::app::logs::SystemProcess::DeliveryOperation,
+        process_name.to_string(),
+        format!(
+            "{}/{}: Confirmed messages are not on delivery",
+            topic_queue.topic_id, topic_queue.queue_id
+        ),
+        Some(format!("{:?}", not_on_delivery)),
+    );
+}
+
+enum DeliveryResult<'s> {
+    All(bool),
+    Partial(&'s QueueWithIntervals),
+    Intermediary(&'s QueueWithIntervals),
+}
+
+fn finish_delivery_spans(
+    app: &AppContext,
+    topic_queue: &mut TopicQueue,
+    subscriber_id: SubscriberId,
+    delivery_result: DeliveryResult,
+) {
+    let subscriber = match topic_queue.subscribers.get_by_id_mut(subscriber_id) {
+        Some(subscriber) => subscriber,
+        None => return,
+    };
+
+    if subscriber.delivery_spans.is_empty() {
+        return;
+    }
+
+    let confirmed_ids = match &delivery_result {
+        DeliveryResult::All(_) => HashSet::new(),
+        DeliveryResult::Partial(confirmed) => get_ids(confirmed),
+        DeliveryResult::Intermediary(confirmed) => get_ids(confirmed),
+    };
+
+    for (message_id, span) in std::mem::take(&mut subscriber.delivery_spans) {
+        let delivered = confirmed_ids.contains(&message_id);
+
+        match delivery_result {
+            DeliveryResult::All(delivered) => app.telemetry.finish_span(span, delivered),
+            DeliveryResult::Partial(_) => app.telemetry.finish_span(span, delivered),
+            DeliveryResult::Intermediary(_) => {
+                if delivered {
+                    app.telemetry.finish_span(span, true);
+                } else {
+                    //Not confirmed yet. The span is finished by the final confirmation
+                    subscriber.delivery_spans.push((message_id, span));
+                }
+            }
+        }
+    }
+}
+
+fn get_ids(src: &QueueWithIntervals) -> HashSet<MessageId> {
+    let mut result = HashSet::new();
+
+    for message_id in src {
+        result.insert(message_id);
+    }
+
+    result
+}
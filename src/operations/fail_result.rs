@@ -13,6 +13,10 @@ pub enum OperationFailResult {
     TonicError(tonic::Status),
     Other(String),
     ShuttingDown,
+    TopicHasSubscribers { topic_id: String, amount: usize },
+    TopicHasUnpersistedMessages { topic_id: String, amount: usize },
+    PersistenceOperationIsNotSupported { operation: String },
+    TopicIsReadOnly { topic_id: String },
     TopicOrQueueValidationError(InvalidTopicName),
 }
 
@@ -15,4 +15,15 @@ pub async fn gc_http_connections(app: &AppContext) {
             crate::operations::sessions::disconnect(app, disconnected_session.as_ref()).await;
         }
     }
+
+    let expired_publishers = app
+        .sessions
+        .remove_and_disconnect_expired_grpc_publishers(inactive_session_timeout)
+        .await;
+
+    if let Some(expired_publishers) = expired_publishers {
+        for expired_publisher in expired_publishers {
+            crate::operations::sessions::disconnect(app, expired_publisher.as_ref()).await;
+        }
+    }
 }
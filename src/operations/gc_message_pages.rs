@@ -3,35 +3,36 @@ use std::collections::HashMap;
 use my_service_bus_shared::sub_page::SubPageId;
 use rust_extensions::lazy::LazyVec;
 
-use crate::app::AppContext;
+use crate::app::{logs::SystemProcess, AppContext};
 use crate::topics::TopicData;
 
-pub fn gc_message_pages(_app: &AppContext, topic_data: &mut TopicData) {
+pub fn gc_message_pages(app: &AppContext, topic_data: &mut TopicData) {
     let active_pages = super::get_active_sub_pages(topic_data);
 
     let sub_pages_to_gc = get_subpages_to_gc(topic_data, &active_pages);
 
     if let Some(sub_pages_to_gc) = sub_pages_to_gc {
         for sub_page_to_gc in sub_pages_to_gc {
-            let (_sub_page, _page) = topic_data.pages.gc_if_possible(sub_page_to_gc);
-
-            #[cfg(test)]
-            {
-                if let Some(sub_page) = _sub_page {
-                    println!(
-                        "SubPage {} is GCed for topic: {}",
-                        sub_page.sub_page_id.value,
-                        topic_data.topic_id.as_str()
-                    );
-                }
+            let (sub_page, page) = topic_data.pages.gc_if_possible(sub_page_to_gc);
+
+            if let Some(sub_page) = sub_page {
+                app.logs.add_info(
+                    Some(topic_data.topic_id.to_string()),
+                    SystemProcess::Timer,
+                    "gc_message_pages".to_string(),
+                    format!("SubPage {} is GCed", sub_page.sub_page_id.value),
+                    None,
+                );
+            }
 
-                if let Some(page) = _page {
-                    println!(
-                        "Page {} is GCed for topic: {}",
-                        page.page_id,
-                        topic_data.topic_id.as_str()
-                    );
-                }
+            if let Some(page) = page {
+                app.logs.add_info(
+                    Some(topic_data.topic_id.to_string()),
+                    SystemProcess::Timer,
+                    "gc_message_pages".to_string(),
+                    format!("Page {} is GCed", page.page_id),
+                    None,
+                );
             }
         }
     }
@@ -1,7 +1,10 @@
 use my_service_bus_shared::queue::TopicQueueType;
 use rust_extensions::date_time::DateTimeAsMicroseconds;
 
-use crate::{app::AppContext, topics::TopicData};
+use crate::{
+    app::{logs::SystemProcess, AppContext},
+    topics::TopicData,
+};
 
 pub fn gc_queues_with_no_subscribers(app: &AppContext, topic_data: &mut TopicData) {
     let now = DateTimeAsMicroseconds::now();
@@ -19,9 +22,20 @@ pub fn gc_queues_with_no_subscribers(app: &AppContext, topic_data: &mut TopicDat
             if now
                 .duration_since(topic_queue.subscribers.last_unsubscribe)
                 .as_positive_or_zero()
-                > app.empty_queue_gc_timeout
+                > app.get_empty_queue_gc_timeout()
             {
-                println!("Detected DeleteOnDisconnect queue {}/{} with 0 subscribers. Last disconnect since {:?}", topic_data.topic_id, topic_queue.queue_id, topic_queue.subscribers.last_unsubscribe);
+                app.logs.add_info(
+                    Some(topic_data.topic_id.to_string()),
+                    SystemProcess::QueueOperation,
+                    "gc_queues_with_no_subscribers".to_string(),
+                    format!(
+                        "Detected DeleteOnDisconnect queue {}/{} with 0 subscribers. Last disconnect since {:?}",
+                        topic_data.topic_id,
+                        topic_queue.queue_id,
+                        topic_queue.subscribers.last_unsubscribe
+                    ),
+                    None,
+                );
 
                 if queues_to_delete.is_none() {
                     queues_to_delete = Some(Vec::new());
@@ -11,9 +11,25 @@ pub async fn init(app: Arc<AppContext>) {
     let mut sw = StopWatch::new();
     sw.start();
 
+    if let Err(err) = app.metrics_history.load().await {
+        app.logs.add_error(
+            None,
+            crate::app::logs::SystemProcess::Init,
+            "load_metrics_history".to_string(),
+            "Can not restore metrics history. Starting with an empty one".to_string(),
+            Some(err),
+        );
+    }
+
     let topics_and_queues = restore_topics_and_queues(app.as_ref()).await;
 
-    println!("Loaded topics {}", topics_and_queues.len());
+    app.logs.add_info(
+        None,
+        crate::app::logs::SystemProcess::Init,
+        "restore_topics_and_queues".to_string(),
+        format!("Loaded topics {}", topics_and_queues.len()),
+        None,
+    );
 
     let topics_count = topics_and_queues.len();
 
@@ -23,6 +39,11 @@ pub async fn init(app: Arc<AppContext>) {
             .restore(topic_and_queues.topic_id, topic_and_queues.message_id)
             .await;
 
+        if topic_and_queues.read_only {
+            let mut topic_data = topic.get_access().await;
+            topic_data.read_only = true;
+        }
+
         for queue in topic_and_queues.queues {
             let queue_with_intervals = QueueWithIntervals::restore(queue.ranges);
 
@@ -32,10 +53,15 @@ pub async fn init(app: Arc<AppContext>) {
                 queue.queue_id.to_string(),
                 queue.queue_type,
                 queue_with_intervals,
+                queue.paused,
             );
         }
     }
 
+    if let Some(topology) = &app.topology {
+        crate::topology::reconcile(&app, topology).await;
+    }
+
     for topic in app.topic_list.get_all().await {
         restore_topic_pages(app.clone(), topic.clone()).await;
     }
@@ -53,8 +79,6 @@ pub async fn init(app: Arc<AppContext>) {
         ),
         None,
     );
-
-    println!("Application is initialized in {:?}", sw.duration());
 }
 
 async fn restore_topic_pages(app: Arc<AppContext>, topic: Arc<Topic>) {
@@ -64,6 +88,7 @@ async fn restore_topic_pages(app: Arc<AppContext>, topic: Arc<Topic>) {
         topic,
         app.messages_pages_repo.clone(),
         Some(app.logs.as_ref()),
+        Some(&app.prometheus),
         page_id,
         sub_page_id,
     )
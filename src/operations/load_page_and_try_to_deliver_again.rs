@@ -18,6 +18,7 @@ pub fn load_page_and_try_to_deliver_again(
             topic.clone(),
             app.messages_pages_repo.clone(),
             Some(app.logs.as_ref()),
+            Some(&app.prometheus),
             page_id,
             sub_page_id,
         )
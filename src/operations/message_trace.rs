@@ -0,0 +1,247 @@
+use std::sync::Arc;
+
+use my_service_bus_shared::{
+    page_id::get_page_id, queue_with_intervals::QueueWithIntervals, sub_page::SubPageId, MessageId,
+};
+use rust_extensions::date_time::DateTimeAsMicroseconds;
+
+use crate::{app::AppContext, queue_subscribers::SubscriberId, sessions::MyServiceBusSession};
+
+use super::OperationFailResult;
+
+pub enum MessageQueueState {
+    Queued,
+    OnDelivery,
+    NotInQueue,
+}
+
+impl MessageQueueState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MessageQueueState::Queued => "Queued",
+            MessageQueueState::OnDelivery => "OnDelivery",
+            MessageQueueState::NotInQueue => "NotInQueue",
+        }
+    }
+}
+
+pub struct MessageOnDeliveryTrace {
+    pub subscriber_id: SubscriberId,
+    pub session: Arc<MyServiceBusSession>,
+}
+
+pub struct MessageQueueTrace {
+    pub queue_id: String,
+    pub state: MessageQueueState,
+    pub delivery_attempts: i32,
+    pub on_delivery: Vec<MessageOnDeliveryTrace>,
+}
+
+pub struct MessageInRamTrace {
+    pub time: DateTimeAsMicroseconds,
+    pub size: usize,
+    pub waiting_to_persist: bool,
+}
+
+pub struct MessageTrace {
+    pub topic_message_id: MessageId,
+    pub in_ram: Option<MessageInRamTrace>,
+    pub queues: Vec<MessageQueueTrace>,
+}
+
+pub async fn trace_message(
+    app: &AppContext,
+    topic_id: &str,
+    message_id: MessageId,
+) -> Result<MessageTrace, OperationFailResult> {
+    let topic = app
+        .topic_list
+        .get(topic_id)
+        .await
+        .ok_or(OperationFailResult::TopicNotFound {
+            topic_id: topic_id.to_string(),
+        })?;
+
+    let topic_data = topic.get_access().await;
+
+    if message_id < 0 || message_id >= topic_data.message_id {
+        return Err(OperationFailResult::Other(format!(
+            "Message {} does not exist in topic {}. Topic message id is {}",
+            message_id, topic_id, topic_data.message_id
+        )));
+    }
+
+    let sub_page_id = SubPageId::from_message_id(message_id);
+
+    let in_ram = topic_data
+        .pages
+        .get_page(get_page_id(message_id))
+        .and_then(|page| page.get_sub_page(&sub_page_id))
+        .and_then(|sub_page| {
+            let message = sub_page.sub_page.get_message(message_id)?;
+
+            Some(MessageInRamTrace {
+                time: message.time,
+                size: message.content.len(),
+                waiting_to_persist: contains(&sub_page.messages_to_persist, message_id)
+                    || sub_page
+                        .get_on_persistence()
+                        .any(|ids| contains(ids, message_id)),
+            })
+        });
+
+    let mut queues = Vec::new();
+
+    for topic_queue in topic_data.queues.get_all() {
+        let mut on_delivery = Vec::new();
+
+        if let Some(subscribers) = topic_queue.subscribers.get_all() {
+            for subscriber in subscribers {
+                if let Some(messages_on_delivery) = subscriber.get_messages_on_delivery() {
+                    if contains(&messages_on_delivery, message_id) {
+                        on_delivery.push(MessageOnDeliveryTrace {
+                            subscriber_id: subscriber.id,
+                            session: subscriber.session.clone(),
+                        });
+                    }
+                }
+            }
+        }
+
+        let state = if !on_delivery.is_empty() {
+            MessageQueueState::OnDelivery
+        } else if contains(&topic_queue.queue, message_id) {
+            MessageQueueState::Queued
+        } else {
+            MessageQueueState::NotInQueue
+        };
+
+        queues.push(MessageQueueTrace {
+            queue_id: topic_queue.queue_id.to_string(),
+            state,
+            delivery_attempts: topic_queue.delivery_attempts.get(message_id),
+            on_delivery,
+        });
+    }
+
+    Ok(MessageTrace {
+        topic_message_id: topic_data.message_id,
+        in_ram,
+        queues,
+    })
+}
+
+fn contains(ids: &QueueWithIntervals, message_id: MessageId) -> bool {
+    ids.intervals
+        .iter()
+        .any(|interval| interval.from_id <= message_id && message_id <= interval.to_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use my_service_bus_shared::queue::TopicQueueType;
+    use my_service_bus_tcp_shared::MessageToPublishTcpContract;
+
+    use crate::{
+        sessions::{SessionId, TestConnectionData},
+        settings::{CliArgs, SettingsModel},
+    };
+
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_trace_message_on_delivery_and_queued() {
+        const TOPIC_NAME: &str = "test-topic";
+        const QUEUE_NAME: &str = "test-queue";
+        const IDLE_QUEUE_NAME: &str = "idle-queue";
+        const SESSION_ID: SessionId = 13;
+
+        let settings = SettingsModel::create_test_settings(16);
+
+        let app = Arc::new(AppContext::new(&settings, CliArgs::default()).await);
+
+        let session = app
+            .sessions
+            .add_test(TestConnectionData::new(SESSION_ID, "127.0.0.1"))
+            .await;
+
+        crate::operations::publisher::create_topic_if_not_exists(
+            &app,
+            Some(session.id),
+            TOPIC_NAME,
+        )
+        .await
+        .unwrap();
+
+        crate::operations::queues::create_queue_if_not_exists(
+            &app,
+            TOPIC_NAME,
+            IDLE_QUEUE_NAME,
+            TopicQueueType::Permanent,
+            None,
+        )
+        .await
+        .unwrap();
+
+        crate::operations::subscriber::subscribe_to_queue(
+            &app,
+            TOPIC_NAME.to_string(),
+            QUEUE_NAME.to_string(),
+            TopicQueueType::Permanent,
+            &session,
+        )
+        .await
+        .unwrap();
+
+        let messages = vec![MessageToPublishTcpContract {
+            headers: None,
+            content: vec![0u8, 1u8, 2u8],
+        }];
+
+        crate::operations::publisher::publish(&app, TOPIC_NAME, messages, false, &session)
+            .await
+            .unwrap();
+
+        let result = trace_message(app.as_ref(), TOPIC_NAME, 0).await.unwrap();
+
+        let in_ram = result.in_ram.unwrap();
+        assert_eq!(3, in_ram.size);
+        assert!(in_ram.waiting_to_persist);
+
+        let queue = result
+            .queues
+            .iter()
+            .find(|itm| itm.queue_id == QUEUE_NAME)
+            .unwrap();
+        assert_eq!("OnDelivery", queue.state.as_str());
+        assert_eq!(SESSION_ID, queue.on_delivery[0].session.id);
+
+        let idle_queue = result
+            .queues
+            .iter()
+            .find(|itm| itm.queue_id == IDLE_QUEUE_NAME)
+            .unwrap();
+        assert_eq!("Queued", idle_queue.state.as_str());
+
+        // The message is still waiting while it is sent to persistence and is not confirmed yet
+        let topic = app.topic_list.get(TOPIC_NAME).await.unwrap();
+        let (sub_page_id, messages_to_persist) =
+            crate::operations::get_next_messages_to_persist(topic.as_ref())
+                .await
+                .unwrap();
+
+        let result = trace_message(app.as_ref(), TOPIC_NAME, 0).await.unwrap();
+        assert!(result.in_ram.unwrap().waiting_to_persist);
+
+        topic.get_access().await.pages.commit_persisted_messages(
+            sub_page_id,
+            &messages_to_persist,
+            true,
+        );
+
+        let result = trace_message(app.as_ref(), TOPIC_NAME, 0).await.unwrap();
+        assert!(!result.in_ram.unwrap().waiting_to_persist);
+
+        assert!(trace_message(app.as_ref(), TOPIC_NAME, 1).await.is_err());
+    }
+}
@@ -0,0 +1,277 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use my_service_bus_shared::{
+    page_id::get_page_id, sub_page::SubPageId, MessageId, MySbMessageContent,
+};
+use rust_extensions::StopWatch;
+
+use crate::{app::AppContext, topics::Topic};
+
+use super::OperationFailResult;
+
+pub async fn get_topic_messages(
+    app: &AppContext,
+    topic_id: &str,
+    from_message_id: Option<MessageId>,
+    max_amount: usize,
+) -> Result<Vec<MySbMessageContent>, OperationFailResult> {
+    let topic = get_topic(app, topic_id).await?;
+
+    let topic_message_id = topic.get_message_id().await;
+
+    let from_message_id = match from_message_id {
+        Some(from_message_id) => from_message_id,
+        None => topic_message_id - max_amount as MessageId,
+    };
+
+    let ids: Vec<MessageId> = (from_message_id.max(0)..topic_message_id)
+        .take(max_amount)
+        .collect();
+
+    get_messages(app, &topic, ids).await
+}
+
+pub async fn peek_queue_messages(
+    app: &AppContext,
+    topic_id: &str,
+    queue_id: &str,
+    max_amount: usize,
+) -> Result<Vec<MySbMessageContent>, OperationFailResult> {
+    let topic = get_topic(app, topic_id).await?;
+
+    let ids = {
+        let topic_data = topic.get_access().await;
+
+        let topic_queue =
+            topic_data
+                .queues
+                .get(queue_id)
+                .ok_or(OperationFailResult::QueueNotFound {
+                    queue_id: queue_id.to_string(),
+                })?;
+
+        let mut ids = Vec::new();
+
+        for interval in &topic_queue.queue.intervals {
+            for message_id in interval.from_id..=interval.to_id {
+                if ids.len() >= max_amount {
+                    break;
+                }
+
+                ids.push(message_id);
+            }
+        }
+
+        ids
+    };
+
+    get_messages(app, &topic, ids).await
+}
+
+async fn get_topic(app: &AppContext, topic_id: &str) -> Result<Arc<Topic>, OperationFailResult> {
+    app.topic_list
+        .get(topic_id)
+        .await
+        .ok_or(OperationFailResult::TopicNotFound {
+            topic_id: topic_id.to_string(),
+        })
+}
+
+async fn get_messages(
+    app: &AppContext,
+    topic: &Topic,
+    ids: Vec<MessageId>,
+) -> Result<Vec<MySbMessageContent>, OperationFailResult> {
+    let mut result = BTreeMap::new();
+    let mut not_cached: BTreeMap<MessageId, Vec<MessageId>> = BTreeMap::new();
+
+    {
+        let topic_data = topic.get_access().await;
+
+        for message_id in ids {
+            let sub_page_id = SubPageId::from_message_id(message_id);
+
+            let message = topic_data
+                .pages
+                .get_page(get_page_id(message_id))
+                .and_then(|page| page.get_sub_page(&sub_page_id))
+                .and_then(|sub_page| sub_page.sub_page.get_message(message_id));
+
+            match message {
+                Some(message) => {
+                    result.insert(
+                        message_id,
+                        MySbMessageContent {
+                            id: message.id,
+                            content: message.content.clone(),
+                            time: message.time,
+                            headers: message.headers.clone(),
+                        },
+                    );
+                }
+                None => {
+                    not_cached
+                        .entry(sub_page_id.get_first_message_id())
+                        .or_insert_with(Vec::new)
+                        .push(message_id);
+                }
+            }
+        }
+    }
+
+    for ids in not_cached.into_values() {
+        let from_message_id = ids[0];
+        let to_message_id = ids[ids.len() - 1];
+
+        let mut sw = StopWatch::new();
+        sw.start();
+
+        let loaded = app
+            .messages_pages_repo
+            .load_page(
+                topic.topic_id.as_str(),
+                get_page_id(from_message_id),
+                from_message_id,
+                to_message_id,
+            )
+            .await;
+
+        sw.pause();
+        app.prometheus
+            .observe_persistence_load(topic.topic_id.as_str(), sw.duration());
+
+        let loaded =
+            loaded.map_err(|err| OperationFailResult::PersistenceError(format!("{:?}", err)))?;
+
+        if let Some(loaded) = loaded {
+            for (message_id, message) in loaded {
+                if message_id >= from_message_id && message_id <= to_message_id {
+                    result.insert(message_id, message);
+                }
+            }
+        }
+    }
+
+    Ok(result.into_values().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use my_service_bus_shared::{protobuf_models::MessageProtobufModel, queue::TopicQueueType};
+    use my_service_bus_tcp_shared::MessageToPublishTcpContract;
+    use rust_extensions::date_time::DateTimeAsMicroseconds;
+
+    use crate::{
+        sessions::{SessionId, TestConnectionData},
+        settings::{CliArgs, SettingsModel},
+    };
+
+    use super::*;
+
+    const TOPIC_NAME: &str = "test-topic";
+    const QUEUE_NAME: &str = "test-queue";
+    const SESSION_ID: SessionId = 13;
+
+    fn get_ids(messages: &[MySbMessageContent]) -> Vec<MessageId> {
+        messages.iter().map(|itm| itm.id).collect()
+    }
+
+    #[tokio::test]
+    async fn test_topic_and_queue_messages_are_paged() {
+        let settings = SettingsModel::create_test_settings(16);
+
+        let app = Arc::new(AppContext::new(&settings, CliArgs::default()).await);
+
+        let session = app
+            .sessions
+            .add_test(TestConnectionData::new(SESSION_ID, "127.0.0.1"))
+            .await;
+
+        crate::operations::publisher::create_topic_if_not_exists(
+            &app,
+            Some(session.id),
+            TOPIC_NAME,
+        )
+        .await
+        .unwrap();
+
+        crate::operations::queues::create_queue_if_not_exists(
+            &app,
+            TOPIC_NAME,
+            QUEUE_NAME,
+            TopicQueueType::Permanent,
+            None,
+        )
+        .await
+        .unwrap();
+
+        let messages = (0..5u8)
+            .map(|itm| MessageToPublishTcpContract {
+                headers: None,
+                content: vec![itm],
+            })
+            .collect();
+
+        crate::operations::publisher::publish(&app, TOPIC_NAME, messages, false, &session)
+            .await
+            .unwrap();
+
+        // The last page is returned when the first message id is not specified
+        let result = get_topic_messages(app.as_ref(), TOPIC_NAME, None, 2)
+            .await
+            .unwrap();
+        assert_eq!(vec![3, 4], get_ids(&result));
+        assert_eq!(vec![4u8], result[1].content);
+
+        let result = get_topic_messages(app.as_ref(), TOPIC_NAME, Some(1), 2)
+            .await
+            .unwrap();
+        assert_eq!(vec![1, 2], get_ids(&result));
+
+        let result = get_topic_messages(app.as_ref(), TOPIC_NAME, Some(4), 10)
+            .await
+            .unwrap();
+        assert_eq!(vec![4], get_ids(&result));
+
+        let result = peek_queue_messages(app.as_ref(), TOPIC_NAME, QUEUE_NAME, 3)
+            .await
+            .unwrap();
+        assert_eq!(vec![0, 1, 2], get_ids(&result));
+
+        let result = peek_queue_messages(app.as_ref(), TOPIC_NAME, "unknown-queue", 3).await;
+        assert!(matches!(
+            result,
+            Err(OperationFailResult::QueueNotFound { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_messages_which_are_not_cached_are_loaded_from_persistence() {
+        let settings = SettingsModel::create_test_settings(16);
+
+        let app = Arc::new(AppContext::new(&settings, CliArgs::default()).await);
+
+        app.topic_list.restore(TOPIC_NAME.to_string(), 3).await;
+
+        let messages_to_persist = (1..3)
+            .map(|message_id| MessageProtobufModel {
+                headers: vec![],
+                data: vec![message_id as u8],
+                message_id,
+                created: DateTimeAsMicroseconds::now().unix_microseconds,
+            })
+            .collect();
+
+        app.messages_pages_repo
+            .save_messages(TOPIC_NAME, messages_to_persist)
+            .await
+            .unwrap();
+
+        let result = get_topic_messages(app.as_ref(), TOPIC_NAME, Some(0), 10)
+            .await
+            .unwrap();
+
+        assert_eq!(vec![1, 2], get_ids(&result));
+        assert_eq!(vec![2u8], result[1].content);
+    }
+}
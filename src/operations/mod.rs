@@ -9,14 +9,19 @@ pub mod initialization;
 mod load_page_and_try_to_deliver_again;
 pub mod page_loader;
 mod persist_topics_and_queues;
+mod reload_settings;
 mod save_messages_for_topic;
 mod send_package;
 
+pub mod alerts;
 pub mod delivery_confirmation;
+pub mod message_trace;
+pub mod messages_browser;
 pub mod publisher;
 pub mod queues;
 pub mod sessions;
 pub mod subscriber;
+pub mod topics;
 
 pub use fail_result::*;
 pub use gc_http_connections::gc_http_connections;
@@ -26,5 +31,6 @@ pub use get_active_sub_pages::get_active_sub_pages;
 pub use get_next_messages_to_persist::get_next_messages_to_persist;
 pub use load_page_and_try_to_deliver_again::load_page_and_try_to_deliver_again;
 pub use persist_topics_and_queues::persist_topics_and_queues;
+pub use reload_settings::reload_settings;
 pub use save_messages_for_topic::save_messages_for_topic;
 pub use send_package::send_package;
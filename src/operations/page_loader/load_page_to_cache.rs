@@ -3,12 +3,17 @@ use std::sync::Arc;
 use my_service_bus_shared::{page_id::PageId, sub_page::SubPageId};
 use rust_extensions::date_time::DateTimeAsMicroseconds;
 
-use crate::{app::logs::Logs, persistence::MessagesPagesRepo, topics::Topic};
+use crate::{
+    app::{logs::Logs, prometheus_metrics::PrometheusMetrics},
+    persistence::MessagesPagesRepo,
+    topics::Topic,
+};
 
 pub async fn load_page_to_cache(
     topic: Arc<Topic>,
     messages_pages_repo: Arc<MessagesPagesRepo>,
     logs: Option<&Logs>,
+    prometheus: Option<&PrometheusMetrics>,
     page_id: PageId,
     sub_page_id: SubPageId,
 ) {
@@ -17,15 +22,24 @@ pub async fn load_page_to_cache(
     let from_message_id = sub_page_id.get_first_message_id();
     let to_message_id = sub_page_id.get_first_message_id_of_next_sub_page() - 1;
 
-    println!(
-        "Loading messages {}-{} for page {} with subpage {} for topic:{}",
-        from_message_id, to_message_id, page_id, sub_page_id.value, topic.topic_id
-    );
+    if let Some(logs) = logs {
+        logs.add_info(
+            Some(topic.topic_id.to_string()),
+            crate::app::logs::SystemProcess::Init,
+            "load_page_to_cache".to_string(),
+            format!(
+                "Loading messages {}-{} for page {} with subpage {}",
+                from_message_id, to_message_id, page_id, sub_page_id.value
+            ),
+            None,
+        );
+    }
 
     let sub_page = super::operations::load_page(
         topic.as_ref(),
         &messages_pages_repo,
         logs,
+        prometheus,
         page_id,
         sub_page_id,
     )
@@ -6,17 +6,31 @@ use my_service_bus_shared::{
     MessageId, MySbMessageContent,
 };
 
-use crate::{app::logs::Logs, persistence::MessagesPagesRepo, topics::Topic};
+use rust_extensions::StopWatch;
+
+use crate::{
+    app::{logs::Logs, prometheus_metrics::PrometheusMetrics},
+    persistence::MessagesPagesRepo,
+    topics::Topic,
+};
 
 pub async fn load_page(
     topic: &Topic,
     messages_pages_repo: &Arc<MessagesPagesRepo>,
     logs: Option<&Logs>,
+    prometheus: Option<&PrometheusMetrics>,
     page_id: PageId,
     sub_page_id: SubPageId,
 ) -> SubPage {
-    let messages =
-        load_page_from_repo(topic, messages_pages_repo, logs, page_id, sub_page_id).await;
+    let messages = load_page_from_repo(
+        topic,
+        messages_pages_repo,
+        logs,
+        prometheus,
+        page_id,
+        sub_page_id,
+    )
+    .await;
 
     match messages {
         Some(messages) => SubPage::restored(sub_page_id, messages),
@@ -29,11 +43,15 @@ async fn load_page_from_repo(
     topic: &Topic,
     messages_pages_repo: &Arc<MessagesPagesRepo>,
     logs: Option<&Logs>,
+    prometheus: Option<&PrometheusMetrics>,
     page_id: PageId,
     sub_page_id: SubPageId,
 ) -> Option<BTreeMap<MessageId, MySbMessageContent>> {
     let mut attempt_no = 0;
     loop {
+        let mut sw = StopWatch::new();
+        sw.start();
+
         let result = messages_pages_repo
             .load_page(
                 topic.topic_id.as_str(),
@@ -43,7 +61,27 @@ async fn load_page_from_repo(
             )
             .await;
 
+        sw.pause();
+
+        if let Some(prometheus) = prometheus {
+            prometheus.observe_persistence_load(topic.topic_id.as_str(), sw.duration());
+        }
+
         if let Ok(result) = result {
+            if let Some(logs) = logs {
+                logs.add_info(
+                    Some(topic.topic_id.to_string()),
+                    crate::app::logs::SystemProcess::Init,
+                    "get_page".to_string(),
+                    format!(
+                        "Read page {} with messages amount: {}",
+                        page_id,
+                        result.as_ref().map(|messages| messages.len()).unwrap_or(0)
+                    ),
+                    None,
+                );
+            }
+
             return result;
         }
 
@@ -17,6 +17,8 @@ pub async fn persist_topics_and_queues(app: &Arc<AppContext>) {
 
     let result = app.topics_and_queues_repo.save(topics_snapshots).await;
 
+    let mut persistence_is_ok = result.is_ok();
+
     if let Err(err) = result {
         app.logs.add_error(
             None,
@@ -28,6 +30,10 @@ pub async fn persist_topics_and_queues(app: &Arc<AppContext>) {
     }
 
     for topic in &topics {
-        crate::operations::save_messages_for_topic(&app, topic).await;
+        if !crate::operations::save_messages_for_topic(&app, topic).await {
+            persistence_is_ok = false;
+        }
     }
+
+    app.alerts.set_persistence_state(persistence_is_ok);
 }
@@ -1,8 +1,14 @@
 use std::sync::Arc;
 
+use my_service_bus_shared::MessageId;
 use my_service_bus_tcp_shared::MessageToPublishTcpContract;
 
-use crate::{app::AppContext, sessions::SessionId, topics::Topic};
+use crate::{
+    app::AppContext,
+    sessions::{MyServiceBusSession, SessionId},
+    telemetry::{SpanKind, TelemetrySpan, TraceContext},
+    topics::Topic,
+};
 
 use super::OperationFailResult;
 
@@ -26,6 +32,34 @@ pub async fn create_topic_if_not_exists(
 }
 
 pub async fn publish(
+    app: &Arc<AppContext>,
+    topic_id: &str,
+    messages: Vec<MessageToPublishTcpContract>,
+    persist_immediately: bool,
+    session: &MyServiceBusSession,
+) -> Result<(), OperationFailResult> {
+    let messages_count = messages.len();
+
+    let result = publish_to_topic(app, topic_id, messages, persist_immediately, session.id).await;
+
+    let session_name = session.get_metrics_name();
+
+    match &result {
+        Ok(_) => {
+            session.statistics.messages_are_published(messages_count);
+            app.prometheus
+                .session_messages_are_published(session_name.as_str(), messages_count);
+        }
+        Err(_) => {
+            session.statistics.reject_is_sent();
+            app.prometheus.session_reject_is_sent(session_name.as_str());
+        }
+    }
+
+    result
+}
+
+async fn publish_to_topic(
     app: &Arc<AppContext>,
     topic_id: &str,
     messages: Vec<MessageToPublishTcpContract>,
@@ -39,7 +73,7 @@ pub async fn publish(
     let topic = app.topic_list.get(topic_id).await;
 
     if topic.is_none() {
-        if app.auto_create_topic_on_publish {
+        if app.auto_create_topic_on_publish() {
             app.topic_list.add_if_not_exists(topic_id).await?;
         } else {
             return Err(OperationFailResult::TopicNotFound {
@@ -52,13 +86,32 @@ pub async fn publish(
 
     let mut topic_data = topic.get_access().await;
 
+    if topic_data.read_only {
+        return Err(OperationFailResult::TopicIsReadOnly {
+            topic_id: topic_id.to_string(),
+        });
+    }
+
     let messages_count = messages.len();
 
+    let publish_spans = start_publish_spans(app, topic_id, topic_data.message_id, &messages);
+
     topic_data.publish_messages(session_id, messages);
 
+    for span in publish_spans {
+        app.telemetry.finish_span(span, true);
+    }
+
     topic_data.metrics.update_topic_metrics(messages_count);
 
-    if persist_immediately {
+    app.prometheus
+        .messages_are_published(topic_id, messages_count);
+
+    if persist_immediately
+        || topic
+            .persist_immediately
+            .load(std::sync::atomic::Ordering::Relaxed)
+    {
         let prev = topic
             .immediatelly_persist_is_charged
             .swap(true, std::sync::atomic::Ordering::SeqCst);
@@ -71,3 +124,112 @@ pub async fn publish(
     super::delivery::start_new(&app, &topic, &mut topic_data);
     Ok(())
 }
+
+fn start_publish_spans(
+    app: &AppContext,
+    topic_id: &str,
+    first_message_id: MessageId,
+    messages: &[MessageToPublishTcpContract],
+) -> Vec<TelemetrySpan> {
+    let mut result = Vec::new();
+
+    if !app.telemetry.is_enabled() {
+        return result;
+    }
+
+    //Headers are kept as the client sent them. Persistence and delivery spans are siblings of the publish span
+    for (index, message) in messages.iter().enumerate() {
+        let span = TraceContext::from_headers(&message.headers).and_then(|parent| {
+            app.telemetry
+                .start_span("publish", SpanKind::Producer, &parent)
+        });
+
+        if let Some(mut span) = span {
+            span.add_attribute("messaging.destination.name", topic_id.to_string());
+            span.add_attribute(
+                "messaging.message.id",
+                (first_message_id + index as MessageId).to_string(),
+            );
+
+            result.push(span);
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use my_service_bus_shared::{page_id::get_page_id, sub_page::SubPageId};
+
+    use crate::{
+        sessions::TestConnectionData,
+        settings::{CliArgs, SettingsModel},
+        telemetry::TRACE_PARENT_HEADER,
+    };
+
+    use super::*;
+
+    const TOPIC_NAME: &str = "test-topic";
+    const TRACE_PARENT: &str = "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01";
+
+    #[tokio::test]
+    async fn test_publish_span_is_a_child_of_the_client_span() {
+        let mut settings = SettingsModel::create_test_settings(16);
+        settings.open_telemetry_endpoint = Some("http://127.0.0.1:4318".to_string());
+
+        let app = Arc::new(AppContext::new(&settings, CliArgs::default()).await);
+
+        let session = app
+            .sessions
+            .add_test(TestConnectionData::new(13, "127.0.0.1"))
+            .await;
+
+        create_topic_if_not_exists(&app, Some(session.id), TOPIC_NAME)
+            .await
+            .unwrap();
+
+        let mut headers = HashMap::new();
+        headers.insert(TRACE_PARENT_HEADER.to_string(), TRACE_PARENT.to_string());
+
+        let messages = vec![MessageToPublishTcpContract {
+            headers: Some(headers),
+            content: vec![0u8],
+        }];
+
+        publish(&app, TOPIC_NAME, messages, false, &session)
+            .await
+            .unwrap();
+
+        let parent = TraceContext::parse(TRACE_PARENT).unwrap();
+
+        let spans = app.telemetry.get_spans_to_export();
+        let span = spans.iter().find(|span| span.name == "publish").unwrap();
+
+        assert_eq!(parent.trace_id, span.context.trace_id);
+        assert_eq!(parent.span_id, span.parent_span_id);
+        assert_ne!(parent.span_id, span.context.span_id);
+
+        // The message keeps the traceparent header the client has sent
+        let topic = app.topic_list.get(TOPIC_NAME).await.unwrap();
+        let topic_data = topic.get_access().await;
+
+        let message = topic_data
+            .pages
+            .get_page(get_page_id(0))
+            .and_then(|page| page.get_sub_page(&SubPageId::from_message_id(0)))
+            .and_then(|sub_page| sub_page.sub_page.get_message(0))
+            .unwrap();
+
+        assert_eq!(
+            Some(TRACE_PARENT),
+            message
+                .headers
+                .as_ref()
+                .and_then(|headers| headers.get(TRACE_PARENT_HEADER))
+                .map(|value| value.as_str())
+        );
+    }
+}
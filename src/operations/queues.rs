@@ -1,15 +1,124 @@
-use my_service_bus_shared::MessageId;
+use std::sync::Arc;
 
-use crate::app::AppContext;
+use my_service_bus_shared::{
+    queue::TopicQueueType, queue_with_intervals::QueueWithIntervals, MessageId,
+};
+
+use crate::app::{logs::SystemProcess, AppContext};
 
 use super::OperationFailResult;
 
+// Returns true if queue is created. A created queue starts from from_message_id if it is set
+pub async fn create_queue_if_not_exists(
+    app: &AppContext,
+    topic_id: &str,
+    queue_id: &str,
+    queue_type: TopicQueueType,
+    from_message_id: Option<MessageId>,
+) -> Result<bool, OperationFailResult> {
+    let topic = app
+        .topic_list
+        .get(topic_id)
+        .await
+        .ok_or(OperationFailResult::TopicNotFound {
+            topic_id: topic_id.to_string(),
+        })?;
+
+    let mut topic_data = topic.get_access().await;
+
+    let topic_message_id = topic_data.message_id;
+
+    let created = topic_data.queues.get(queue_id).is_none();
+
+    let topic_queue = topic_data.queues.add_queue_if_not_exists(
+        topic_id.to_string(),
+        queue_id.to_string(),
+        queue_type,
+    );
+
+    if created {
+        if let Some(from_message_id) = from_message_id {
+            if from_message_id < topic_message_id {
+                topic_queue.set_message_id(from_message_id, topic_message_id - 1);
+            }
+        }
+    }
+
+    Ok(created)
+}
+
+pub fn parse_queue_type(src: &str) -> Option<TopicQueueType> {
+    match src {
+        "Permanent" => Some(TopicQueueType::Permanent),
+        "PermanentWithSingleConnection" => Some(TopicQueueType::PermanentWithSingleConnection),
+        "DeleteOnDisconnect" => Some(TopicQueueType::DeleteOnDisconnect),
+        _ => None,
+    }
+}
+
+pub fn queue_type_as_str(src: TopicQueueType) -> &'static str {
+    match src {
+        TopicQueueType::Permanent => "Permanent",
+        TopicQueueType::PermanentWithSingleConnection => "PermanentWithSingleConnection",
+        TopicQueueType::DeleteOnDisconnect => "DeleteOnDisconnect",
+    }
+}
+
+pub async fn declare_queue(
+    app: &Arc<AppContext>,
+    topic_id: &str,
+    queue_id: &str,
+    queue_type: TopicQueueType,
+    from_message_id: Option<MessageId>,
+) -> Result<bool, OperationFailResult> {
+    if let TopicQueueType::DeleteOnDisconnect = queue_type {
+        return Err(OperationFailResult::Other(format!(
+            "Queue {}/{} can not be declared as DeleteOnDisconnect",
+            topic_id, queue_id
+        )));
+    }
+
+    super::publisher::create_topic_if_not_exists(app, None, topic_id).await?;
+
+    let created = create_queue_if_not_exists(
+        app.as_ref(),
+        topic_id,
+        queue_id,
+        queue_type,
+        from_message_id,
+    )
+    .await?;
+
+    if created {
+        app.logs.add_info(
+            Some(topic_id.to_string()),
+            SystemProcess::QueueOperation,
+            "declare_queue".to_string(),
+            format!(
+                "Queue {} is declared as {}",
+                queue_id,
+                queue_type_as_str(queue_type)
+            ),
+            None,
+        );
+
+        super::persist_topics_and_queues(app).await;
+    }
+
+    Ok(created)
+}
+
+pub struct QueueRangesChange {
+    pub before: String,
+    pub after: String,
+}
+
 pub async fn set_message_id(
     app: &AppContext,
     topic_id: &str,
     queue_id: &str,
     message_id: MessageId,
-) -> Result<(), OperationFailResult> {
+) -> Result<QueueRangesChange, OperationFailResult> {
     let topic = app
         .topic_list
         .get(topic_id)
@@ -30,16 +139,259 @@ pub async fn set_message_id(
                 queue_id: queue_id.to_string(),
             })?;
 
+    let before = format_intervals(&topic_queue.queue);
     topic_queue.set_message_id(message_id, topic_message_id);
+    let after = format_intervals(&topic_queue.queue);
+
+    Ok(QueueRangesChange { before, after })
+}
+
+pub async fn requeue_messages(
+    app: &Arc<AppContext>,
+    topic_id: &str,
+    queue_id: &str,
+    ids: QueueWithIntervals,
+) -> Result<QueueRangesChange, OperationFailResult> {
+    let topic = app
+        .topic_list
+        .get(topic_id)
+        .await
+        .ok_or(OperationFailResult::TopicNotFound {
+            topic_id: topic_id.to_string(),
+        })?;
+
+    let mut topic_data = topic.get_access().await;
+
+    let topic_message_id = topic_data.message_id;
+
+    if let Some(max_id) = ids.intervals.last().map(|interval| interval.to_id) {
+        if max_id >= topic_message_id {
+            return Err(OperationFailResult::Other(format!(
+                "Message {} does not exist yet in topic {}. Topic message id is {}",
+                max_id, topic_id, topic_message_id
+            )));
+        }
+    }
+
+    let topic_queue =
+        topic_data
+            .queues
+            .get_mut(queue_id)
+            .ok_or(OperationFailResult::QueueNotFound {
+                queue_id: queue_id.to_string(),
+            })?;
+
+    let before = format_intervals(&topic_queue.queue);
+    let size_before = topic_queue.get_queue_size();
+    let requeued = topic_queue.requeue_messages(&ids);
+    let size_after = topic_queue.get_queue_size();
+    let after = format_intervals(&topic_queue.queue);
+
+    app.logs.add_info(
+        Some(topic_id.to_string()),
+        SystemProcess::QueueOperation,
+        "requeue_messages".to_string(),
+        format!(
+            "Queue {} requeued messages {} of requested {}. Queue size {} -> {}",
+            queue_id,
+            format_intervals(&requeued),
+            format_intervals(&ids),
+            size_before,
+            size_after
+        ),
+        None,
+    );
+
+    super::delivery::start_new(app, &topic, &mut topic_data);
+
+    drop(topic_data);
+
+    super::persist_topics_and_queues(app).await;
+
+    Ok(QueueRangesChange { before, after })
+}
+
+pub async fn skip_messages(
+    app: &Arc<AppContext>,
+    topic_id: &str,
+    queue_id: &str,
+    ids: QueueWithIntervals,
+) -> Result<QueueRangesChange, OperationFailResult> {
+    let topic = app
+        .topic_list
+        .get(topic_id)
+        .await
+        .ok_or(OperationFailResult::TopicNotFound {
+            topic_id: topic_id.to_string(),
+        })?;
+
+    let mut topic_data = topic.get_access().await;
+
+    let topic_queue =
+        topic_data
+            .queues
+            .get_mut(queue_id)
+            .ok_or(OperationFailResult::QueueNotFound {
+                queue_id: queue_id.to_string(),
+            })?;
+
+    let before = format_intervals(&topic_queue.queue);
+    let size_before = topic_queue.get_queue_size();
+    topic_queue.skip_messages(&ids);
+    let size_after = topic_queue.get_queue_size();
+    let after = format_intervals(&topic_queue.queue);
+
+    app.logs.add_info(
+        Some(topic_id.to_string()),
+        SystemProcess::QueueOperation,
+        "skip_messages".to_string(),
+        format!(
+            "Queue {} skipped messages {}. Queue size {} -> {}",
+            queue_id,
+            format_intervals(&ids),
+            size_before,
+            size_after
+        ),
+        None,
+    );
+
+    drop(topic_data);
+
+    super::persist_topics_and_queues(app).await;
+
+    Ok(QueueRangesChange { before, after })
+}
+
+pub async fn purge_queue(
+    app: &Arc<AppContext>,
+    topic_id: &str,
+    queue_id: &str,
+) -> Result<usize, OperationFailResult> {
+    let topic = app
+        .topic_list
+        .get(topic_id)
+        .await
+        .ok_or(OperationFailResult::TopicNotFound {
+            topic_id: topic_id.to_string(),
+        })?;
+
+    let mut topic_data = topic.get_access().await;
+
+    let topic_queue =
+        topic_data
+            .queues
+            .get_mut(queue_id)
+            .ok_or(OperationFailResult::QueueNotFound {
+                queue_id: queue_id.to_string(),
+            })?;
+
+    let purged_amount = topic_queue.purge();
+
+    //Purged messages are not going to be redelivered. Their spans are finished as not delivered
+    for subscriber in topic_queue.subscribers.get_all_mut() {
+        for (_, span) in std::mem::take(&mut subscriber.delivery_spans) {
+            app.telemetry.finish_span(span, false);
+        }
+    }
+
+    app.prometheus
+        .queue_is_purged(topic_id, queue_id, purged_amount);
+
+    app.logs.add_info(
+        Some(topic_id.to_string()),
+        SystemProcess::QueueOperation,
+        "purge_queue".to_string(),
+        format!(
+            "Queue {} is purged. Dropped {} messages",
+            queue_id, purged_amount
+        ),
+        None,
+    );
+
+    super::delivery::start_new(app, &topic, &mut topic_data);
+
+    drop(topic_data);
+
+    super::persist_topics_and_queues(app).await;
+
+    Ok(purged_amount)
+}
+
+fn format_intervals(ids: &QueueWithIntervals) -> String {
+    let mut result = String::new();
 
-    Ok(())
+    for interval in &ids.intervals {
+        if result.len() > 0 {
+            result.push(',');
+        }
+
+        if interval.from_id == interval.to_id {
+            result.push_str(interval.from_id.to_string().as_str());
+        } else {
+            result.push_str(format!("{}-{}", interval.from_id, interval.to_id).as_str());
+        }
+    }
+
+    result
+}
+
+///Returns the previous paused state of the queue
+pub async fn set_queue_paused(
+    app: &Arc<AppContext>,
+    topic_id: &str,
+    queue_id: &str,
+    paused: bool,
+) -> Result<bool, OperationFailResult> {
+    let topic = app
+        .topic_list
+        .get(topic_id)
+        .await
+        .ok_or(OperationFailResult::TopicNotFound {
+            topic_id: topic_id.to_string(),
+        })?;
+
+    let prev = {
+        let mut topic_data = topic.get_access().await;
+
+        let prev = topic_data.queues.set_paused(queue_id, paused).ok_or(
+            OperationFailResult::QueueNotFound {
+                queue_id: queue_id.to_string(),
+            },
+        )?;
+
+        if prev == paused {
+            return Ok(prev);
+        }
+
+        app.logs.add_info(
+            Some(topic_id.to_string()),
+            SystemProcess::QueueOperation,
+            "set_queue_paused".to_string(),
+            if paused {
+                format!("Queue {} is paused", queue_id)
+            } else {
+                format!("Queue {} is resumed", queue_id)
+            },
+            None,
+        );
+
+        if !paused {
+            super::delivery::start_new(app, &topic, &mut topic_data);
+        }
+
+        prev
+    };
+
+    super::persist_topics_and_queues(app).await;
+
+    Ok(prev)
 }
 
 pub async fn delete_queue(
     app: &AppContext,
     topic_id: &str,
     queue_id: &str,
-) -> Result<(), OperationFailResult> {
+) -> Result<Option<String>, OperationFailResult> {
     let topic = app
         .topic_list
         .get(topic_id)
@@ -50,9 +402,368 @@ pub async fn delete_queue(
 
     let mut topic_data = topic.get_access().await;
 
+    let ranges = topic_data
+        .queues
+        .get(queue_id)
+        .map(|topic_queue| format_intervals(&topic_queue.queue));
+
     topic_data.queues.delete_queue(queue_id);
 
-    app.prometheus.queue_is_deleted(topic_id, queue_id);
+    if let Err(err) = app.prometheus.queue_is_deleted(topic_id, queue_id) {
+        app.logs.add_info(
+            Some(topic_id.to_string()),
+            SystemProcess::QueueOperation,
+            "delete_queue".to_string(),
+            err,
+            None,
+        );
+    }
+    app.metrics_history.remove_queue(topic_id, queue_id).await;
+
+    Ok(ranges)
+}
+
+#[cfg(test)]
+mod tests {
+    use my_service_bus_tcp_shared::MessageToPublishTcpContract;
+
+    use crate::{
+        sessions::{MyServiceBusSession, SessionId, TestConnectionData},
+        settings::{CliArgs, SettingsModel},
+    };
+
+    use super::*;
+
+    const TOPIC_NAME: &str = "test-topic";
+    const QUEUE_NAME: &str = "test-queue";
+    const SESSION_ID: SessionId = 13;
+
+    async fn create_app_with_messages() -> (Arc<AppContext>, Arc<MyServiceBusSession>) {
+        let settings = SettingsModel::create_test_settings(16);
+
+        let app = Arc::new(AppContext::new(&settings, CliArgs::default()).await);
+
+        let session = app
+            .sessions
+            .add_test(TestConnectionData::new(SESSION_ID, "127.0.0.1"))
+            .await;
+
+        crate::operations::publisher::create_topic_if_not_exists(
+            &app,
+            Some(session.id),
+            TOPIC_NAME,
+        )
+        .await
+        .unwrap();
+
+        create_queue_if_not_exists(
+            &app,
+            TOPIC_NAME,
+            QUEUE_NAME,
+            TopicQueueType::Permanent,
+            None,
+        )
+        .await
+        .unwrap();
+
+        let messages = (0..5u8)
+            .map(|itm| MessageToPublishTcpContract {
+                headers: None,
+                content: vec![itm],
+            })
+            .collect();
+
+        crate::operations::publisher::publish(&app, TOPIC_NAME, messages, false, &session)
+            .await
+            .unwrap();
+
+        (app, session)
+    }
+
+    async fn get_persisted_ranges(app: &AppContext) -> Vec<(MessageId, MessageId)> {
+        let snapshot = app.topics_and_queues_repo.load().await.unwrap();
+
+        let topic = snapshot
+            .iter()
+            .find(|itm| itm.topic_id == TOPIC_NAME)
+            .unwrap();
+
+        let queue = topic
+            .queues
+            .iter()
+            .find(|itm| itm.queue_id == QUEUE_NAME)
+            .unwrap();
+
+        queue
+            .ranges
+            .iter()
+            .map(|itm| (itm.from_id, itm.to_id))
+            .collect()
+    }
+
+    fn create_ids(from_id: MessageId, to_id: MessageId) -> QueueWithIntervals {
+        let mut ids = QueueWithIntervals::new();
+
+        for message_id in from_id..=to_id {
+            ids.enqueue(message_id);
+        }
+
+        ids
+    }
+
+    #[tokio::test]
+    async fn test_skip_and_requeue_messages_are_persisted() {
+        let (app, _) = create_app_with_messages().await;
+
+        let change = skip_messages(&app, TOPIC_NAME, QUEUE_NAME, create_ids(1, 2))
+            .await
+            .unwrap();
+
+        assert_eq!("0-4", change.before);
+        assert_eq!("0,3-4", change.after);
+        assert_eq!(vec![(0, 0), (3, 4)], get_persisted_ranges(&app).await);
+
+        let change = requeue_messages(&app, TOPIC_NAME, QUEUE_NAME, create_ids(1, 1))
+            .await
+            .unwrap();
+
+        assert_eq!("0-1,3-4", change.after);
+        assert_eq!(vec![(0, 1), (3, 4)], get_persisted_ranges(&app).await);
+
+        // Messages which are not published yet can not be requeued
+        let result = requeue_messages(&app, TOPIC_NAME, QUEUE_NAME, create_ids(4, 5)).await;
+        assert!(matches!(result, Err(OperationFailResult::Other(_))));
+    }
+
+    #[tokio::test]
+    async fn test_declared_queue_accumulates_messages_before_first_subscriber() {
+        let settings = SettingsModel::create_test_settings(16);
+
+        let app = Arc::new(AppContext::new(&settings, CliArgs::default()).await);
+
+        let session = app
+            .sessions
+            .add_test(TestConnectionData::new(SESSION_ID, "127.0.0.1"))
+            .await;
+
+        let result = declare_queue(
+            &app,
+            TOPIC_NAME,
+            QUEUE_NAME,
+            TopicQueueType::DeleteOnDisconnect,
+            None,
+        )
+        .await;
+        assert!(result.is_err());
+
+        let created = declare_queue(
+            &app,
+            TOPIC_NAME,
+            QUEUE_NAME,
+            TopicQueueType::Permanent,
+            None,
+        )
+        .await
+        .unwrap();
+        assert!(created);
+
+        let created = declare_queue(
+            &app,
+            TOPIC_NAME,
+            QUEUE_NAME,
+            TopicQueueType::Permanent,
+            None,
+        )
+        .await
+        .unwrap();
+        assert!(!created);
+
+        let messages = (0..3u8)
+            .map(|itm| MessageToPublishTcpContract {
+                headers: None,
+                content: vec![itm],
+            })
+            .collect();
+
+        crate::operations::publisher::publish(&app, TOPIC_NAME, messages, false, &session)
+            .await
+            .unwrap();
+
+        {
+            let topic = app.topic_list.get(TOPIC_NAME).await.unwrap();
+            let topic_data = topic.get_access().await;
+            let topic_queue = topic_data.queues.get(QUEUE_NAME).unwrap();
+            assert_eq!(3, topic_queue.get_queue_size());
+        }
+
+        let snapshot = app.topics_and_queues_repo.load().await.unwrap();
+        let topic = snapshot
+            .iter()
+            .find(|itm| itm.topic_id == TOPIC_NAME)
+            .unwrap();
+        assert!(topic.queues.iter().any(|itm| itm.queue_id == QUEUE_NAME));
+    }
+
+    #[tokio::test]
+    async fn test_paused_queue_is_not_delivered_until_resumed() {
+        let (app, session) = create_app_with_messages().await;
+
+        let paused = set_queue_paused(&app, TOPIC_NAME, QUEUE_NAME, true)
+            .await
+            .unwrap();
+        assert!(!paused);
+
+        crate::operations::subscriber::subscribe_to_queue(
+            &app,
+            TOPIC_NAME.to_string(),
+            QUEUE_NAME.to_string(),
+            TopicQueueType::Permanent,
+            &session,
+        )
+        .await
+        .unwrap();
+
+        {
+            let topic = app.topic_list.get(TOPIC_NAME).await.unwrap();
+            let topic_data = topic.get_access().await;
+            let topic_queue = topic_data.queues.get(QUEUE_NAME).unwrap();
+            assert_eq!(0, topic_queue.get_on_delivery());
+            assert_eq!(5, topic_queue.get_queue_size());
+        }
+
+        let snapshot = app.topics_and_queues_repo.load().await.unwrap();
+        let topic = snapshot
+            .iter()
+            .find(|itm| itm.topic_id == TOPIC_NAME)
+            .unwrap();
+        assert!(
+            topic
+                .queues
+                .iter()
+                .find(|itm| itm.queue_id == QUEUE_NAME)
+                .unwrap()
+                .paused
+        );
+
+        let paused = set_queue_paused(&app, TOPIC_NAME, QUEUE_NAME, false)
+            .await
+            .unwrap();
+        assert!(paused);
+
+        let topic = app.topic_list.get(TOPIC_NAME).await.unwrap();
+        let topic_data = topic.get_access().await;
+        let topic_queue = topic_data.queues.get(QUEUE_NAME).unwrap();
+        assert_eq!(5, topic_queue.get_on_delivery());
+        assert_eq!(0, topic_queue.get_queue_size());
+    }
+
+    #[tokio::test]
+    async fn test_purge_drops_queue_and_absorbs_confirmation_of_purged_delivery() {
+        let mut settings = SettingsModel::create_test_settings(16);
+        settings.open_telemetry_endpoint = Some("http://127.0.0.1:4318".to_string());
+
+        let app = Arc::new(AppContext::new(&settings, CliArgs::default()).await);
+
+        let session = app
+            .sessions
+            .add_test(TestConnectionData::new(SESSION_ID, "127.0.0.1"))
+            .await;
+
+        crate::operations::publisher::create_topic_if_not_exists(
+            &app,
+            Some(session.id),
+            TOPIC_NAME,
+        )
+        .await
+        .unwrap();
+
+        crate::operations::subscriber::subscribe_to_queue(
+            &app,
+            TOPIC_NAME.to_string(),
+            QUEUE_NAME.to_string(),
+            TopicQueueType::Permanent,
+            &session,
+        )
+        .await
+        .unwrap();
+
+        let mut headers = std::collections::HashMap::new();
+        headers.insert(
+            crate::telemetry::TRACE_PARENT_HEADER.to_string(),
+            "00-0af7651916cd43dd8448eb211c80319c-b7ad6b7169203331-01".to_string(),
+        );
+
+        let messages = (0..3u8)
+            .map(|itm| MessageToPublishTcpContract {
+                headers: Some(headers.clone()),
+                content: vec![itm],
+            })
+            .collect();
+
+        crate::operations::publisher::publish(&app, TOPIC_NAME, messages, false, &session)
+            .await
+            .unwrap();
+
+        let subscriber_id = {
+            let topic = app.topic_list.get(TOPIC_NAME).await.unwrap();
+            let topic_data = topic.get_access().await;
+            let topic_queue = topic_data.queues.get(QUEUE_NAME).unwrap();
+            assert_eq!(3, topic_queue.get_on_delivery());
+            topic_queue.subscribers.get_all().unwrap()[0].id
+        };
+
+        app.telemetry.get_spans_to_export();
+
+        let purged_amount = purge_queue(&app, TOPIC_NAME, QUEUE_NAME).await.unwrap();
+        assert_eq!(3, purged_amount);
+
+        let spans = app.telemetry.get_spans_to_export();
+        assert_eq!(3, spans.len());
+        assert!(spans.iter().all(|span| span.name == "deliver" && !span.ok));
+
+        crate::operations::delivery_confirmation::all_confirmed(
+            &app,
+            TOPIC_NAME,
+            QUEUE_NAME,
+            subscriber_id,
+        )
+        .await
+        .unwrap();
+
+        {
+            let topic = app.topic_list.get(TOPIC_NAME).await.unwrap();
+            let topic_data = topic.get_access().await;
+            let topic_queue = topic_data.queues.get(QUEUE_NAME).unwrap();
+            assert_eq!(0, topic_queue.get_on_delivery());
+            assert_eq!(0, topic_queue.get_queue_size());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_messages_on_delivery_are_not_requeued() {
+        let (app, session) = create_app_with_messages().await;
+
+        crate::operations::subscriber::subscribe_to_queue(
+            &app,
+            TOPIC_NAME.to_string(),
+            QUEUE_NAME.to_string(),
+            TopicQueueType::Permanent,
+            &session,
+        )
+        .await
+        .unwrap();
+
+        let change = requeue_messages(&app, TOPIC_NAME, QUEUE_NAME, create_ids(0, 4))
+            .await
+            .unwrap();
+
+        assert_eq!("", change.before);
+        assert_eq!("", change.after);
+
+        let topic = app.topic_list.get(TOPIC_NAME).await.unwrap();
+        let topic_data = topic.get_access().await;
+        let topic_queue = topic_data.queues.get(QUEUE_NAME).unwrap();
 
-    Ok(())
+        assert_eq!(5, topic_queue.get_on_delivery());
+    }
 }
@@ -0,0 +1,120 @@
+use crate::{
+    app::{logs::SystemProcess, AppContext},
+    settings::SettingsModel,
+};
+
+use super::OperationFailResult;
+
+pub async fn reload_settings(
+    app: &AppContext,
+    reason: &str,
+) -> Result<Vec<String>, OperationFailResult> {
+    let mut notes = Vec::new();
+
+    let settings = match SettingsModel::try_read(&app.cli_args, &mut notes).await {
+        Ok(settings) => settings,
+        Err(err) => {
+            notes.insert(0, format!("Reload is triggered by {}", reason));
+
+            app.logs.add_error(
+                None,
+                SystemProcess::System,
+                "reload_settings".to_string(),
+                format!(
+                    "Can not reload settings. Previous settings are kept. Reason: {}",
+                    err
+                ),
+                Some(notes.join("; ")),
+            );
+
+            return Err(OperationFailResult::Other(err));
+        }
+    };
+
+    let changes = app.apply_settings(&settings).await;
+
+    let message = if changes.is_empty() {
+        format!("Settings are reloaded ({}). Nothing is changed", reason)
+    } else {
+        format!(
+            "Settings are reloaded ({}). Changes: {}",
+            reason,
+            changes.join("; ")
+        )
+    };
+
+    app.logs.add_info(
+        None,
+        SystemProcess::System,
+        "reload_settings".to_string(),
+        message,
+        if notes.is_empty() {
+            None
+        } else {
+            Some(notes.join("; "))
+        },
+    );
+
+    Ok(changes)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use crate::settings::CliArgs;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_reload_applies_mutable_settings_and_rejects_invalid_file() {
+        let settings_file = std::env::temp_dir().join(format!(
+            "my-sb-reload-settings-{}.yaml",
+            uuid::Uuid::new_v4()
+        ));
+
+        let cli_args = CliArgs {
+            settings_file: Some(settings_file.to_str().unwrap().to_string()),
+            ..Default::default()
+        };
+
+        let settings = SettingsModel::create_test_settings(16);
+        let app = Arc::new(AppContext::new(&settings, cli_args).await);
+
+        app.set_debug_topic_and_queue("test-topic", "test-queue")
+            .await;
+
+        tokio::fs::write(
+            &settings_file,
+            "MaxDeliverySize: 1024\nDeliveryTimeout: 00:00:10\nQueueGcTimeout: 00:00:05\nAutoCreateTopicOnPublish: false\nAutoCreateTopicOnSubscribe: true\nDebugMode: false\n",
+        )
+        .await
+        .unwrap();
+
+        let changes = reload_settings(app.as_ref(), "test").await.unwrap();
+
+        assert_eq!(5, changes.len());
+        assert_eq!(1024, app.get_max_delivery_size());
+        assert_eq!(Duration::from_secs(10), app.get_delivery_timeout());
+        assert_eq!(Duration::from_secs(5), app.get_empty_queue_gc_timeout());
+        assert!(!app.auto_create_topic_on_publish());
+        assert!(app.auto_create_topic_on_subscribe());
+        assert!(!app.is_debug_mode());
+        assert!(app.debug_topic_and_queue.read().await.is_none());
+
+        tokio::fs::write(
+            &settings_file,
+            "MaxDeliverySize: 2048\nDeliveryTimeout: abc\n",
+        )
+        .await
+        .unwrap();
+
+        let result = reload_settings(app.as_ref(), "test").await;
+        assert!(result.is_err());
+
+        assert_eq!(1024, app.get_max_delivery_size());
+        assert_eq!(Duration::from_secs(10), app.get_delivery_timeout());
+
+        let _ = tokio::fs::remove_file(&settings_file).await;
+    }
+}
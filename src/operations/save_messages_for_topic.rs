@@ -1,15 +1,38 @@
 use std::sync::Arc;
 
-use my_service_bus_shared::sub_page::SubPageId;
+use my_service_bus_shared::{protobuf_models::MessageProtobufModel, sub_page::SubPageId};
+use rust_extensions::StopWatch;
 
-use crate::{app::AppContext, messages_page::MessagesToPersistBucket, topics::Topic};
+use crate::{
+    app::AppContext,
+    messages_page::MessagesToPersistBucket,
+    telemetry::{SpanKind, TelemetrySpan, TraceContext, TRACE_PARENT_HEADER},
+    topics::Topic,
+};
+
+pub async fn save_messages_for_topic(app: &Arc<AppContext>, topic: &Arc<Topic>) -> bool {
+    let mut all_are_saved = true;
 
-pub async fn save_messages_for_topic(app: &Arc<AppContext>, topic: &Arc<Topic>) {
     while let Some((sub_page_id, mut messages_to_persist)) =
         super::get_next_messages_to_persist(topic.as_ref()).await
     {
+        if !messages_to_persist.not_found.is_empty() {
+            app.logs.add_error(
+                Some(topic.topic_id.to_string()),
+                crate::app::logs::SystemProcess::Timer,
+                "persist_messages".to_string(),
+                "Somehow we can not find messages to persist".to_string(),
+                Some(format!("{:?}", messages_to_persist.not_found)),
+            );
+        }
+
         let messages = messages_to_persist.get();
 
+        let persist_spans = start_persist_spans(app, topic.topic_id.as_str(), &messages);
+
+        let mut sw = StopWatch::new();
+        sw.start();
+
         let result = if app.persist_compressed {
             app.messages_pages_repo
                 .save_messages(topic.topic_id.as_str(), messages)
@@ -20,7 +43,17 @@ pub async fn save_messages_for_topic(app: &Arc<AppContext>, topic: &Arc<Topic>)
                 .await
         };
 
+        sw.pause();
+        app.prometheus
+            .observe_persistence_save(topic.topic_id.as_str(), sw.duration());
+
+        for span in persist_spans {
+            app.telemetry.finish_span(span, result.is_ok());
+        }
+
         if let Err(err) = result {
+            all_are_saved = false;
+
             commit_persisted(topic.as_ref(), sub_page_id, &messages_to_persist, false).await;
 
             app.logs.add_error(
@@ -37,6 +70,8 @@ pub async fn save_messages_for_topic(app: &Arc<AppContext>, topic: &Arc<Topic>)
             commit_persisted(topic.as_ref(), sub_page_id, &messages_to_persist, true).await;
         }
     }
+
+    all_are_saved
 }
 
 async fn commit_persisted(
@@ -50,3 +85,35 @@ async fn commit_persisted(
         .pages
         .commit_persisted_messages(sub_page_id, messages_to_persist, persisted);
 }
+
+fn start_persist_spans(
+    app: &AppContext,
+    topic_id: &str,
+    messages: &[MessageProtobufModel],
+) -> Vec<TelemetrySpan> {
+    let mut result = Vec::new();
+
+    if !app.telemetry.is_enabled() {
+        return result;
+    }
+
+    for message in messages {
+        let span = message
+            .headers
+            .iter()
+            .find(|header| header.key == TRACE_PARENT_HEADER)
+            .and_then(|header| TraceContext::parse(header.value.as_str()))
+            .and_then(|parent| {
+                app.telemetry
+                    .start_span("persist", SpanKind::Internal, &parent)
+            });
+
+        if let Some(mut span) = span {
+            span.add_attribute("messaging.destination.name", topic_id.to_string());
+            span.add_attribute("messaging.message.id", message.message_id.to_string());
+            result.push(span);
+        }
+    }
+
+    result
+}
@@ -1,6 +1,13 @@
 use std::sync::Arc;
 
-use crate::{sessions::MyServiceBusSession, topics::TopicData};
+use my_service_bus_shared::MessageId;
+
+use crate::{
+    app::AppContext,
+    sessions::MyServiceBusSession,
+    telemetry::{SpanKind, TelemetrySpan, TraceContext},
+    topics::TopicData,
+};
 
 use super::delivery::{SendNewMessagesResult, SubscriberPackageBuilder};
 
@@ -9,20 +16,15 @@ pub fn send_package(
     tcp_packet: my_service_bus_tcp_shared::TcpContract,
 ) {
     let _handle = tokio::spawn(async move {
-        match &session.connection {
-            crate::sessions::SessionConnection::Tcp(data) => {
-                data.connection.send(tcp_packet).await;
-            }
-            #[cfg(test)]
-            crate::sessions::SessionConnection::Test(data) => {
-                data.send_packet(tcp_packet).await;
-            }
-            crate::sessions::SessionConnection::Http(_) => todo!("Not suppored yet"),
-        }
+        session.send(tcp_packet).await;
     });
 }
 
-pub fn send_new_messages_to_deliver(builder: SubscriberPackageBuilder, topic_data: &mut TopicData) {
+pub fn send_new_messages_to_deliver(
+    app: &AppContext,
+    builder: SubscriberPackageBuilder,
+    topic_data: &mut TopicData,
+) {
     let subscriber_id = builder.subscriber_id;
 
     match builder.get_result() {
@@ -31,9 +33,30 @@ pub fn send_new_messages_to_deliver(builder: SubscriberPackageBuilder, topic_dat
             tcp_contract,
             queue_id,
             messages_on_delivery,
+            redelivered_amount,
+            traces,
         } => {
             if let Some(queue) = topic_data.queues.get_mut(queue_id.as_str()) {
                 if let Some(subsciber) = queue.subscribers.get_by_id_mut(subscriber_id) {
+                    app.prometheus.messages_are_delivered(
+                        queue.topic_id.as_str(),
+                        queue_id.as_str(),
+                        messages_on_delivery.len() as usize,
+                        redelivered_amount,
+                    );
+                    subsciber.delivery_spans = start_delivery_spans(
+                        app,
+                        queue.topic_id.as_str(),
+                        queue_id.as_str(),
+                        traces,
+                    );
+                    session
+                        .statistics
+                        .messages_are_delivered(messages_on_delivery.len() as usize);
+                    app.prometheus.session_messages_are_delivered(
+                        session.get_metrics_name().as_str(),
+                        messages_on_delivery.len() as usize,
+                    );
                     subsciber.set_messages_on_delivery(messages_on_delivery);
                     send_package(session, tcp_contract);
                     subsciber.metrics.set_started_delivery();
@@ -49,3 +72,26 @@ pub fn send_new_messages_to_deliver(builder: SubscriberPackageBuilder, topic_dat
         }
     }
 }
+
+fn start_delivery_spans(
+    app: &AppContext,
+    topic_id: &str,
+    queue_id: &str,
+    traces: Vec<(MessageId, TraceContext)>,
+) -> Vec<(MessageId, TelemetrySpan)> {
+    let mut result = Vec::new();
+
+    for (message_id, trace_context) in traces {
+        if let Some(mut span) =
+            app.telemetry
+                .start_span("deliver", SpanKind::Consumer, &trace_context)
+        {
+            span.add_attribute("messaging.destination.name", topic_id.to_string());
+            span.add_attribute("messaging.consumer.group.name", queue_id.to_string());
+            span.add_attribute("messaging.message.id", message_id.to_string());
+            result.push((message_id, span));
+        }
+    }
+
+    result
+}
@@ -1,4 +1,7 @@
-use crate::{app::AppContext, sessions::MyServiceBusSession};
+use crate::{
+    app::{logs::SystemProcess, AppContext},
+    sessions::MyServiceBusSession,
+};
 
 pub async fn disconnect(app: &AppContext, disconnected_session: &MyServiceBusSession) {
     let topics = app.topic_list.get_all().await;
@@ -10,14 +13,24 @@ pub async fn disconnect(app: &AppContext, disconnected_session: &MyServiceBusSes
 
         if let Some(removed_subscribers) = removed_subscribers {
             for (topic_queue, removed_subscriber) in removed_subscribers {
-                println!(
-                    "Subscriber {} with connection_id {} is removed during the session [{}]/{:?} disconnect process",
-                    removed_subscriber.id,
-                    removed_subscriber.session.id,
-                    disconnected_session.id,
-                    disconnected_session.get_name_and_client_version().await
+                app.logs.add_info(
+                    Some(topic_queue.topic_id.to_string()),
+                    SystemProcess::QueueOperation,
+                    "disconnect".to_string(),
+                    format!(
+                        "Subscriber {} with connection_id {} is removed during the session [{}]/{:?} disconnect process",
+                        removed_subscriber.id,
+                        removed_subscriber.session.id,
+                        disconnected_session.id,
+                        disconnected_session.get_name_and_client_version().await
+                    ),
+                    Some(format!("QueueId:{}", topic_queue.queue_id)),
+                );
+                crate::operations::subscriber::remove_subscriber(
+                    app,
+                    topic_queue,
+                    removed_subscriber,
                 );
-                crate::operations::subscriber::remove_subscriber(topic_queue, removed_subscriber);
             }
         }
     }
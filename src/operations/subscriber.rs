@@ -3,8 +3,10 @@ use std::sync::Arc;
 use my_service_bus_shared::queue::TopicQueueType;
 
 use crate::{
-    app::AppContext, queue_subscribers::QueueSubscriber, queues::TopicQueue,
-    sessions::MyServiceBusSession,
+    app::AppContext,
+    queue_subscribers::QueueSubscriber,
+    queues::TopicQueue,
+    sessions::{MyServiceBusSession, SessionId},
 };
 
 use super::OperationFailResult;
@@ -19,7 +21,7 @@ pub async fn subscribe_to_queue(
     let mut topic = app.topic_list.get(topic_id.as_str()).await;
 
     if topic.is_none() {
-        if app.auto_create_topic_on_subscribe {
+        if app.auto_create_topic_on_subscribe() {
             topic = Some(app.topic_list.add_if_not_exists(topic_id.as_str()).await?);
         } else {
             return Err(OperationFailResult::TopicNotFound { topic_id });
@@ -62,7 +64,7 @@ pub async fn subscribe_to_queue(
     );
 
     if let Some(kicked_subscriber) = kicked_subscriber_result {
-        remove_subscriber(topic_queue, kicked_subscriber);
+        remove_subscriber(app, topic_queue, kicked_subscriber);
     }
 
     super::delivery::start_new(&app, &topic, &mut topic_data);
@@ -70,7 +72,63 @@ pub async fn subscribe_to_queue(
     Ok(())
 }
 
-pub fn remove_subscriber(queue: &mut TopicQueue, mut subscriber: QueueSubscriber) {
+pub async fn unsubscribe_from_queue(
+    app: &Arc<AppContext>,
+    topic_id: &str,
+    queue_id: &str,
+    session_id: SessionId,
+) -> Result<(), OperationFailResult> {
+    let topic = app
+        .topic_list
+        .get(topic_id)
+        .await
+        .ok_or(OperationFailResult::TopicNotFound {
+            topic_id: topic_id.to_string(),
+        })?;
+
+    let mut topic_data = topic.get_access().await;
+
+    let topic_queue =
+        topic_data
+            .queues
+            .get_mut(queue_id)
+            .ok_or(OperationFailResult::QueueNotFound {
+                queue_id: queue_id.to_string(),
+            })?;
+
+    if let Some(removed_subscriber) = topic_queue.subscribers.remove_by_session_id(session_id) {
+        app.logs.add_info(
+            Some(topic.topic_id.to_string()),
+            crate::app::logs::SystemProcess::QueueOperation,
+            format!(
+                "Unsubscribed. SessionId: {}. SubscriberId: {}",
+                session_id, removed_subscriber.id
+            ),
+            format!(
+                "Session {} is unsubscribing from the {}/{} ",
+                session_id, topic.topic_id, topic_queue.queue_id
+            ),
+            None,
+        );
+
+        remove_subscriber(app, topic_queue, removed_subscriber);
+    }
+
+    super::delivery::start_new(&app, &topic, &mut topic_data);
+
+    Ok(())
+}
+
+pub fn remove_subscriber(
+    app: &AppContext,
+    queue: &mut TopicQueue,
+    mut subscriber: QueueSubscriber,
+) {
+    //Messages on delivery are going to be redelivered. Their spans are finished as not delivered
+    for (_, span) in std::mem::take(&mut subscriber.delivery_spans) {
+        app.telemetry.finish_span(span, false);
+    }
+
     let messages = subscriber.reset_delivery();
 
     if let Some(delivery_bucket) = &messages {
@@ -0,0 +1,373 @@
+use std::{collections::HashMap, sync::Arc};
+
+use crate::{
+    app::{logs::SystemProcess, AppContext},
+    persistence::PersistenceError,
+};
+
+use super::OperationFailResult;
+
+///Returns the previous read-only mode of the topic
+pub async fn set_read_only(
+    app: &Arc<AppContext>,
+    topic_id: &str,
+    read_only: bool,
+) -> Result<bool, OperationFailResult> {
+    let topic = app
+        .topic_list
+        .get(topic_id)
+        .await
+        .ok_or(OperationFailResult::TopicNotFound {
+            topic_id: topic_id.to_string(),
+        })?;
+
+    {
+        let mut topic_data = topic.get_access().await;
+
+        if topic_data.read_only == read_only {
+            return Ok(read_only);
+        }
+
+        topic_data.read_only = read_only;
+    }
+
+    app.logs.add_info(
+        Some(topic_id.to_string()),
+        SystemProcess::TopicOperation,
+        "set_read_only".to_string(),
+        if read_only {
+            "Topic is switched to read-only mode. Publishing is rejected".to_string()
+        } else {
+            "Topic is switched to read-write mode".to_string()
+        },
+        None,
+    );
+
+    crate::operations::persist_topics_and_queues(app).await;
+
+    Ok(!read_only)
+}
+
+pub async fn delete_topic(
+    app: &Arc<AppContext>,
+    topic_id: &str,
+    force: bool,
+    delete_persisted_pages: bool,
+) -> Result<(), OperationFailResult> {
+    let topic = app
+        .topic_list
+        .get(topic_id)
+        .await
+        .ok_or(OperationFailResult::TopicNotFound {
+            topic_id: topic_id.to_string(),
+        })?;
+
+    let unpersisted_amount = {
+        let topic_data = topic.get_access().await;
+
+        let subscribers_amount: usize = topic_data
+            .queues
+            .get_all()
+            .map(|queue| queue.subscribers.get_amount())
+            .sum();
+
+        if subscribers_amount > 0 && !force {
+            return Err(OperationFailResult::TopicHasSubscribers {
+                topic_id: topic_id.to_string(),
+                amount: subscribers_amount,
+            });
+        }
+
+        let unpersisted_amount = topic_data.pages.get_page_size_metrics().persist_size;
+
+        if unpersisted_amount > 0 && !force {
+            return Err(OperationFailResult::TopicHasUnpersistedMessages {
+                topic_id: topic_id.to_string(),
+                amount: unpersisted_amount,
+            });
+        }
+
+        unpersisted_amount
+    };
+
+    // Topic is removed from the list first, so new publishers and subscribers do not find it
+    app.topic_list.delete(topic_id).await;
+
+    let mut sessions_to_notify = HashMap::new();
+
+    {
+        let mut topic_data = topic.get_access().await;
+
+        for topic_queue in topic_data.queues.get_all_mut() {
+            let subscriber_ids: Vec<_> = match topic_queue.subscribers.get_all() {
+                Some(subscribers) => subscribers.iter().map(|itm| itm.id).collect(),
+                None => continue,
+            };
+
+            for subscriber_id in subscriber_ids {
+                if let Some(subscriber) = topic_queue.subscribers.remove(subscriber_id) {
+                    app.logs.add_info(
+                        Some(topic_id.to_string()),
+                        SystemProcess::TopicOperation,
+                        "Delete topic".to_string(),
+                        format!(
+                            "Subscriber {} with session {} is removed because topic is deleted",
+                            subscriber.id, subscriber.session.id
+                        ),
+                        Some(format!("QueueId:{}", topic_queue.queue_id)),
+                    );
+
+                    sessions_to_notify.insert(subscriber.session.id, subscriber.session.clone());
+
+                    super::subscriber::remove_subscriber(app, topic_queue, subscriber);
+                }
+            }
+
+            if let Err(err) = app
+                .prometheus
+                .queue_is_deleted(topic_id, topic_queue.queue_id.as_str())
+            {
+                app.logs.add_info(
+                    Some(topic_id.to_string()),
+                    SystemProcess::TopicOperation,
+                    "delete_topic".to_string(),
+                    err,
+                    None,
+                );
+            }
+        }
+    }
+
+    // There is no packet to report a removed subscription, so the sessions reconnect and resubscribe
+    for session in sessions_to_notify.into_values() {
+        session.disconnect().await;
+    }
+
+    app.prometheus.topic_is_deleted(topic_id);
+    app.metrics_history.remove_topic(topic_id).await;
+
+    app.logs.add_info(
+        Some(topic_id.to_string()),
+        SystemProcess::TopicOperation,
+        "Delete topic".to_string(),
+        format!(
+            "Topic {} is deleted. Force: {}. DeletePersistedPages: {}. Unpersisted messages dropped: {}",
+            topic_id, force, delete_persisted_pages, unpersisted_amount
+        ),
+        None,
+    );
+
+    super::persist_topics_and_queues(app).await;
+
+    if delete_persisted_pages {
+        match app.messages_pages_repo.delete_topic(topic_id).await {
+            Ok(()) => {}
+            // Persistence older than the DeleteTopic contract keeps the pages
+            Err(PersistenceError::TonicError(status))
+                if status.code() == tonic::Code::Unimplemented =>
+            {
+                app.logs.add_error(
+                    Some(topic_id.to_string()),
+                    SystemProcess::TopicOperation,
+                    "Delete topic".to_string(),
+                    format!(
+                        "Topic {} is deleted, but persistence does not implement DeleteTopic. Persisted pages are kept",
+                        topic_id
+                    ),
+                    None,
+                );
+
+                return Err(OperationFailResult::PersistenceOperationIsNotSupported {
+                    operation: "DeleteTopic".to_string(),
+                });
+            }
+            Err(err) => {
+                return Err(OperationFailResult::PersistenceError(format!("{:?}", err)));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use my_service_bus_shared::queue::TopicQueueType;
+    use my_service_bus_tcp_shared::MessageToPublishTcpContract;
+
+    use crate::{
+        persistence::MessagesPagesRepo,
+        sessions::{MyServiceBusSession, SessionId, TestConnectionData},
+        settings::{CliArgs, SettingsModel},
+    };
+
+    use super::*;
+
+    const TOPIC_NAME: &str = "test-topic";
+    const QUEUE_NAME: &str = "test-queue";
+    const SESSION_ID: SessionId = 13;
+
+    async fn create_app_with_message() -> (Arc<AppContext>, Arc<MyServiceBusSession>) {
+        let settings = SettingsModel::create_test_settings(16);
+
+        let app = Arc::new(AppContext::new(&settings, CliArgs::default()).await);
+
+        let session = app
+            .sessions
+            .add_test(TestConnectionData::new(SESSION_ID, "127.0.0.1"))
+            .await;
+
+        crate::operations::publisher::create_topic_if_not_exists(
+            &app,
+            Some(session.id),
+            TOPIC_NAME,
+        )
+        .await
+        .unwrap();
+
+        let messages = vec![MessageToPublishTcpContract {
+            headers: None,
+            content: vec![0u8, 1u8, 2u8],
+        }];
+
+        crate::operations::publisher::publish(&app, TOPIC_NAME, messages, false, &session)
+            .await
+            .unwrap();
+
+        (app, session)
+    }
+
+    #[tokio::test]
+    async fn test_read_only_topic_rejects_publish() {
+        let (app, session) = create_app_with_message().await;
+
+        set_read_only(&app, TOPIC_NAME, true).await.unwrap();
+
+        let create_messages = || {
+            vec![MessageToPublishTcpContract {
+                headers: None,
+                content: vec![3u8],
+            }]
+        };
+
+        let result = crate::operations::publisher::publish(
+            &app,
+            TOPIC_NAME,
+            create_messages(),
+            false,
+            &session,
+        )
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(OperationFailResult::TopicIsReadOnly { .. })
+        ));
+
+        let topic = app.topic_list.get(TOPIC_NAME).await.unwrap();
+        assert_eq!(1, topic.get_access().await.message_id);
+
+        let snapshot = app.topics_and_queues_repo.load().await.unwrap();
+        assert!(
+            snapshot
+                .iter()
+                .find(|itm| itm.topic_id == TOPIC_NAME)
+                .unwrap()
+                .read_only
+        );
+
+        set_read_only(&app, TOPIC_NAME, false).await.unwrap();
+
+        crate::operations::publisher::publish(&app, TOPIC_NAME, create_messages(), false, &session)
+            .await
+            .unwrap();
+
+        assert_eq!(2, topic.get_access().await.message_id);
+    }
+
+    #[tokio::test]
+    async fn test_delete_topic_with_unpersisted_messages_is_refused() {
+        let (app, _) = create_app_with_message().await;
+
+        let result = delete_topic(&app, TOPIC_NAME, false, false).await;
+
+        assert!(matches!(
+            result,
+            Err(OperationFailResult::TopicHasUnpersistedMessages { amount: 1, .. })
+        ));
+
+        let topic = app.topic_list.get(TOPIC_NAME).await.unwrap();
+
+        crate::operations::save_messages_for_topic(&app, &topic).await;
+
+        delete_topic(&app, TOPIC_NAME, false, false).await.unwrap();
+
+        assert!(app.topic_list.get(TOPIC_NAME).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_forced_delete_removes_subscribers_and_disconnects_their_sessions() {
+        let (app, session) = create_app_with_message().await;
+
+        crate::operations::subscriber::subscribe_to_queue(
+            &app,
+            TOPIC_NAME.to_string(),
+            QUEUE_NAME.to_string(),
+            TopicQueueType::Permanent,
+            &session,
+        )
+        .await
+        .unwrap();
+
+        let result = delete_topic(&app, TOPIC_NAME, false, false).await;
+
+        assert!(matches!(
+            result,
+            Err(OperationFailResult::TopicHasSubscribers { amount: 1, .. })
+        ));
+
+        delete_topic(&app, TOPIC_NAME, true, false).await.unwrap();
+
+        assert!(app.topic_list.get(TOPIC_NAME).await.is_none());
+
+        let test_connection = session.connection.unwrap_as_test();
+        assert!(!test_connection
+            .connected
+            .load(std::sync::atomic::Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn test_delete_topic_reports_persistence_failures() {
+        let (app, _) = create_app_with_message().await;
+
+        let mock_repo = match app.messages_pages_repo.as_ref() {
+            MessagesPagesRepo::Mock(repo) => repo,
+            _ => panic!("Should not be here"),
+        };
+
+        mock_repo.fail_delete_topic(tonic::Code::Unimplemented);
+
+        let result = delete_topic(&app, TOPIC_NAME, true, true).await;
+
+        assert!(matches!(
+            result,
+            Err(OperationFailResult::PersistenceOperationIsNotSupported { .. })
+        ));
+
+        // The topic is deleted in memory even if persistence keeps the pages
+        assert!(app.topic_list.get(TOPIC_NAME).await.is_none());
+
+        crate::operations::publisher::create_topic_if_not_exists(&app, None, TOPIC_NAME)
+            .await
+            .unwrap();
+
+        mock_repo.fail_delete_topic(tonic::Code::Unavailable);
+
+        let result = delete_topic(&app, TOPIC_NAME, true, true).await;
+
+        assert!(matches!(
+            result,
+            Err(OperationFailResult::PersistenceError(_))
+        ));
+    }
+}
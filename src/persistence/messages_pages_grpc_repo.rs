@@ -116,6 +116,20 @@ impl MessagesPagesGrpcRepo {
         return Ok(());
     }
 
+    pub async fn delete_topic(&self, topic_id: &str) -> Result<(), PersistenceError> {
+        let mut grpc_client = self.create_grpc_service();
+
+        tokio::time::timeout(
+            self.time_out,
+            grpc_client.delete_topic(DeleteTopicGrpcRequest {
+                topic_id: topic_id.to_string(),
+            }),
+        )
+        .await??;
+
+        Ok(())
+    }
+
     pub async fn load_page(
         &self,
         topic_id: &str,
@@ -155,12 +169,6 @@ impl MessagesPagesGrpcRepo {
             );
         }
 
-        println!(
-            "Read Page {} with messages amount: {}",
-            page_id,
-            messages.len()
-        );
-
         Ok(Some(messages))
     }
 }
@@ -7,15 +7,21 @@ use super::PersistenceError;
 
 pub struct MessagesPagesMockRepo {
     messages: Mutex<HashMap<String, HashMap<MessageId, MySbMessageContent>>>,
+    delete_topic_fail_code: std::sync::Mutex<Option<tonic::Code>>,
 }
 
 impl MessagesPagesMockRepo {
     pub fn new() -> Self {
         Self {
             messages: Mutex::new(HashMap::new()),
+            delete_topic_fail_code: std::sync::Mutex::new(None),
         }
     }
 
+    pub fn fail_delete_topic(&self, code: tonic::Code) {
+        *self.delete_topic_fail_code.lock().unwrap() = Some(code);
+    }
+
     pub async fn load_page(
         &self,
         topic_id: &str,
@@ -45,6 +51,19 @@ impl MessagesPagesMockRepo {
         }
     }
 
+    pub async fn delete_topic(&self, topic_id: &str) -> Result<(), PersistenceError> {
+        if let Some(code) = *self.delete_topic_fail_code.lock().unwrap() {
+            return Err(PersistenceError::TonicError(tonic::Status::new(
+                code,
+                "DeleteTopic is failed",
+            )));
+        }
+
+        let mut write_access = self.messages.lock().await;
+        write_access.remove(topic_id);
+        Ok(())
+    }
+
     pub async fn save_messages(
         &self,
         topic_id: &str,
@@ -72,6 +72,14 @@ impl MessagesPagesRepo {
         }
     }
 
+    pub async fn delete_topic(&self, topic_id: &str) -> Result<(), PersistenceError> {
+        match self {
+            MessagesPagesRepo::Grpc(repo) => repo.delete_topic(topic_id).await,
+            #[cfg(test)]
+            MessagesPagesRepo::Mock(repo) => repo.delete_topic(topic_id).await,
+        }
+    }
+
     pub async fn get_persistence_version(&self) -> Option<String> {
         let result = match self {
             MessagesPagesRepo::Grpc(repo) => repo.get_persistence_version().await,
@@ -3,7 +3,7 @@ use std::{sync::Arc, time::Duration};
 use my_service_bus_shared::{queue_with_intervals::QueueWithIntervals, MessageId};
 use rust_extensions::date_time::DateTimeAsMicroseconds;
 
-use crate::{queues::DeliveryBucket, sessions::MyServiceBusSession};
+use crate::{queues::DeliveryBucket, sessions::MyServiceBusSession, telemetry::TelemetrySpan};
 
 use super::{SubscriberId, SubscriberMetrics};
 
@@ -44,6 +44,8 @@ pub struct QueueSubscriber {
 
     pub id: SubscriberId,
     pub session: Arc<MyServiceBusSession>,
+
+    pub delivery_spans: Vec<(MessageId, TelemetrySpan)>,
 }
 
 impl QueueSubscriber {
@@ -61,6 +63,7 @@ impl QueueSubscriber {
             delivery_state: QueueSubscriberDeliveryState::ReadyToDeliver,
             session,
             id,
+            delivery_spans: Vec::new(),
         }
     }
 
@@ -107,10 +110,20 @@ impl QueueSubscriber {
         return None;
     }
 
-    pub fn intermediary_confirmed(&mut self, queue: &QueueWithIntervals) {
+    pub fn purge_delivery(&mut self) -> usize {
         if let QueueSubscriberDeliveryState::OnDelivery(state) = &mut self.delivery_state {
-            state.bucket.confirmed(queue);
+            return state.bucket.purge();
         }
+
+        0
+    }
+
+    pub fn intermediary_confirmed(&mut self, queue: &QueueWithIntervals) -> Vec<MessageId> {
+        if let QueueSubscriberDeliveryState::OnDelivery(state) = &mut self.delivery_state {
+            return state.bucket.confirmed(queue);
+        }
+
+        Vec::new()
     }
 
     pub fn set_messages_on_delivery(&mut self, messages: QueueWithIntervals) {
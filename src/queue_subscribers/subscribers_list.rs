@@ -17,6 +17,7 @@ pub enum SubscribersData {
 
 pub struct DeadSubscriber {
     pub subscriber_id: SubscriberId,
+    pub queue_id: String,
     pub session: Arc<MyServiceBusSession>,
     pub duration: Duration,
 }
@@ -26,6 +27,7 @@ impl DeadSubscriber {
         Self {
             session: subscriber.session.clone(),
             subscriber_id: subscriber.id,
+            queue_id: subscriber.queue_id.to_string(),
             duration,
         }
     }
@@ -95,6 +97,16 @@ impl SubscribersList {
         }
     }
 
+    pub fn get_all_mut(&mut self) -> Vec<&mut QueueSubscriber> {
+        match &mut self.data {
+            SubscribersData::MultiSubscribers(hash_map) => hash_map.values_mut().collect(),
+            SubscribersData::SingleSubscriber(single) => match single {
+                Some(subscriber) => vec![subscriber],
+                None => vec![],
+            },
+        }
+    }
+
     pub fn get_min_message_id(&self) -> Option<MessageId> {
         match &self.data {
             SubscribersData::MultiSubscribers(subscribers) => {
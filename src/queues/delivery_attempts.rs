@@ -25,6 +25,10 @@ impl DeliveryAttempts {
         self.attempts.remove(&message_id);
     }
 
+    pub fn clear(&mut self) {
+        self.attempts.clear();
+    }
+
     pub fn add(&mut self, message_id: MessageId) {
         let result = self.attempts.get(&message_id);
 
@@ -1,26 +1,45 @@
-use my_service_bus_shared::queue_with_intervals::QueueWithIntervals;
+use my_service_bus_shared::{queue_with_intervals::QueueWithIntervals, MessageId};
 
 pub struct DeliveryBucket {
     pub ids: QueueWithIntervals,
     pub confirmed: usize,
+    pub purged: bool,
 }
 
 impl DeliveryBucket {
     pub fn new(ids: QueueWithIntervals) -> Self {
-        Self { ids, confirmed: 0 }
+        Self {
+            ids,
+            confirmed: 0,
+            purged: false,
+        }
+    }
+
+    // Client still holds the messages. Its confirmation is absorbed and nothing is returned to the queue
+    pub fn purge(&mut self) -> usize {
+        let purged_amount = self.ids.len() as usize;
+        self.ids.clean();
+        self.purged = true;
+        purged_amount
     }
 
-    pub fn confirmed(&mut self, confirmed: &QueueWithIntervals) {
+    ///Returns the confirmed ids which are not on delivery
+    pub fn confirmed(&mut self, confirmed: &QueueWithIntervals) -> Vec<MessageId> {
+        let mut not_on_delivery = Vec::new();
+
         self.confirmed += confirmed.len() as usize;
 
+        if self.purged {
+            return not_on_delivery;
+        }
+
         for id in confirmed {
-            if let Err(err) = self.ids.remove(id) {
-                println!(
-                    "We are trying to confirm message {} - but something went wrong. Reason: {:?}",
-                    id, err
-                )
+            if self.ids.remove(id).is_err() {
+                not_on_delivery.push(id);
             }
         }
+
+        not_on_delivery
     }
 
     pub fn confirm_everything(&mut self) {
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use my_service_bus_shared::{
     queue::TopicQueueType,
     queue_with_intervals::{QueueIndexRange, QueueWithIntervals},
@@ -21,6 +23,7 @@ pub struct TopicQueue {
     pub subscribers: SubscribersList,
     pub delivery_attempts: DeliveryAttempts,
     pub queue_type: TopicQueueType,
+    pub paused: bool,
 
     pub delivery_lock: Mutex<usize>,
 }
@@ -34,6 +37,7 @@ impl TopicQueue {
             subscribers: SubscribersList::new(queue_type),
             delivery_attempts: DeliveryAttempts::new(),
             queue_type,
+            paused: false,
             delivery_lock: Mutex::new(0),
         }
     }
@@ -43,6 +47,7 @@ impl TopicQueue {
         queue_id: String,
         queue_type: TopicQueueType,
         queue: QueueWithIntervals,
+        paused: bool,
     ) -> Self {
         Self {
             topic_id,
@@ -51,6 +56,7 @@ impl TopicQueue {
             subscribers: SubscribersList::new(queue_type),
             delivery_attempts: DeliveryAttempts::new(),
             queue_type,
+            paused,
             delivery_lock: Mutex::new(0),
         }
     }
@@ -66,6 +72,7 @@ impl TopicQueue {
                     queue_id: self.queue_id.to_string(),
                     queue_type: self.queue_type.clone(),
                     ranges: self.queue.get_snapshot(),
+                    paused: self.paused,
                 };
 
                 Some(result)
@@ -76,6 +83,7 @@ impl TopicQueue {
                     queue_id: self.queue_id.to_string(),
                     queue_type: self.queue_type.clone(),
                     ranges: self.queue.get_snapshot(),
+                    paused: self.paused,
                 };
 
                 Some(result)
@@ -119,6 +127,51 @@ impl TopicQueue {
         self.queue.reset(intervals);
     }
 
+    // Messages which are on delivery stay with their subscribers. Returns the requeued ids
+    pub fn requeue_messages(&mut self, ids: &QueueWithIntervals) -> QueueWithIntervals {
+        let mut intervals = to_ranges(ids);
+
+        if let Some(subscribers) = self.subscribers.get_all() {
+            for subscriber in subscribers {
+                if let Some(on_delivery) = subscriber.get_messages_on_delivery() {
+                    for range in &on_delivery.intervals {
+                        intervals = remove_range(intervals, range.from_id, range.to_id);
+                    }
+                }
+            }
+        }
+
+        let mut requeued = QueueWithIntervals::new();
+        requeued.reset(intervals);
+
+        self.queue.merge_with(&requeued);
+
+        requeued
+    }
+
+    pub fn skip_messages(&mut self, ids: &QueueWithIntervals) {
+        let mut intervals = to_ranges(&self.queue);
+
+        for range in &ids.intervals {
+            intervals = remove_range(intervals, range.from_id, range.to_id);
+        }
+
+        self.queue.reset(intervals);
+    }
+
+    pub fn purge(&mut self) -> usize {
+        let mut purged_amount = self.queue.len() as usize;
+
+        self.queue.clean();
+        self.delivery_attempts.clear();
+
+        for subscriber in self.subscribers.get_all_mut() {
+            purged_amount += subscriber.purge_delivery();
+        }
+
+        purged_amount
+    }
+
     pub fn mark_not_delivered(&mut self, delivery_bucket: &DeliveryBucket) {
         self.process_not_delivered(&delivery_bucket.ids);
     }
@@ -134,7 +187,7 @@ impl TopicQueue {
     pub fn confirmed_delivered(
         &mut self,
         subscriber_id: SubscriberId,
-    ) -> Result<(), OperationFailResult> {
+    ) -> Result<Option<Duration>, OperationFailResult> {
         let subscriber = self.subscribers.get_by_id_mut(subscriber_id);
 
         if subscriber.is_none() {
@@ -146,22 +199,17 @@ impl TopicQueue {
         let messages_bucket = subscriber.reset_delivery();
 
         if messages_bucket.is_none() {
-            println!(
-                "{}/{} confirmed_delivered: No messages on delivery at subscriber {}",
-                self.topic_id, self.queue_id, subscriber_id
-            );
-
-            return Ok(());
+            return Ok(None);
         };
 
         let mut messages_bucket = messages_bucket.unwrap();
         messages_bucket.confirm_everything();
 
-        update_delivery_time(subscriber, messages_bucket.confirmed, true);
+        let delivery_duration = update_delivery_time(subscriber, messages_bucket.confirmed, true);
 
         self.process_delivered(&messages_bucket.ids);
 
-        Ok(())
+        Ok(Some(delivery_duration))
     }
 
     fn process_delivered(&mut self, delivered_ids: &QueueWithIntervals) {
@@ -185,11 +233,6 @@ impl TopicQueue {
         let messages_bucket = subscriber.reset_delivery();
 
         if messages_bucket.is_none() {
-            println!(
-                "{}/{} confirmed_non_delivered: No messages on delivery at subscriber {}",
-                self.topic_id, self.queue_id, subscriber_id
-            );
-
             return Ok(());
         };
 
@@ -206,7 +249,7 @@ impl TopicQueue {
         &mut self,
         subscriber_id: SubscriberId,
         delivered: QueueWithIntervals,
-    ) -> Result<(), OperationFailResult> {
+    ) -> Result<Vec<MessageId>, OperationFailResult> {
         let subscriber = self.subscribers.get_by_id_mut(subscriber_id);
 
         if subscriber.is_none() {
@@ -218,18 +261,13 @@ impl TopicQueue {
         let delivery_bucket = subscriber.reset_delivery();
 
         if delivery_bucket.is_none() {
-            println!(
-                "{}/{} confirmed_some_delivered: No messages on delivery at subscriber {}",
-                self.topic_id, self.queue_id, subscriber_id
-            );
-
-            return Ok(());
+            return Ok(Vec::new());
         };
 
         let mut delivery_bucket = delivery_bucket.unwrap();
 
         //Remove delivered and what remains - is not delivered
-        delivery_bucket.confirmed(&delivered);
+        let not_on_delivery = delivery_bucket.confirmed(&delivered);
 
         update_delivery_time(subscriber, delivery_bucket.confirmed, false);
 
@@ -239,14 +277,14 @@ impl TopicQueue {
             self.process_delivered(&delivered);
         }
 
-        Ok(())
+        Ok(not_on_delivery)
     }
 
     pub fn intermediary_confirmed(
         &mut self,
         subscriber_id: SubscriberId,
         confirmed: QueueWithIntervals,
-    ) -> Result<(), OperationFailResult> {
+    ) -> Result<Vec<MessageId>, OperationFailResult> {
         let subscriber = self.subscribers.get_by_id_mut(subscriber_id);
 
         if subscriber.is_none() {
@@ -255,12 +293,14 @@ impl TopicQueue {
 
         let subscriber = subscriber.unwrap();
 
-        if confirmed.len() > 0 {
-            subscriber.intermediary_confirmed(&confirmed);
-            self.process_delivered(&confirmed);
+        if confirmed.len() == 0 {
+            return Ok(Vec::new());
         }
 
-        return Ok(());
+        let not_on_delivery = subscriber.intermediary_confirmed(&confirmed);
+        self.process_delivered(&confirmed);
+
+        Ok(not_on_delivery)
     }
 
     pub fn get_messages_on_delivery(
@@ -292,7 +332,52 @@ impl TopicQueue {
     }
 }
 
-fn update_delivery_time(subscriber: &mut QueueSubscriber, amount: usize, positive: bool) {
+fn to_ranges(src: &QueueWithIntervals) -> Vec<QueueIndexRange> {
+    src.intervals
+        .iter()
+        .map(|interval| QueueIndexRange {
+            from_id: interval.from_id,
+            to_id: interval.to_id,
+        })
+        .collect()
+}
+
+fn remove_range(
+    intervals: Vec<QueueIndexRange>,
+    from_id: MessageId,
+    to_id: MessageId,
+) -> Vec<QueueIndexRange> {
+    let mut result = Vec::with_capacity(intervals.len() + 1);
+
+    for interval in intervals {
+        if interval.to_id < from_id || interval.from_id > to_id {
+            result.push(interval);
+            continue;
+        }
+
+        if interval.from_id < from_id {
+            result.push(QueueIndexRange {
+                from_id: interval.from_id,
+                to_id: from_id - 1,
+            });
+        }
+
+        if interval.to_id > to_id {
+            result.push(QueueIndexRange {
+                from_id: to_id + 1,
+                to_id: interval.to_id,
+            });
+        }
+    }
+
+    result
+}
+
+fn update_delivery_time(
+    subscriber: &mut QueueSubscriber,
+    amount: usize,
+    positive: bool,
+) -> Duration {
     let delivery_duration = DateTimeAsMicroseconds::now()
         .duration_since(subscriber.metrics.start_delivery_time)
         .as_positive_or_zero();
@@ -306,4 +391,40 @@ fn update_delivery_time(subscriber: &mut QueueSubscriber, amount: usize, positiv
             .metrics
             .set_not_delivered_statistic(amount as i32, delivery_duration);
     }
+
+    delivery_duration
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(from_id: MessageId, to_id: MessageId) -> QueueIndexRange {
+        QueueIndexRange { from_id, to_id }
+    }
+
+    fn as_tuples(intervals: &[QueueIndexRange]) -> Vec<(MessageId, MessageId)> {
+        intervals.iter().map(|i| (i.from_id, i.to_id)).collect()
+    }
+
+    #[test]
+    fn test_remove_range_in_the_middle_splits_interval() {
+        let result = remove_range(vec![range(10, 20)], 13, 15);
+
+        assert_eq!(vec![(10, 12), (16, 20)], as_tuples(&result));
+    }
+
+    #[test]
+    fn test_remove_range_covering_several_intervals() {
+        let result = remove_range(vec![range(1, 5), range(8, 10), range(15, 20)], 4, 16);
+
+        assert_eq!(vec![(1, 3), (17, 20)], as_tuples(&result));
+    }
+
+    #[test]
+    fn test_remove_range_outside_of_intervals() {
+        let result = remove_range(vec![range(10, 20)], 21, 30);
+
+        assert_eq!(vec![(10, 20)], as_tuples(&result));
+    }
 }
@@ -53,8 +53,10 @@ impl TopicQueuesList {
         queue_id: String,
         queue_type: TopicQueueType,
         queue: QueueWithIntervals,
+        paused: bool,
     ) -> &TopicQueue {
-        let topic_queue = TopicQueue::restore(topic_id, queue_id.to_string(), queue_type, queue);
+        let topic_queue =
+            TopicQueue::restore(topic_id, queue_id.to_string(), queue_type, queue, paused);
 
         self.queues.insert(queue_id.to_string(), topic_queue);
 
@@ -71,6 +73,19 @@ impl TopicQueuesList {
         return self.queues.get_mut(queue_id);
     }
 
+    pub fn set_paused(&mut self, queue_id: &str, paused: bool) -> Option<bool> {
+        let topic_queue = self.queues.get_mut(queue_id)?;
+
+        let prev = topic_queue.paused;
+        topic_queue.paused = paused;
+
+        if prev != paused {
+            self.snapshot_id += 1;
+        }
+
+        Some(prev)
+    }
+
     pub fn delete_queue(&mut self, queue_id: &str) -> Option<TopicQueue> {
         let result = self.queues.remove(queue_id);
         self.snapshot_id += 1;
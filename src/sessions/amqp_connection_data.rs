@@ -0,0 +1,163 @@
+use std::{
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+};
+
+use my_service_bus_tcp_shared::{PacketProtVer, TcpContract};
+use tokio::sync::{mpsc::UnboundedSender, Notify};
+
+use crate::{
+    amqp::{AmqpConfirmation, AmqpDeliveries},
+    sessions::{ConnectionMetrics, ConnectionMetricsSnapshot},
+};
+
+pub struct AmqpConnectionData {
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub addr: SocketAddr,
+    pub frame_max: usize,
+    pub deliveries: Mutex<AmqpDeliveries>,
+    sender: UnboundedSender<Vec<u8>>,
+    confirmations: UnboundedSender<AmqpConfirmation>,
+    connection_metrics: ConnectionMetrics,
+    connected: AtomicBool,
+    pub disconnected: Notify,
+}
+
+impl AmqpConnectionData {
+    pub fn new(
+        name: Option<String>,
+        version: Option<String>,
+        addr: SocketAddr,
+        frame_max: usize,
+        sender: UnboundedSender<Vec<u8>>,
+        confirmations: UnboundedSender<AmqpConfirmation>,
+    ) -> Self {
+        Self {
+            name,
+            version,
+            addr,
+            frame_max,
+            deliveries: Mutex::new(AmqpDeliveries::new()),
+            sender,
+            confirmations,
+            connection_metrics: ConnectionMetrics::new(),
+            connected: AtomicBool::new(true),
+            disconnected: Notify::new(),
+        }
+    }
+
+    pub fn get_messages_to_deliver_protocol_version(&self) -> PacketProtVer {
+        PacketProtVer {
+            protocol_version: 3,
+            packet_version: 1,
+        }
+    }
+
+    pub fn send_payload(&self, payload: Vec<u8>) {
+        if !self.connected.load(Ordering::SeqCst) {
+            return;
+        }
+
+        self.connection_metrics.add_written(payload.len());
+        let _ = self.sender.send(payload);
+    }
+
+    pub async fn send(&self, tcp_contract: TcpContract) {
+        let payload = match tcp_contract {
+            TcpContract::Raw(payload) => payload,
+            _ => return,
+        };
+
+        let version = self.get_messages_to_deliver_protocol_version();
+        let tcp_contract =
+            my_service_bus_tcp_shared::tcp_serializers::convert_from_raw(payload, &version).await;
+
+        if let TcpContract::NewMessages {
+            topic_id,
+            queue_id,
+            confirmation_id,
+            messages,
+        } = tcp_contract
+        {
+            let mut payload = Vec::new();
+
+            let consumer = {
+                let mut deliveries = self.deliveries.lock().unwrap();
+
+                let consumer = match deliveries.get_consumer(&topic_id, &queue_id) {
+                    Some(consumer) => consumer.clone(),
+                    None => {
+                        let _ = self.confirmations.send(AmqpConfirmation {
+                            topic_id,
+                            queue_id,
+                            confirmation_id,
+                            result: crate::amqp::AmqpConfirmationResult::AllFail,
+                        });
+                        return;
+                    }
+                };
+
+                let message_ids: Vec<i64> = messages.iter().map(|itm| itm.id).collect();
+                let delivery_tags =
+                    deliveries.register_delivery(&consumer, confirmation_id, &message_ids);
+
+                for (message, delivery_tag) in messages.iter().zip(delivery_tags) {
+                    crate::amqp::serialize_basic_deliver(
+                        &mut payload,
+                        &consumer,
+                        delivery_tag,
+                        message.attempt_no > 0,
+                        &message.headers,
+                        &message.content,
+                        self.frame_max,
+                    );
+                }
+
+                consumer
+            };
+
+            self.send_payload(payload);
+
+            if consumer.no_ack {
+                let _ = self.confirmations.send(AmqpConfirmation {
+                    topic_id,
+                    queue_id,
+                    confirmation_id,
+                    result: crate::amqp::AmqpConfirmationResult::AllConfirmed,
+                });
+            }
+        }
+    }
+
+    pub fn update_read_amount(&self, amount: usize) {
+        self.connection_metrics.add_read(amount);
+    }
+
+    pub fn get_connection_metrics(&self) -> ConnectionMetricsSnapshot {
+        self.connection_metrics.get_snapshot()
+    }
+
+    pub fn one_second_tick(&self) {
+        self.connection_metrics.one_second_tick();
+    }
+
+    pub fn disconnect(&self) -> bool {
+        let result = self.connected.swap(false, Ordering::SeqCst);
+
+        if result {
+            let close = crate::amqp::methods::connection_close(
+                crate::amqp::methods::REPLY_CONNECTION_FORCED,
+                "Session is disconnected",
+            );
+
+            let _ = self.sender.send(close.serialize());
+            self.disconnected.notify_one();
+        }
+
+        result
+    }
+}
@@ -0,0 +1,132 @@
+use std::{
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use my_service_bus_tcp_shared::{PacketProtVer, TcpContract};
+use rust_extensions::date_time::{AtomicDateTimeAsMicroseconds, DateTimeAsMicroseconds};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    app::logs::{Logs, SystemProcess},
+    my_service_bus_grpc::SubscribeGrpcResponse,
+    sessions::{ConnectionMetrics, ConnectionMetricsSnapshot},
+};
+
+pub type GrpcDeliverySender = UnboundedSender<Result<SubscribeGrpcResponse, tonic::Status>>;
+
+pub struct GrpcConnectionData {
+    pub name: Option<String>,
+    pub version: Option<String>,
+    pub addr: Option<SocketAddr>,
+    pub connected_moment: DateTimeAsMicroseconds,
+    pub publisher_key: Option<String>,
+    last_incoming: AtomicDateTimeAsMicroseconds,
+    sender: Option<GrpcDeliverySender>,
+    connection_metrics: ConnectionMetrics,
+    connected: AtomicBool,
+    logs: Arc<Logs>,
+}
+
+impl GrpcConnectionData {
+    pub fn new(
+        name: Option<String>,
+        version: Option<String>,
+        addr: Option<SocketAddr>,
+        publisher_key: Option<String>,
+        sender: Option<GrpcDeliverySender>,
+        logs: Arc<Logs>,
+    ) -> Self {
+        Self {
+            name,
+            version,
+            addr,
+            connected_moment: DateTimeAsMicroseconds::now(),
+            publisher_key,
+            last_incoming: AtomicDateTimeAsMicroseconds::now(),
+            sender,
+            connection_metrics: ConnectionMetrics::new(),
+            connected: AtomicBool::new(true),
+            logs,
+        }
+    }
+
+    pub fn get_messages_to_deliver_protocol_version(&self) -> PacketProtVer {
+        PacketProtVer {
+            protocol_version: 3,
+            packet_version: 1,
+        }
+    }
+
+    pub async fn send(&self, tcp_contract: TcpContract) {
+        if !self.connected.load(Ordering::SeqCst) {
+            return;
+        }
+
+        let sender = match &self.sender {
+            Some(sender) => sender,
+            None => return,
+        };
+
+        let tcp_contract = if let TcpContract::Raw(payload) = &tcp_contract {
+            self.connection_metrics.add_written(payload.len());
+
+            let version = self.get_messages_to_deliver_protocol_version();
+            my_service_bus_tcp_shared::tcp_serializers::convert_from_raw(tcp_contract, &version)
+                .await
+        } else {
+            tcp_contract
+        };
+
+        match crate::grpc::service_mappers::to_subscribe_grpc_response(tcp_contract) {
+            Ok(response) => {
+                let _ = sender.send(response);
+            }
+            Err(contract_name) => {
+                self.logs.add_error(
+                    None,
+                    SystemProcess::DeliveryOperation,
+                    "Grpc Send".to_string(),
+                    format!("{} packet has no gRPC counterpart. Skipped", contract_name),
+                    Some(format!("Name:{}", self.name.as_deref().unwrap_or_default())),
+                );
+            }
+        }
+    }
+
+    pub fn update_read_amount(&self, amount: usize) {
+        self.connection_metrics.add_read(amount);
+        self.last_incoming.update(DateTimeAsMicroseconds::now());
+    }
+
+    pub fn get_last_incoming_moment(&self) -> DateTimeAsMicroseconds {
+        self.last_incoming.as_date_time()
+    }
+
+    pub fn is_connected(&self) -> bool {
+        self.connected.load(Ordering::SeqCst)
+    }
+
+    pub fn get_connection_metrics(&self) -> ConnectionMetricsSnapshot {
+        self.connection_metrics.get_snapshot()
+    }
+
+    pub fn one_second_tick(&self) {
+        self.connection_metrics.one_second_tick();
+    }
+
+    pub fn disconnect(&self) -> bool {
+        let result = self.connected.swap(false, Ordering::SeqCst);
+
+        if result {
+            if let Some(sender) = &self.sender {
+                let _ = sender.send(Err(tonic::Status::cancelled("Session is disconnected")));
+            }
+        }
+
+        result
+    }
+}
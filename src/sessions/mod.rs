@@ -1,20 +1,30 @@
+mod amqp_connection_data;
 mod connection_metrics;
+mod grpc_connection_data;
 mod http_connection_data;
+mod mqtt_connection_data;
 mod my_sb_session;
 mod session_connection;
+mod session_statistics;
 mod sessions_list;
 mod sessions_list_data;
 mod tcp_connection_data;
 #[cfg(test)]
 mod test_connection_data;
+mod web_socket_connection_data;
 pub use my_sb_session::MyServiceBusSession;
 
 pub use sessions_list::{SessionId, SessionsList};
 
+pub use amqp_connection_data::AmqpConnectionData;
 pub use connection_metrics::{ConnectionMetrics, ConnectionMetricsSnapshot};
+pub use grpc_connection_data::{GrpcConnectionData, GrpcDeliverySender};
 pub use http_connection_data::HttpConnectionData;
+pub use mqtt_connection_data::MqttConnectionData;
 pub use session_connection::SessionConnection;
+pub use session_statistics::{SessionStatistics, SessionStatisticsSnapshot};
 pub use tcp_connection_data::TcpConnectionData;
+pub use web_socket_connection_data::WebSocketConnectionData;
 
 #[cfg(test)]
 pub use test_connection_data::TestConnectionData;
@@ -0,0 +1,156 @@
+use std::{
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+};
+
+use my_service_bus_tcp_shared::{PacketProtVer, TcpContract};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    mqtt::{MqttConfirmation, MqttDeliveries},
+    sessions::{ConnectionMetrics, ConnectionMetricsSnapshot},
+};
+
+pub struct MqttConnectionData {
+    pub client_id: String,
+    pub protocol_level: u8,
+    pub addr: SocketAddr,
+    pub deliveries: Mutex<MqttDeliveries>,
+    sender: UnboundedSender<Vec<u8>>,
+    confirmations: UnboundedSender<MqttConfirmation>,
+    connection_metrics: ConnectionMetrics,
+    connected: AtomicBool,
+}
+
+impl MqttConnectionData {
+    pub fn new(
+        client_id: String,
+        protocol_level: u8,
+        addr: SocketAddr,
+        sender: UnboundedSender<Vec<u8>>,
+        confirmations: UnboundedSender<MqttConfirmation>,
+    ) -> Self {
+        Self {
+            client_id,
+            protocol_level,
+            addr,
+            deliveries: Mutex::new(MqttDeliveries::new()),
+            sender,
+            confirmations,
+            connection_metrics: ConnectionMetrics::new(),
+            connected: AtomicBool::new(true),
+        }
+    }
+
+    pub fn get_messages_to_deliver_protocol_version(&self) -> PacketProtVer {
+        PacketProtVer {
+            protocol_version: 3,
+            packet_version: 1,
+        }
+    }
+
+    pub fn send_payload(&self, payload: Vec<u8>) {
+        if !self.connected.load(Ordering::SeqCst) {
+            return;
+        }
+
+        self.connection_metrics.add_written(payload.len());
+        let _ = self.sender.send(payload);
+    }
+
+    pub async fn send(&self, tcp_contract: TcpContract) {
+        let payload = match tcp_contract {
+            TcpContract::Raw(payload) => payload,
+            _ => return,
+        };
+
+        let version = self.get_messages_to_deliver_protocol_version();
+        let tcp_contract =
+            my_service_bus_tcp_shared::tcp_serializers::convert_from_raw(payload, &version).await;
+
+        if let TcpContract::NewMessages {
+            topic_id,
+            queue_id,
+            confirmation_id,
+            messages,
+        } = tcp_contract
+        {
+            let mut payload = Vec::new();
+
+            let qos = {
+                let mut deliveries = self.deliveries.lock().unwrap();
+
+                let subscription = match deliveries.get_subscription(&topic_id) {
+                    Some(subscription) if subscription.queue_id == queue_id => subscription.clone(),
+                    _ => return,
+                };
+
+                let packet_ids = match deliveries.register_delivery(
+                    &subscription,
+                    confirmation_id,
+                    messages.len(),
+                ) {
+                    Some(packet_ids) => packet_ids,
+                    None => {
+                        let _ = self.confirmations.send(MqttConfirmation {
+                            topic_id,
+                            queue_id,
+                            confirmation_id,
+                            delivered: false,
+                        });
+                        return;
+                    }
+                };
+
+                for (message, packet_id) in messages.iter().zip(packet_ids) {
+                    crate::mqtt::serialize_publish(
+                        &mut payload,
+                        topic_id.as_str(),
+                        subscription.qos,
+                        packet_id,
+                        message.attempt_no > 0,
+                        &message.content,
+                    );
+                }
+
+                subscription.qos
+            };
+
+            self.send_payload(payload);
+
+            if qos == 0 {
+                let _ = self.confirmations.send(MqttConfirmation {
+                    topic_id,
+                    queue_id,
+                    confirmation_id,
+                    delivered: true,
+                });
+            }
+        }
+    }
+
+    pub fn update_read_amount(&self, amount: usize) {
+        self.connection_metrics.add_read(amount);
+    }
+
+    pub fn get_connection_metrics(&self) -> ConnectionMetricsSnapshot {
+        self.connection_metrics.get_snapshot()
+    }
+
+    pub fn one_second_tick(&self) {
+        self.connection_metrics.one_second_tick();
+    }
+
+    pub fn disconnect(&self) -> bool {
+        let result = self.connected.swap(false, Ordering::SeqCst);
+
+        if result {
+            let _ = self.sender.send(Vec::new());
+        }
+
+        result
+    }
+}
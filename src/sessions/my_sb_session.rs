@@ -1,7 +1,12 @@
-use my_service_bus_tcp_shared::PacketProtVer;
+use std::sync::Mutex;
+
+use my_service_bus_tcp_shared::{PacketProtVer, TcpContract};
 use rust_extensions::date_time::DateTimeAsMicroseconds;
 
-use super::{ConnectionMetricsSnapshot, SessionConnection, SessionId};
+use super::{ConnectionMetricsSnapshot, SessionConnection, SessionId, SessionStatistics};
+
+const UNKNOWN_SESSION_NAME: &str = "unknown";
+
 pub struct SessionMetrics {
     pub name: Option<String>,
     pub version: Option<String>,
@@ -15,48 +20,104 @@ pub struct MyServiceBusSession {
     pub id: SessionId,
     pub connection: SessionConnection,
     pub connected: DateTimeAsMicroseconds,
+    pub statistics: SessionStatistics,
+    metrics_name: Mutex<String>,
 }
 
 impl MyServiceBusSession {
     pub fn new(id: SessionId, connection: SessionConnection) -> Self {
+        let metrics_name = get_initial_metrics_name(&connection);
+
         Self {
             connection,
             id,
             connected: DateTimeAsMicroseconds::now(),
+            statistics: SessionStatistics::new(),
+            metrics_name: Mutex::new(metrics_name),
         }
     }
 
+    ///Name is used as a label of prometheus metrics. It is available without awaiting the connection attributes
+    pub fn get_metrics_name(&self) -> String {
+        self.metrics_name.lock().unwrap().clone()
+    }
+
     pub async fn set_tcp_socket_name(
         &self,
         set_socket_name: String,
         client_version: Option<String>,
     ) {
-        if let SessionConnection::Tcp(data) = &self.connection {
-            data.set_socket_name(set_socket_name, client_version).await;
-        } else {
-            panic!("Something went wrong. You re trying to set socket name for tcp session. But session has type: {}", self.connection.get_connection_type())
+        *self.metrics_name.lock().unwrap() = set_socket_name.clone();
+
+        match &self.connection {
+            SessionConnection::Tcp(data) => {
+                data.set_socket_name(set_socket_name, client_version).await;
+            }
+            SessionConnection::WebSocket(data) => {
+                data.set_socket_name(set_socket_name, client_version).await;
+            }
+            _ => {
+                panic!("Something went wrong. You re trying to set socket name for tcp session. But session has type: {}", self.connection.get_connection_type())
+            }
         }
     }
 
     pub fn update_tcp_protocol_version(&self, value: i32) {
-        if let SessionConnection::Tcp(connection_data) = &self.connection {
-            connection_data.update_protocol_version(value);
-        } else {
-            panic!(
-                "Invalid connection type  [{}] to update Tcp protocol version",
-                self.connection.get_connection_type()
-            );
+        match &self.connection {
+            SessionConnection::Tcp(connection_data) => {
+                connection_data.update_protocol_version(value);
+            }
+            SessionConnection::WebSocket(connection_data) => {
+                connection_data.update_protocol_version(value);
+            }
+            _ => {
+                panic!(
+                    "Invalid connection type  [{}] to update Tcp protocol version",
+                    self.connection.get_connection_type()
+                );
+            }
         }
     }
 
     pub fn update_tcp_delivery_packet_version(&self, value: i32) {
-        if let SessionConnection::Tcp(connection_data) = &self.connection {
-            connection_data.update_deliver_message_packet_version(value);
-        } else {
-            panic!(
-                "Invalid connection type  [{}] to update Tcp delivery packet version",
-                self.connection.get_connection_type()
-            );
+        match &self.connection {
+            SessionConnection::Tcp(connection_data) => {
+                connection_data.update_deliver_message_packet_version(value);
+            }
+            SessionConnection::WebSocket(connection_data) => {
+                connection_data.update_deliver_message_packet_version(value);
+            }
+            _ => {
+                panic!(
+                    "Invalid connection type  [{}] to update Tcp delivery packet version",
+                    self.connection.get_connection_type()
+                );
+            }
+        }
+    }
+
+    pub async fn send(&self, tcp_contract: TcpContract) {
+        match &self.connection {
+            SessionConnection::Tcp(data) => {
+                data.connection.send(tcp_contract).await;
+            }
+            SessionConnection::WebSocket(data) => {
+                data.send(tcp_contract);
+            }
+            SessionConnection::Grpc(data) => {
+                data.send(tcp_contract).await;
+            }
+            SessionConnection::Amqp(data) => {
+                data.send(tcp_contract).await;
+            }
+            SessionConnection::Mqtt(data) => {
+                data.send(tcp_contract).await;
+            }
+            #[cfg(test)]
+            SessionConnection::Test(data) => {
+                data.send_packet(tcp_contract).await;
+            }
+            SessionConnection::Http(_) => todo!("Not suppored yet"),
         }
     }
 
@@ -69,6 +130,13 @@ impl MyServiceBusSession {
             SessionConnection::Http(data) => {
                 (Some(data.name.to_string()), Some(data.version.to_string()))
             }
+            SessionConnection::WebSocket(data) => {
+                let attr = data.get_attrs().await;
+                (attr.name, attr.version)
+            }
+            SessionConnection::Grpc(data) => (data.name.clone(), data.version.clone()),
+            SessionConnection::Amqp(data) => (data.name.clone(), data.version.clone()),
+            SessionConnection::Mqtt(data) => (Some(data.client_id.to_string()), None),
             #[cfg(test)]
             SessionConnection::Test(data) => (data.name.clone(), data.version.clone()),
         }
@@ -78,6 +146,12 @@ impl MyServiceBusSession {
         match &self.connection {
             SessionConnection::Tcp(data) => format!("Tcp: {}", data.get_protocol_version()),
             SessionConnection::Http(_) => "Http".to_string(),
+            SessionConnection::WebSocket(data) => {
+                format!("WebSocket: {}", data.get_protocol_version())
+            }
+            SessionConnection::Grpc(_) => "Grpc".to_string(),
+            SessionConnection::Amqp(_) => "Amqp 0-9-1".to_string(),
+            SessionConnection::Mqtt(data) => format!("Mqtt: {}", data.protocol_level),
             #[cfg(test)]
             SessionConnection::Test(_) => "Test".to_string(),
         }
@@ -89,6 +163,10 @@ impl MyServiceBusSession {
             SessionConnection::Http(_) => {
                 panic!("Protocol version is not applicable for HTTP Protocol")
             }
+            SessionConnection::WebSocket(data) => data.get_messages_to_deliver_protocol_version(),
+            SessionConnection::Grpc(data) => data.get_messages_to_deliver_protocol_version(),
+            SessionConnection::Amqp(data) => data.get_messages_to_deliver_protocol_version(),
+            SessionConnection::Mqtt(data) => data.get_messages_to_deliver_protocol_version(),
             #[cfg(test)]
             SessionConnection::Test(_) => PacketProtVer {
                 protocol_version: 3,
@@ -101,6 +179,10 @@ impl MyServiceBusSession {
         let connection_metrics = match &self.connection {
             SessionConnection::Tcp(data) => data.get_connection_metrics(),
             SessionConnection::Http(data) => data.get_connection_metrics(),
+            SessionConnection::WebSocket(data) => data.get_connection_metrics(),
+            SessionConnection::Grpc(data) => data.get_connection_metrics(),
+            SessionConnection::Amqp(data) => data.get_connection_metrics(),
+            SessionConnection::Mqtt(data) => data.get_connection_metrics(),
             #[cfg(test)]
             SessionConnection::Test(_) => {
                 panic!("We do not have metrics in test enviroment");
@@ -129,6 +211,18 @@ impl MyServiceBusSession {
             SessionConnection::Http(data) => {
                 return data.disconnect();
             }
+            SessionConnection::WebSocket(data) => {
+                return data.disconnect();
+            }
+            SessionConnection::Grpc(data) => {
+                return data.disconnect();
+            }
+            SessionConnection::Amqp(data) => {
+                return data.disconnect();
+            }
+            SessionConnection::Mqtt(data) => {
+                return data.disconnect();
+            }
             #[cfg(test)]
             SessionConnection::Test(connection) => {
                 let result = connection
@@ -148,3 +242,18 @@ impl MyServiceBusSession {
         }
     }
 }
+
+fn get_initial_metrics_name(connection: &SessionConnection) -> String {
+    let name = match connection {
+        SessionConnection::Tcp(_) => None,
+        SessionConnection::WebSocket(_) => None,
+        SessionConnection::Http(data) => Some(data.name.to_string()),
+        SessionConnection::Grpc(data) => data.name.clone(),
+        SessionConnection::Amqp(data) => data.name.clone(),
+        SessionConnection::Mqtt(data) => Some(data.client_id.to_string()),
+        #[cfg(test)]
+        SessionConnection::Test(data) => data.name.clone(),
+    };
+
+    name.unwrap_or_else(|| UNKNOWN_SESSION_NAME.to_string())
+}
@@ -1,11 +1,18 @@
 #[cfg(test)]
 use std::sync::Arc;
 
-use super::{HttpConnectionData, TcpConnectionData};
+use super::{
+    AmqpConnectionData, GrpcConnectionData, HttpConnectionData, MqttConnectionData,
+    TcpConnectionData, WebSocketConnectionData,
+};
 
 pub enum SessionConnection {
     Tcp(TcpConnectionData),
     Http(HttpConnectionData),
+    WebSocket(WebSocketConnectionData),
+    Grpc(GrpcConnectionData),
+    Amqp(AmqpConnectionData),
+    Mqtt(MqttConnectionData),
 
     #[cfg(test)]
     Test(Arc<super::TestConnectionData>),
@@ -23,6 +30,28 @@ impl SessionConnection {
         );
     }
 
+    pub fn unwrap_as_amqp(&self) -> &AmqpConnectionData {
+        if let SessionConnection::Amqp(data) = self {
+            return data;
+        }
+
+        panic!(
+            "You are trying to get session as Amqp type, but session has [{}] type",
+            self.get_connection_type()
+        );
+    }
+
+    pub fn unwrap_as_mqtt(&self) -> &MqttConnectionData {
+        if let SessionConnection::Mqtt(data) = self {
+            return data;
+        }
+
+        panic!(
+            "You are trying to get session as Mqtt type, but session has [{}] type",
+            self.get_connection_type()
+        );
+    }
+
     #[cfg(test)]
     pub fn unwrap_as_test(&self) -> Arc<super::TestConnectionData> {
         if let SessionConnection::Test(data) = self {
@@ -49,6 +78,13 @@ impl SessionConnection {
                 None => "N/A".to_string(),
             },
             SessionConnection::Http(data) => data.ip.to_string(),
+            SessionConnection::WebSocket(data) => data.addr.to_string(),
+            SessionConnection::Grpc(data) => match &data.addr {
+                Some(addr) => addr.to_string(),
+                None => "N/A".to_string(),
+            },
+            SessionConnection::Amqp(data) => data.addr.to_string(),
+            SessionConnection::Mqtt(data) => data.addr.to_string(),
             #[cfg(test)]
             SessionConnection::Test(connection) => connection.ip.to_string(),
         }
@@ -58,6 +94,10 @@ impl SessionConnection {
         match self {
             SessionConnection::Tcp(_) => "Tcp",
             SessionConnection::Http(_) => "Http",
+            SessionConnection::WebSocket(_) => "WebSocket",
+            SessionConnection::Grpc(_) => "Grpc",
+            SessionConnection::Amqp(_) => "Amqp",
+            SessionConnection::Mqtt(_) => "Mqtt",
             #[cfg(test)]
             SessionConnection::Test(_) => "Test",
         }
@@ -0,0 +1,111 @@
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Mutex,
+    },
+    time::Duration,
+};
+
+#[derive(Debug, Clone, Default)]
+pub struct SessionStatisticsSnapshot {
+    pub packets: BTreeMap<&'static str, usize>,
+    pub published: usize,
+    pub rejects: usize,
+    pub delivered: usize,
+    pub confirmations: usize,
+    pub confirmed: usize,
+    pub confirmation_microseconds: u64,
+}
+
+impl SessionStatisticsSnapshot {
+    pub fn get_avg_confirmation_microseconds(&self) -> u64 {
+        if self.confirmations == 0 {
+            return 0;
+        }
+
+        self.confirmation_microseconds / self.confirmations as u64
+    }
+}
+
+pub struct SessionStatistics {
+    packets: Mutex<BTreeMap<&'static str, usize>>,
+    published: AtomicUsize,
+    rejects: AtomicUsize,
+    delivered: AtomicUsize,
+    confirmations: AtomicUsize,
+    confirmed: AtomicUsize,
+    confirmation_microseconds: AtomicU64,
+}
+
+impl SessionStatistics {
+    pub fn new() -> Self {
+        Self {
+            packets: Mutex::new(BTreeMap::new()),
+            published: AtomicUsize::new(0),
+            rejects: AtomicUsize::new(0),
+            delivered: AtomicUsize::new(0),
+            confirmations: AtomicUsize::new(0),
+            confirmed: AtomicUsize::new(0),
+            confirmation_microseconds: AtomicU64::new(0),
+        }
+    }
+
+    pub fn packet_is_received(&self, packet: &'static str) {
+        let mut packets = self.packets.lock().unwrap();
+        *packets.entry(packet).or_default() += 1;
+    }
+
+    pub fn messages_are_published(&self, amount: usize) {
+        self.published.fetch_add(amount, Ordering::SeqCst);
+    }
+
+    pub fn reject_is_sent(&self) {
+        self.rejects.fetch_add(1, Ordering::SeqCst);
+    }
+
+    pub fn messages_are_delivered(&self, amount: usize) {
+        self.delivered.fetch_add(amount, Ordering::SeqCst);
+    }
+
+    pub fn confirmation_is_received(&self, confirmed: usize, latency: Duration) {
+        self.confirmations.fetch_add(1, Ordering::SeqCst);
+        self.confirmed.fetch_add(confirmed, Ordering::SeqCst);
+        self.confirmation_microseconds
+            .fetch_add(latency.as_micros() as u64, Ordering::SeqCst);
+    }
+
+    pub fn get_snapshot(&self) -> SessionStatisticsSnapshot {
+        SessionStatisticsSnapshot {
+            packets: self.packets.lock().unwrap().clone(),
+            published: self.published.load(Ordering::SeqCst),
+            rejects: self.rejects.load(Ordering::SeqCst),
+            delivered: self.delivered.load(Ordering::SeqCst),
+            confirmations: self.confirmations.load(Ordering::SeqCst),
+            confirmed: self.confirmed.load(Ordering::SeqCst),
+            confirmation_microseconds: self.confirmation_microseconds.load(Ordering::SeqCst),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_keeps_average_confirmation_latency() {
+        let statistics = SessionStatistics::new();
+        statistics.packet_is_received("Publish");
+        statistics.packet_is_received("Publish");
+        statistics.packet_is_received("Ping");
+        statistics.confirmation_is_received(10, Duration::from_micros(100));
+        statistics.confirmation_is_received(5, Duration::from_micros(300));
+
+        let result = statistics.get_snapshot();
+
+        assert_eq!(Some(&2), result.packets.get("Publish"));
+        assert_eq!(Some(&1), result.packets.get("Ping"));
+        assert_eq!(15, result.confirmed);
+        assert_eq!(200, result.get_avg_confirmation_microseconds());
+    }
+}
@@ -4,8 +4,9 @@ use my_tcp_sockets::ConnectionId;
 use tokio::sync::RwLock;
 
 use super::{
-    sessions_list_data::SessionsListData, HttpConnectionData, MyServiceBusSession,
-    SessionConnection, TcpConnectionData,
+    sessions_list_data::SessionsListData, AmqpConnectionData, GrpcConnectionData,
+    HttpConnectionData, MqttConnectionData, MyServiceBusSession, SessionConnection,
+    TcpConnectionData, WebSocketConnectionData,
 };
 
 pub type SessionId = i64;
@@ -43,6 +44,89 @@ impl SessionsList {
         write_access.add(Arc::new(session));
     }
 
+    pub async fn add_web_socket(&self, data: WebSocketConnectionData) -> Arc<MyServiceBusSession> {
+        let mut write_access = self.data.write().await;
+
+        let session = MyServiceBusSession::new(
+            write_access.get_next_session_id(),
+            SessionConnection::WebSocket(data),
+        );
+
+        let session = Arc::new(session);
+
+        write_access.add(session.clone());
+
+        session
+    }
+
+    pub async fn add_grpc(&self, data: GrpcConnectionData) -> Arc<MyServiceBusSession> {
+        let mut write_access = self.data.write().await;
+
+        let session = MyServiceBusSession::new(
+            write_access.get_next_session_id(),
+            SessionConnection::Grpc(data),
+        );
+
+        let session = Arc::new(session);
+
+        write_access.add(session.clone());
+
+        session
+    }
+
+    pub async fn get_or_add_grpc_publisher(
+        &self,
+        publisher_key: &str,
+        create_data: impl FnOnce() -> GrpcConnectionData,
+    ) -> Arc<MyServiceBusSession> {
+        let mut write_access = self.data.write().await;
+
+        if let Some(session) = write_access.get_grpc_publisher(publisher_key) {
+            return session;
+        }
+
+        let session = MyServiceBusSession::new(
+            write_access.get_next_session_id(),
+            SessionConnection::Grpc(create_data()),
+        );
+
+        let session = Arc::new(session);
+
+        write_access.add(session.clone());
+
+        session
+    }
+
+    pub async fn add_amqp(&self, data: AmqpConnectionData) -> Arc<MyServiceBusSession> {
+        let mut write_access = self.data.write().await;
+
+        let session = MyServiceBusSession::new(
+            write_access.get_next_session_id(),
+            SessionConnection::Amqp(data),
+        );
+
+        let session = Arc::new(session);
+
+        write_access.add(session.clone());
+
+        session
+    }
+
+    pub async fn add_mqtt(&self, data: MqttConnectionData) -> Arc<MyServiceBusSession> {
+        let mut write_access = self.data.write().await;
+
+        let session = MyServiceBusSession::new(
+            write_access.get_next_session_id(),
+            SessionConnection::Mqtt(data),
+        );
+
+        let session = Arc::new(session);
+
+        write_access.add(session.clone());
+
+        session
+    }
+
     #[cfg(test)]
     pub async fn add_test(&self, data: super::TestConnectionData) -> Arc<MyServiceBusSession> {
         let mut write_access = self.data.write().await;
@@ -64,14 +148,6 @@ impl SessionsList {
         read_access.get_by_http_session(session_id)
     }
 
-    pub async fn resolve_session_id_by_tcp_connection_id(
-        &self,
-        connection_id: ConnectionId,
-    ) -> Option<SessionId> {
-        let read_access = self.data.read().await;
-        read_access.get_session_id_by_tcp_connection(connection_id)
-    }
-
     pub async fn get(&self, id: SessionId) -> Option<Arc<MyServiceBusSession>> {
         let read_access = self.data.read().await;
         read_access.get(id)
@@ -90,6 +166,26 @@ impl SessionsList {
         write_access.remove_tcp(id)
     }
 
+    pub async fn remove_web_socket(&self, id: SessionId) -> Option<Arc<MyServiceBusSession>> {
+        let mut write_access = self.data.write().await;
+        write_access.remove_web_socket(id)
+    }
+
+    pub async fn remove_grpc(&self, id: SessionId) -> Option<Arc<MyServiceBusSession>> {
+        let mut write_access = self.data.write().await;
+        write_access.remove_grpc(id)
+    }
+
+    pub async fn remove_amqp(&self, id: SessionId) -> Option<Arc<MyServiceBusSession>> {
+        let mut write_access = self.data.write().await;
+        write_access.remove_amqp(id)
+    }
+
+    pub async fn remove_mqtt(&self, id: SessionId) -> Option<Arc<MyServiceBusSession>> {
+        let mut write_access = self.data.write().await;
+        write_access.remove_mqtt(id)
+    }
+
     pub async fn get_snapshot(&self) -> (usize, Vec<Arc<MyServiceBusSession>>) {
         let read_access = self.data.read().await;
         read_access.get_snapshot()
@@ -115,4 +211,20 @@ impl SessionsList {
 
         result
     }
+
+    pub async fn remove_and_disconnect_expired_grpc_publishers(
+        &self,
+        inactive_timeout: Duration,
+    ) -> Option<Vec<Arc<MyServiceBusSession>>> {
+        let mut write_access = self.data.write().await;
+        let result = write_access.remove_expired_grpc_publishers(inactive_timeout);
+
+        if let Some(sessions) = &result {
+            for session in sessions {
+                session.disconnect().await;
+            }
+        }
+
+        result
+    }
 }
@@ -10,6 +10,10 @@ pub struct SessionsListData {
     sessions: HashMap<SessionId, Arc<MyServiceBusSession>>,
     tcp_sessions: HashMap<ConnectionId, Arc<MyServiceBusSession>>,
     http_sessions: HashMap<String, Arc<MyServiceBusSession>>,
+    web_socket_sessions: HashMap<SessionId, Arc<MyServiceBusSession>>,
+    grpc_sessions: HashMap<SessionId, Arc<MyServiceBusSession>>,
+    amqp_sessions: HashMap<SessionId, Arc<MyServiceBusSession>>,
+    mqtt_sessions: HashMap<SessionId, Arc<MyServiceBusSession>>,
     #[cfg(test)]
     test_sessions: HashMap<SessionId, Arc<MyServiceBusSession>>,
     current_session_id: SessionId,
@@ -25,6 +29,10 @@ impl SessionsListData {
             #[cfg(test)]
             test_sessions: HashMap::new(),
             http_sessions: HashMap::new(),
+            web_socket_sessions: HashMap::new(),
+            grpc_sessions: HashMap::new(),
+            amqp_sessions: HashMap::new(),
+            mqtt_sessions: HashMap::new(),
         }
     }
     pub fn get_next_session_id(&mut self) -> SessionId {
@@ -44,6 +52,18 @@ impl SessionsListData {
             super::SessionConnection::Http(data) => {
                 self.http_sessions.insert(data.id.to_string(), session);
             }
+            super::SessionConnection::WebSocket(_) => {
+                self.web_socket_sessions.insert(session.id, session);
+            }
+            super::SessionConnection::Grpc(_) => {
+                self.grpc_sessions.insert(session.id, session);
+            }
+            super::SessionConnection::Amqp(_) => {
+                self.amqp_sessions.insert(session.id, session);
+            }
+            super::SessionConnection::Mqtt(_) => {
+                self.mqtt_sessions.insert(session.id, session);
+            }
             #[cfg(test)]
             super::SessionConnection::Test(connection) => {
                 self.test_sessions.insert(connection.id, session);
@@ -69,14 +89,6 @@ impl SessionsListData {
         Some(result.clone())
     }
 
-    pub fn get_session_id_by_tcp_connection(
-        &self,
-        connection_id: ConnectionId,
-    ) -> Option<SessionId> {
-        let result = self.tcp_sessions.get(&connection_id)?;
-        Some(result.id)
-    }
-
     fn remove(&mut self, session_id: SessionId) -> Option<Arc<MyServiceBusSession>> {
         let removed_session = self.sessions.remove(&session_id);
 
@@ -89,6 +101,18 @@ impl SessionsListData {
                 super::SessionConnection::Http(data) => {
                     self.http_sessions.remove(&data.id);
                 }
+                super::SessionConnection::WebSocket(_) => {
+                    self.web_socket_sessions.remove(&session.id);
+                }
+                super::SessionConnection::Grpc(_) => {
+                    self.grpc_sessions.remove(&session.id);
+                }
+                super::SessionConnection::Amqp(_) => {
+                    self.amqp_sessions.remove(&session.id);
+                }
+                super::SessionConnection::Mqtt(_) => {
+                    self.mqtt_sessions.remove(&session.id);
+                }
                 #[cfg(test)]
                 super::SessionConnection::Test(connection) => {
                     self.test_sessions.remove(&connection.id);
@@ -100,6 +124,18 @@ impl SessionsListData {
         }
     }
 
+    pub fn get_grpc_publisher(&self, publisher_key: &str) -> Option<Arc<MyServiceBusSession>> {
+        for session in self.grpc_sessions.values() {
+            if let SessionConnection::Grpc(data) = &session.connection {
+                if data.is_connected() && data.publisher_key.as_deref() == Some(publisher_key) {
+                    return Some(session.clone());
+                }
+            }
+        }
+
+        None
+    }
+
     pub fn remove_tcp(&mut self, connection_id: ConnectionId) -> Option<Arc<MyServiceBusSession>> {
         let session_id = self.tcp_sessions.get(&connection_id)?.id;
         return self.remove(session_id);
@@ -110,6 +146,26 @@ impl SessionsListData {
         return self.remove(session_id);
     }
 
+    pub fn remove_web_socket(&mut self, session_id: SessionId) -> Option<Arc<MyServiceBusSession>> {
+        self.web_socket_sessions.get(&session_id)?;
+        return self.remove(session_id);
+    }
+
+    pub fn remove_grpc(&mut self, session_id: SessionId) -> Option<Arc<MyServiceBusSession>> {
+        self.grpc_sessions.get(&session_id)?;
+        return self.remove(session_id);
+    }
+
+    pub fn remove_amqp(&mut self, session_id: SessionId) -> Option<Arc<MyServiceBusSession>> {
+        self.amqp_sessions.get(&session_id)?;
+        return self.remove(session_id);
+    }
+
+    pub fn remove_mqtt(&mut self, session_id: SessionId) -> Option<Arc<MyServiceBusSession>> {
+        self.mqtt_sessions.get(&session_id)?;
+        return self.remove(session_id);
+    }
+
     pub fn get_snapshot(&self) -> (usize, Vec<Arc<MyServiceBusSession>>) {
         let mut sessions_result = Vec::new();
 
@@ -126,6 +182,30 @@ impl SessionsListData {
                 data.one_second_tick();
             }
         }
+
+        for session in self.web_socket_sessions.values() {
+            if let super::SessionConnection::WebSocket(data) = &session.connection {
+                data.one_second_tick();
+            }
+        }
+
+        for session in self.grpc_sessions.values() {
+            if let super::SessionConnection::Grpc(data) = &session.connection {
+                data.one_second_tick();
+            }
+        }
+
+        for session in self.amqp_sessions.values() {
+            if let super::SessionConnection::Amqp(data) = &session.connection {
+                data.one_second_tick();
+            }
+        }
+
+        for session in self.mqtt_sessions.values() {
+            if let super::SessionConnection::Mqtt(data) = &session.connection {
+                data.one_second_tick();
+            }
+        }
     }
 
     pub fn remove_and_disconnect_expired_http_sessions(
@@ -160,4 +240,38 @@ impl SessionsListData {
 
         return sessions_result;
     }
+
+    pub fn remove_expired_grpc_publishers(
+        &mut self,
+        inactive_timeout: Duration,
+    ) -> Option<Vec<Arc<MyServiceBusSession>>> {
+        let now = DateTimeAsMicroseconds::now();
+
+        let expired: Vec<SessionId> = self
+            .grpc_sessions
+            .values()
+            .filter(|session| match &session.connection {
+                SessionConnection::Grpc(data) => {
+                    data.publisher_key.is_some()
+                        && now
+                            .duration_since(data.get_last_incoming_moment())
+                            .as_positive_or_zero()
+                            > inactive_timeout
+                }
+                _ => false,
+            })
+            .map(|session| session.id)
+            .collect();
+
+        if expired.is_empty() {
+            return None;
+        }
+
+        Some(
+            expired
+                .into_iter()
+                .filter_map(|session_id| self.remove_grpc(session_id))
+                .collect(),
+        )
+    }
 }
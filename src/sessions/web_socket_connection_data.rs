@@ -0,0 +1,136 @@
+use std::{
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicBool, AtomicI32, Ordering},
+        Mutex,
+    },
+};
+
+use my_service_bus_tcp_shared::{
+    ConnectionAttributes, MySbTcpSerializer, PacketProtVer, TcpContract,
+};
+use my_tcp_sockets::TcpSocketSerializer;
+use rust_extensions::date_time::DateTimeAsMicroseconds;
+use tokio::sync::{mpsc::UnboundedSender, Notify, RwLock};
+use tokio_tungstenite::tungstenite::Message;
+
+use crate::sessions::{ConnectionMetrics, ConnectionMetricsSnapshot};
+
+use super::tcp_connection_data::TcpConnectionAttributes;
+
+pub struct WebSocketConnectionData {
+    pub addr: SocketAddr,
+    pub connected_moment: DateTimeAsMicroseconds,
+    sender: UnboundedSender<Message>,
+    serializer: Mutex<MySbTcpSerializer>,
+    protocol_version: AtomicI32,
+    delivery_packet_version: AtomicI32,
+    attr: RwLock<TcpConnectionAttributes>,
+    connection_metrics: ConnectionMetrics,
+    connected: AtomicBool,
+    pub disconnected: Notify,
+}
+
+impl WebSocketConnectionData {
+    pub fn new(addr: SocketAddr, sender: UnboundedSender<Message>) -> Self {
+        let attr = TcpConnectionAttributes {
+            name: None,
+            version: None,
+        };
+
+        Self {
+            addr,
+            connected_moment: DateTimeAsMicroseconds::now(),
+            sender,
+            serializer: Mutex::new(MySbTcpSerializer::new(ConnectionAttributes::new(0))),
+            protocol_version: AtomicI32::new(0),
+            delivery_packet_version: AtomicI32::new(0),
+            attr: RwLock::new(attr),
+            connection_metrics: ConnectionMetrics::new(),
+            connected: AtomicBool::new(true),
+            disconnected: Notify::new(),
+        }
+    }
+
+    pub async fn set_socket_name(&self, name: String, version: Option<String>) {
+        let mut write_access = self.attr.write().await;
+
+        write_access.name = Some(name);
+        write_access.version = version;
+    }
+
+    pub async fn get_attrs(&self) -> TcpConnectionAttributes {
+        let read_access = self.attr.read().await;
+        read_access.clone()
+    }
+
+    pub fn update_protocol_version(&self, value: i32) {
+        self.protocol_version.store(value, Ordering::SeqCst);
+    }
+
+    pub fn update_deliver_message_packet_version(&self, value: i32) {
+        self.delivery_packet_version.store(value, Ordering::SeqCst);
+    }
+
+    pub fn get_protocol_version(&self) -> i32 {
+        self.protocol_version.load(Ordering::Relaxed)
+    }
+
+    pub fn get_messages_to_deliver_protocol_version(&self) -> PacketProtVer {
+        let protocol_version = self.get_protocol_version();
+        if protocol_version == 0 {
+            panic!("Protocol version is not initialized");
+        }
+        let packet_version = self.delivery_packet_version.load(Ordering::Relaxed);
+
+        PacketProtVer {
+            protocol_version,
+            packet_version,
+        }
+    }
+
+    pub fn apply_incoming_packet(&self, tcp_contract: &TcpContract) {
+        let mut serializer = self.serializer.lock().unwrap();
+        serializer.apply_packet(tcp_contract);
+    }
+
+    pub fn send_ping(&self) {
+        let _ = self.sender.send(Message::Ping(Vec::new()));
+    }
+
+    pub fn send(&self, tcp_contract: TcpContract) {
+        if !self.connected.load(Ordering::SeqCst) {
+            return;
+        }
+
+        let payload = {
+            let serializer = self.serializer.lock().unwrap();
+            serializer.serialize(tcp_contract)
+        };
+        self.connection_metrics.add_written(payload.len());
+        let _ = self.sender.send(Message::Binary(payload));
+    }
+
+    pub fn update_read_amount(&self, amount: usize) {
+        self.connection_metrics.add_read(amount);
+    }
+
+    pub fn get_connection_metrics(&self) -> ConnectionMetricsSnapshot {
+        self.connection_metrics.get_snapshot()
+    }
+
+    pub fn one_second_tick(&self) {
+        self.connection_metrics.one_second_tick();
+    }
+
+    pub fn disconnect(&self) -> bool {
+        let result = self.connected.swap(false, Ordering::SeqCst);
+
+        if result {
+            let _ = self.sender.send(Message::Close(None));
+            self.disconnected.notify_one();
+        }
+
+        result
+    }
+}
@@ -0,0 +1,535 @@
+mod overrides;
+
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    alerts::{AlertRule, AlertsSettingsJson},
+    app::logs::{LogFormat, LogLevel, LogSinksSettings},
+    persistence::{MessagesPagesRepo, TopicsAndQueuesSnapshotRepo},
+    topology::TopologyJson,
+};
+
+pub use overrides::CliArgs;
+#[cfg(test)]
+const TEST_GRPC_URL: &str = "test";
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SettingsModelJson {
+    #[serde(rename = "GrpcUrl", default = "default_grpc_url")]
+    pub persistence_grpc_url: String,
+
+    #[serde(
+        rename = "EventuallyPersistenceDelay",
+        default = "default_eventually_persistence_delay"
+    )]
+    pub eventually_persistence_delay: String,
+
+    #[serde(rename = "QueueGcTimeout", default = "default_queue_gc_timeout")]
+    pub queue_gc_timeout: String,
+
+    #[serde(rename = "DebugMode", default)]
+    pub debug_mode: bool,
+
+    #[serde(rename = "MaxDeliverySize", default = "default_max_delivery_size")]
+    pub max_delivery_size: usize,
+
+    #[serde(rename = "DeliveryTimeout")]
+    pub delivery_timeout: Option<String>,
+
+    #[serde(rename = "AutoCreateTopicOnPublish")]
+    pub auto_create_topic_on_publish: Option<bool>,
+
+    #[serde(rename = "AutoCreateTopicOnSubscribe")]
+    pub auto_create_topic_on_subscribe: Option<bool>,
+
+    #[serde(rename = "GrpcTimeoutSecs", default = "default_grpc_timeout_secs")]
+    pub grpc_timeout_secs: u64,
+
+    #[serde(
+        rename = "PersistTimerIntervalSecs",
+        default = "default_persist_timer_secs"
+    )]
+    pub persist_timer_secs: u64,
+
+    #[serde(rename = "PersistCompressed", default)]
+    pub persist_compressed: bool,
+
+    #[serde(rename = "GrpcPort")]
+    pub grpc_port: Option<u16>,
+
+    #[serde(rename = "AmqpPort")]
+    pub amqp_port: Option<u16>,
+
+    #[serde(rename = "MqttPort")]
+    pub mqtt_port: Option<u16>,
+
+    #[serde(rename = "StreamingPort")]
+    pub streaming_port: Option<u16>,
+
+    #[serde(rename = "Topology")]
+    pub topology: Option<TopologyJson>,
+
+    #[serde(rename = "TopologyFile")]
+    pub topology_file: Option<String>,
+
+    #[serde(rename = "OpenTelemetryEndpoint")]
+    pub open_telemetry_endpoint: Option<String>,
+
+    #[serde(rename = "ServiceName", default = "default_service_name")]
+    pub service_name: String,
+
+    #[serde(rename = "LogFormat", default = "default_log_format")]
+    pub log_format: String,
+
+    #[serde(rename = "LogLevel", default = "default_log_level")]
+    pub log_level: String,
+
+    #[serde(rename = "LogFile")]
+    pub log_file: Option<String>,
+
+    #[serde(rename = "LogFileMaxSizeMb", default = "default_log_file_max_size_mb")]
+    pub log_file_max_size_mb: u64,
+
+    #[serde(rename = "LogFileMaxFiles", default = "default_log_file_max_files")]
+    pub log_file_max_files: usize,
+
+    #[serde(rename = "MetricsHistoryFile")]
+    pub metrics_history_file: Option<String>,
+
+    #[serde(
+        rename = "HealthMaxPersistQueueSize",
+        default = "default_health_max_persist_queue_size"
+    )]
+    pub health_max_persist_queue_size: usize,
+
+    #[serde(rename = "AuditFile")]
+    pub audit_file: Option<String>,
+
+    #[serde(rename = "AuditTrustUserHeader", default)]
+    pub audit_trust_user_header: bool,
+
+    #[serde(rename = "Alerts")]
+    pub alerts: Option<AlertsSettingsJson>,
+}
+
+pub struct SettingsModel {
+    pub persistence_grpc_url: String,
+    pub eventually_persistence_delay: Duration,
+    pub queue_gc_timeout: Duration,
+    pub debug_mode: bool,
+
+    pub max_delivery_size: usize,
+
+    pub delivery_timeout: Option<Duration>,
+
+    pub auto_create_topic_on_publish: bool,
+    pub auto_create_topic_on_subscribe: bool,
+    pub grpc_timeout: Duration,
+    pub persist_timer_interval: Duration,
+    pub persist_compressed: bool,
+    pub grpc_port: Option<u16>,
+    pub amqp_port: Option<u16>,
+    pub mqtt_port: Option<u16>,
+    pub streaming_port: Option<u16>,
+    pub topology: Option<TopologyJson>,
+    pub topology_file: Option<String>,
+    pub open_telemetry_endpoint: Option<String>,
+    pub service_name: String,
+    pub log_sinks: LogSinksSettings,
+    pub metrics_history_file: Option<String>,
+    pub health_max_persist_queue_size: usize,
+    pub audit_file: Option<String>,
+    pub audit_trust_user_header: bool,
+    pub alert_rules: Vec<AlertRule>,
+    pub alert_webhooks: Vec<String>,
+}
+
+impl SettingsModel {
+    // Notes are logged by the caller once logs are initialized
+    pub async fn read(cli_args: &CliArgs, notes: &mut Vec<String>) -> Self {
+        match Self::try_read(cli_args, notes).await {
+            Ok(result) => result,
+            Err(err) => {
+                for note in notes.iter() {
+                    println!("{}", note);
+                }
+
+                panic!("{}", err)
+            }
+        }
+    }
+
+    // Notes describe what is read and which features are enabled
+    pub async fn try_read(cli_args: &CliArgs, notes: &mut Vec<String>) -> Result<Self, String> {
+        let filename = get_settings_filename(cli_args);
+
+        notes.extend(cli_args.notes.iter().cloned());
+        notes.push(format!("Reading settings file {}", filename));
+
+        let mut settings = match tokio::fs::read(&filename).await {
+            Ok(file_content) => serde_yaml::from_slice(&file_content)
+                .map_err(|err| format!("Can not parse settings file: {:?}", err))?,
+            Err(err) => {
+                notes.push(format!(
+                    "Can not open settings file: {}. The reason is: {:?}. Defaults are used",
+                    filename, err
+                ));
+                serde_yaml::Value::Null
+            }
+        };
+
+        overrides::apply_overrides(&mut settings, overrides::get_env_overrides(), false, notes)?;
+        overrides::apply_overrides(&mut settings, cli_args.overrides.clone(), true, notes)?;
+
+        let mut result: SettingsModelJson = serde_yaml::from_value(settings)
+            .map_err(|err| format!("Can not parse settings: {:?}", err))?;
+
+        if let Some(topology_file) = &result.topology_file {
+            notes.push(format!("Reading topology file {}", topology_file));
+
+            result.topology = Some(TopologyJson::read_from_file(topology_file.as_str()).await?);
+        }
+
+        result.into_settings(notes)
+    }
+
+    pub async fn get_modified_time(cli_args: &CliArgs) -> Option<SystemTime> {
+        let filename = get_settings_filename(cli_args);
+        let metadata = tokio::fs::metadata(filename).await.ok()?;
+        metadata.modified().ok()
+    }
+
+    #[cfg(test)]
+    pub fn create_test_settings(max_delivery_size: usize) -> Self {
+        Self {
+            persistence_grpc_url: TEST_GRPC_URL.to_string(),
+            eventually_persistence_delay: Duration::from_secs(1),
+            queue_gc_timeout: Duration::from_secs(1),
+            debug_mode: true,
+            max_delivery_size,
+            delivery_timeout: None,
+            auto_create_topic_on_publish: true,
+            auto_create_topic_on_subscribe: true,
+            grpc_timeout: Duration::from_secs(1),
+            persist_timer_interval: Duration::from_secs(1),
+            persist_compressed: false,
+            grpc_port: None,
+            amqp_port: None,
+            mqtt_port: None,
+            streaming_port: None,
+            topology: None,
+            topology_file: None,
+            open_telemetry_endpoint: None,
+            service_name: default_service_name(),
+            log_sinks: LogSinksSettings {
+                format: LogFormat::Text,
+                min_level: LogLevel::Info,
+                file: None,
+                file_max_size: default_log_file_max_size_mb() * 1024 * 1024,
+                file_max_files: default_log_file_max_files(),
+            },
+            metrics_history_file: None,
+            health_max_persist_queue_size: default_health_max_persist_queue_size(),
+            audit_file: None,
+            audit_trust_user_header: false,
+            alert_rules: vec![],
+            alert_webhooks: vec![],
+        }
+    }
+
+    pub async fn create_topics_and_queues_snapshot_repo(&self) -> TopicsAndQueuesSnapshotRepo {
+        #[cfg(test)]
+        if self.persistence_grpc_url == TEST_GRPC_URL {
+            return TopicsAndQueuesSnapshotRepo::create_mock_instance();
+        }
+
+        TopicsAndQueuesSnapshotRepo::create_production_instance(self).await
+    }
+
+    pub async fn create_messages_pages_repo(&self) -> MessagesPagesRepo {
+        #[cfg(test)]
+        if self.persistence_grpc_url == TEST_GRPC_URL {
+            return MessagesPagesRepo::create_mock_instance();
+        }
+
+        MessagesPagesRepo::create_production_instance(self).await
+    }
+}
+
+fn get_settings_filename(cli_args: &CliArgs) -> String {
+    if let Some(filename) = &cli_args.settings_file {
+        return filename.to_string();
+    }
+
+    if let Ok(filename) = std::env::var(overrides::SETTINGS_FILE_ENV_VARIABLE) {
+        return filename;
+    }
+
+    get_default_settings_filename()
+}
+
+#[cfg(target_os = "windows")]
+fn get_default_settings_filename() -> String {
+    let home_path = env!("HOME");
+    let filename = format!("{}\\{}", home_path, ".myservicebus");
+    filename
+}
+
+#[cfg(not(target_os = "windows"))]
+fn get_default_settings_filename() -> String {
+    let home_path = env!("HOME");
+    let filename = format!("{}/{}", home_path, ".myservicebus");
+    filename
+}
+
+fn default_grpc_url() -> String {
+    "http://127.0.0.1:7124".to_string()
+}
+
+fn default_eventually_persistence_delay() -> String {
+    "00:00:05".to_string()
+}
+
+fn default_queue_gc_timeout() -> String {
+    "00:00:20".to_string()
+}
+
+fn default_max_delivery_size() -> usize {
+    4194304
+}
+
+fn default_grpc_timeout_secs() -> u64 {
+    5
+}
+
+fn default_persist_timer_secs() -> u64 {
+    1
+}
+
+fn default_service_name() -> String {
+    "my-service-bus".to_string()
+}
+
+fn default_log_format() -> String {
+    "Text".to_string()
+}
+
+fn default_log_level() -> String {
+    "Error".to_string()
+}
+
+fn default_log_file_max_size_mb() -> u64 {
+    10
+}
+
+fn default_log_file_max_files() -> usize {
+    5
+}
+
+fn default_health_max_persist_queue_size() -> usize {
+    100_000
+}
+
+impl SettingsModelJson {
+    fn into_settings(self, notes: &mut Vec<String>) -> Result<SettingsModel, String> {
+        let queue_gc_timeout =
+            rust_extensions::duration_utils::parse_duration(self.queue_gc_timeout.as_str())
+                .map_err(|err| {
+                    format!(
+                        "Can not parse QueueGcTimeout value '{}'. Reason: {:?}",
+                        self.queue_gc_timeout, err
+                    )
+                })?;
+
+        let eventually_persistence_delay = rust_extensions::duration_utils::parse_duration(
+            self.eventually_persistence_delay.as_str(),
+        )
+        .map_err(|err| {
+            format!(
+                "Can not parse EventuallyPersistenceDelay value '{}'. Reason: {:?}",
+                self.eventually_persistence_delay, err
+            )
+        })?;
+
+        let delivery_timeout = if let Some(src) = self.delivery_timeout {
+            notes.push(format!("Delivery timeout is set {}", src));
+
+            let timeout_duration = rust_extensions::duration_utils::parse_duration(src.as_str());
+
+            if let Err(err) = timeout_duration {
+                return Err(format!(
+                    "Can not parse Delivery Timeout value '{}'. Reason: {:?}",
+                    src, err
+                ));
+            }
+            Some(timeout_duration.unwrap())
+        } else {
+            notes.push(
+                "Delivery timeout is disabled. To enable please specify DeliveryTimeout: hh:mm:ss"
+                    .to_string(),
+            );
+            None
+        };
+
+        let auto_create_topic_on_publish = if let Some(auto_create_topic) =
+            self.auto_create_topic_on_publish
+        {
+            if auto_create_topic {
+                notes.push("Auto create topic on publish is enabled".to_string());
+            } else {
+                notes.push("Auto create topic on publish is disabled".to_string());
+            }
+
+            auto_create_topic
+        } else {
+            notes.push("Auto create topic on publish is disabled. To enable please add parameter AutoCreateTopicOnPublish: true".to_string());
+            false
+        };
+
+        let auto_create_topic_on_subscribe = if let Some(auto_create_topic_on_subscribe) =
+            self.auto_create_topic_on_subscribe
+        {
+            if auto_create_topic_on_subscribe {
+                notes.push("Auto create topic on subscribe is enabled".to_string());
+            } else {
+                notes.push("Auto create topic on subscribe is disabled".to_string());
+            }
+
+            auto_create_topic_on_subscribe
+        } else {
+            notes.push("Auto create topic on subscribe is disabled. To enable please add parameter AutoCreateTopicOnSubscribe: true".to_string());
+            false
+        };
+
+        match self.grpc_port {
+            Some(port) => notes.push(format!("Grpc listener is enabled on port {}", port)),
+            None => notes.push(
+                "Grpc listener is disabled. To enable please add parameter GrpcPort: 6125"
+                    .to_string(),
+            ),
+        }
+
+        match self.amqp_port {
+            Some(port) => notes.push(format!("Amqp listener is enabled on port {}", port)),
+            None => notes.push(
+                "Amqp listener is disabled. To enable please add parameter AmqpPort: 5672"
+                    .to_string(),
+            ),
+        }
+
+        match self.mqtt_port {
+            Some(port) => notes.push(format!("Mqtt listener is enabled on port {}", port)),
+            None => notes.push(
+                "Mqtt listener is disabled. To enable please add parameter MqttPort: 1883"
+                    .to_string(),
+            ),
+        }
+
+        match &self.topology {
+            Some(topology) => notes.push(format!(
+                "Topology is enabled in {} mode. Topics: {}",
+                topology.validate()?.as_str(),
+                topology.topics.len()
+            )),
+            None => notes.push("Topology is disabled. To enable please add Topology section or TopologyFile parameter".to_string()),
+        }
+
+        match &self.open_telemetry_endpoint {
+            Some(endpoint) => notes.push(format!("OpenTelemetry traces are exported to {}", endpoint)),
+            None => notes.push("OpenTelemetry is disabled. To enable please add parameter OpenTelemetryEndpoint: http://127.0.0.1:4318".to_string()),
+        }
+
+        let log_format = LogFormat::parse(self.log_format.as_str()).ok_or_else(|| {
+            format!(
+                "Invalid log format '{}'. Supported formats: Text, Json",
+                self.log_format
+            )
+        })?;
+
+        let log_level = LogLevel::parse(self.log_level.as_str()).ok_or_else(|| {
+            format!(
+                "Invalid log level '{}'. Supported levels: Info, Error, FatalError",
+                self.log_level
+            )
+        })?;
+
+        if let Some(log_file) = &self.log_file {
+            notes.push(format!("Logs are written to file {}", log_file));
+        }
+
+        match &self.metrics_history_file {
+            Some(file) => notes.push(format!("Metrics history is persisted to file {}", file)),
+            None => notes.push("Metrics history is kept in memory. To persist it please add parameter MetricsHistoryFile".to_string()),
+        }
+
+        if let Some(audit_file) = &self.audit_file {
+            notes.push(format!("Audit records are written to file {}", audit_file));
+        }
+
+        if self.audit_trust_user_header {
+            notes.push("Audit identity is taken from the X-User header".to_string());
+        }
+
+        let alerts = self.alerts.unwrap_or_default();
+
+        for webhook in &alerts.webhooks {
+            if !webhook.starts_with("http://") && !webhook.starts_with("https://") {
+                return Err(format!(
+                    "Invalid alert webhook '{}'. Only http:// and https:// urls are supported",
+                    webhook
+                ));
+            }
+        }
+
+        let mut alert_rules = Vec::new();
+
+        for rule in &alerts.rules {
+            alert_rules.push(AlertRule::parse(rule)?);
+        }
+
+        if !alert_rules.is_empty() {
+            notes.push(format!(
+                "Alert rules: {}. Webhooks: {}",
+                alert_rules.len(),
+                alerts.webhooks.len()
+            ));
+        }
+
+        Ok(SettingsModel {
+            persistence_grpc_url: self.persistence_grpc_url,
+            debug_mode: self.debug_mode,
+            queue_gc_timeout,
+            eventually_persistence_delay,
+            max_delivery_size: self.max_delivery_size,
+            delivery_timeout,
+            auto_create_topic_on_publish,
+            auto_create_topic_on_subscribe,
+            grpc_timeout: Duration::from_secs(self.grpc_timeout_secs),
+            persist_timer_interval: Duration::from_secs(self.persist_timer_secs),
+            persist_compressed: self.persist_compressed,
+            grpc_port: self.grpc_port,
+            amqp_port: self.amqp_port,
+            mqtt_port: self.mqtt_port,
+            streaming_port: self.streaming_port,
+            topology: self.topology,
+            topology_file: self.topology_file,
+            open_telemetry_endpoint: self.open_telemetry_endpoint,
+            service_name: self.service_name,
+            log_sinks: LogSinksSettings {
+                format: log_format,
+                min_level: log_level,
+                file: self.log_file,
+                file_max_size: self.log_file_max_size_mb * 1024 * 1024,
+                file_max_files: self.log_file_max_files,
+            },
+            metrics_history_file: self.metrics_history_file,
+            health_max_persist_queue_size: self.health_max_persist_queue_size,
+            audit_file: self.audit_file,
+            audit_trust_user_header: self.audit_trust_user_header,
+            alert_rules,
+            alert_webhooks: alerts.webhooks,
+        })
+    }
+}
@@ -0,0 +1,248 @@
+use serde_yaml::{Mapping, Value};
+
+pub const ENV_PREFIX: &str = "MYSB_";
+pub const SETTINGS_FILE_ENV_VARIABLE: &str = "MYSB_SETTINGS_FILE";
+
+pub const SETTINGS_KEYS: [&str; 29] = [
+    "GrpcUrl",
+    "EventuallyPersistenceDelay",
+    "QueueGcTimeout",
+    "DebugMode",
+    "MaxDeliverySize",
+    "DeliveryTimeout",
+    "AutoCreateTopicOnPublish",
+    "AutoCreateTopicOnSubscribe",
+    "GrpcTimeoutSecs",
+    "PersistTimerIntervalSecs",
+    "PersistCompressed",
+    "GrpcPort",
+    "AmqpPort",
+    "MqttPort",
+    "StreamingPort",
+    "Topology",
+    "TopologyFile",
+    "OpenTelemetryEndpoint",
+    "ServiceName",
+    "LogFormat",
+    "LogLevel",
+    "LogFile",
+    "LogFileMaxSizeMb",
+    "LogFileMaxFiles",
+    "MetricsHistoryFile",
+    "HealthMaxPersistQueueSize",
+    "AuditFile",
+    "AuditTrustUserHeader",
+    "Alerts",
+];
+
+#[derive(Clone, Default)]
+pub struct CliArgs {
+    pub settings_file: Option<String>,
+    pub check_config: bool,
+    pub overrides: Vec<(String, String)>,
+    // Problems with command line arguments. Logged with the settings notes
+    pub notes: Vec<String>,
+}
+
+impl CliArgs {
+    pub fn parse() -> Self {
+        Self::parse_from(std::env::args().skip(1))
+    }
+
+    pub fn parse_from(args: impl Iterator<Item = String>) -> Self {
+        let mut result = Self {
+            settings_file: None,
+            check_config: false,
+            overrides: Vec::new(),
+            notes: Vec::new(),
+        };
+
+        let mut args = args.peekable();
+
+        while let Some(arg) = args.next() {
+            if arg == "--check-config" {
+                result.check_config = true;
+                continue;
+            }
+
+            if let Some(value) = get_arg_value("--settings", arg.as_str(), &mut args) {
+                result.settings_file = Some(value);
+                continue;
+            }
+
+            if let Some(value) = get_arg_value("--set", arg.as_str(), &mut args) {
+                if let Some((key, value)) = value.split_once('=') {
+                    result.overrides.push((key.to_string(), value.to_string()));
+                } else {
+                    result.notes.push(format!(
+                        "Invalid argument --set {}. Expected --set Key=Value",
+                        value
+                    ));
+                }
+                continue;
+            }
+
+            result
+                .notes
+                .push(format!("Unknown command line argument {}", arg));
+        }
+
+        result
+    }
+}
+
+fn get_arg_value(
+    name: &str,
+    arg: &str,
+    args: &mut std::iter::Peekable<impl Iterator<Item = String>>,
+) -> Option<String> {
+    if arg == name {
+        return args.next();
+    }
+
+    let value = arg.strip_prefix(name)?.strip_prefix('=')?;
+    Some(value.to_string())
+}
+
+pub fn get_env_overrides() -> Vec<(String, String)> {
+    let mut result = Vec::new();
+
+    for (name, value) in std::env::vars() {
+        if name == SETTINGS_FILE_ENV_VARIABLE {
+            continue;
+        }
+
+        if let Some(key) = name.strip_prefix(ENV_PREFIX) {
+            result.push((key.to_string(), value));
+        }
+    }
+
+    result
+}
+
+pub fn apply_overrides(
+    settings: &mut Value,
+    overrides: Vec<(String, String)>,
+    strict: bool,
+    notes: &mut Vec<String>,
+) -> Result<(), String> {
+    if settings.is_null() {
+        *settings = Value::Mapping(Mapping::new());
+    }
+
+    let mapping = settings
+        .as_mapping_mut()
+        .ok_or("Settings must be a yaml mapping".to_string())?;
+
+    for (key, value) in overrides {
+        let settings_key = match find_settings_key(key.as_str()) {
+            Some(settings_key) => settings_key,
+            None => {
+                if strict {
+                    return Err(format!("Unknown settings key {}", key));
+                }
+
+                notes.push(format!("Unknown settings key {} is ignored", key));
+                continue;
+            }
+        };
+
+        let value = match serde_yaml::from_str::<Value>(value.as_str()) {
+            Ok(Value::Null) | Err(_) => Value::String(value),
+            Ok(parsed) => parsed,
+        };
+
+        notes.push(format!("Settings key {} is overridden", settings_key));
+
+        mapping.insert(Value::String(settings_key.to_string()), value);
+    }
+
+    Ok(())
+}
+
+fn find_settings_key(src: &str) -> Option<&'static str> {
+    let src = normalize_key(src);
+
+    SETTINGS_KEYS
+        .iter()
+        .find(|key| normalize_key(key) == src)
+        .map(|key| *key)
+}
+
+fn normalize_key(src: &str) -> String {
+    src.chars()
+        .filter(|c| *c != '_' && *c != '-')
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_env_key_is_matched_ignoring_case_and_underscores() {
+        assert_eq!(
+            Some("MaxDeliverySize"),
+            find_settings_key("MAXDELIVERYSIZE")
+        );
+        assert_eq!(
+            Some("MaxDeliverySize"),
+            find_settings_key("MAX_DELIVERY_SIZE")
+        );
+        assert_eq!(None, find_settings_key("UNKNOWN"));
+    }
+
+    #[test]
+    fn test_cli_overrides_env_overrides_file() {
+        let mut settings: Value =
+            serde_yaml::from_str("MaxDeliverySize: 100\nDebugMode: true").unwrap();
+
+        let mut notes = Vec::new();
+
+        apply_overrides(
+            &mut settings,
+            vec![
+                ("MAXDELIVERYSIZE".to_string(), "200".to_string()),
+                ("UNKNOWN_KEY".to_string(), "1".to_string()),
+            ],
+            false,
+            &mut notes,
+        )
+        .unwrap();
+
+        let args = vec!["--set".to_string(), "MaxDeliverySize=300".to_string()];
+        let cli_args = CliArgs::parse_from(args.into_iter());
+        apply_overrides(&mut settings, cli_args.overrides, true, &mut notes).unwrap();
+
+        assert_eq!(Some(300), settings["MaxDeliverySize"].as_u64());
+        assert_eq!(Some(true), settings["DebugMode"].as_bool());
+        assert_eq!(
+            vec![
+                "Settings key MaxDeliverySize is overridden",
+                "Unknown settings key UNKNOWN_KEY is ignored",
+                "Settings key MaxDeliverySize is overridden",
+            ],
+            notes
+        );
+    }
+
+    #[test]
+    fn test_settings_keys_match_settings_model_fields() {
+        let settings: crate::settings::SettingsModelJson = serde_yaml::from_str("{}").unwrap();
+
+        let mut fields: Vec<String> = serde_yaml::to_value(&settings)
+            .unwrap()
+            .as_mapping()
+            .unwrap()
+            .iter()
+            .map(|(key, _)| key.as_str().unwrap().to_string())
+            .collect();
+        fields.sort();
+
+        let mut keys: Vec<String> = SETTINGS_KEYS.iter().map(|key| key.to_string()).collect();
+        keys.sort();
+
+        assert_eq!(fields, keys);
+    }
+}
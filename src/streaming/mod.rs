@@ -0,0 +1,6 @@
+mod server;
+mod status_events;
+mod web_socket_loop;
+
+pub use server::start;
+pub use status_events::StatusEvents;
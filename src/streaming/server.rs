@@ -0,0 +1,144 @@
+use std::{convert::Infallible, net::SocketAddr, sync::Arc};
+
+use hyper::{
+    body::Bytes,
+    header::{
+        CACHE_CONTROL, CONNECTION, CONTENT_TYPE, SEC_WEBSOCKET_ACCEPT, SEC_WEBSOCKET_KEY, UPGRADE,
+    },
+    server::conn::AddrStream,
+    service::{make_service_fn, service_fn},
+    Body, Request, Response, Server, StatusCode,
+};
+use tokio::sync::broadcast::error::RecvError;
+use tokio_tungstenite::{
+    tungstenite::{handshake::derive_accept_key, protocol::Role},
+    WebSocketStream,
+};
+
+use crate::app::{logs::SystemProcess, AppContext};
+
+// Opt-in listener for the WebSocket transport and the status stream. Controllers stay on the http server
+pub fn start(app: Arc<AppContext>, addr: SocketAddr) {
+    tokio::spawn(async move {
+        let app_for_service = app.clone();
+
+        let make_service = make_service_fn(move |conn: &AddrStream| {
+            let app = app_for_service.clone();
+            let remote_addr = conn.remote_addr();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |req| {
+                    handle_request(app.clone(), remote_addr, req)
+                }))
+            }
+        });
+
+        if let Err(err) = Server::bind(&addr).serve(make_service).await {
+            app.logs.add_fatal_error(
+                SystemProcess::TcpSocket,
+                "StreamingServer".to_string(),
+                format!("Streaming server stopped. Err: {:?}", err),
+                Some(format!("Addr:{}", addr)),
+            );
+        }
+    });
+}
+
+async fn handle_request(
+    app: Arc<AppContext>,
+    remote_addr: SocketAddr,
+    req: Request<Body>,
+) -> Result<Response<Body>, Infallible> {
+    let path = req.uri().path().to_lowercase();
+
+    let result = match path.as_str() {
+        "/ws" => upgrade_to_web_socket(app, remote_addr, req),
+        "/status/stream" => stream_status(app),
+        _ => Response::builder()
+            .status(StatusCode::NOT_FOUND)
+            .body(Body::empty())
+            .unwrap(),
+    };
+
+    Ok(result)
+}
+
+fn upgrade_to_web_socket(
+    app: Arc<AppContext>,
+    remote_addr: SocketAddr,
+    req: Request<Body>,
+) -> Response<Body> {
+    let accept_key = match req.headers().get(SEC_WEBSOCKET_KEY) {
+        Some(key) => derive_accept_key(key.as_bytes()),
+        None => {
+            return Response::builder()
+                .status(StatusCode::BAD_REQUEST)
+                .body(Body::from("Sec-WebSocket-Key header is missing"))
+                .unwrap();
+        }
+    };
+
+    tokio::spawn(async move {
+        match hyper::upgrade::on(req).await {
+            Ok(upgraded) => {
+                let web_socket =
+                    WebSocketStream::from_raw_socket(upgraded, Role::Server, None).await;
+                super::web_socket_loop::serve(app, web_socket, remote_addr).await;
+            }
+            Err(err) => {
+                app.logs.add_error(
+                    None,
+                    SystemProcess::TcpSocket,
+                    "WebSocketUpgrade".to_string(),
+                    format!("Can not upgrade connection to websocket. Err: {:?}", err),
+                    Some(format!("Addr:{}", remote_addr)),
+                );
+            }
+        }
+    });
+
+    Response::builder()
+        .status(StatusCode::SWITCHING_PROTOCOLS)
+        .header(CONNECTION, "Upgrade")
+        .header(UPGRADE, "websocket")
+        .header(SEC_WEBSOCKET_ACCEPT, accept_key)
+        .body(Body::empty())
+        .unwrap()
+}
+
+fn stream_status(app: Arc<AppContext>) -> Response<Body> {
+    let (mut body_sender, body) = Body::channel();
+
+    tokio::spawn(async move {
+        let (mut snapshot, mut receiver) = app.status_events.subscribe();
+
+        loop {
+            if let Some(snapshot) = snapshot.take() {
+                let payload = Bytes::from(snapshot.as_bytes().to_vec());
+                if body_sender.send_data(payload).await.is_err() {
+                    break;
+                }
+            }
+
+            match receiver.recv().await {
+                Ok(event) => {
+                    let payload = Bytes::from(event.as_bytes().to_vec());
+                    if body_sender.send_data(payload).await.is_err() {
+                        break;
+                    }
+                }
+                Err(RecvError::Lagged(_)) => {
+                    let (last_snapshot, new_receiver) = app.status_events.subscribe();
+                    snapshot = last_snapshot;
+                    receiver = new_receiver;
+                }
+                Err(RecvError::Closed) => break,
+            }
+        }
+    });
+
+    Response::builder()
+        .header(CONTENT_TYPE, "text/event-stream")
+        .header(CACHE_CONTROL, "no-cache")
+        .body(body)
+        .unwrap()
+}
@@ -0,0 +1,212 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, Mutex},
+};
+
+use serde::Serialize;
+use tokio::sync::broadcast::{self, Receiver, Sender};
+
+use crate::http::controllers::{
+    alerts::AlertJsonModel,
+    status::{
+        index_models::{StatusJsonResult, SystemStatusModel},
+        models::{
+            queue_model::QueuesJsonResult, session_model::SessionsJsonResult,
+            topic_model::TopicJsonContract,
+        },
+    },
+};
+
+#[derive(Serialize)]
+pub struct StatusDeltaJsonModel {
+    #[serde(rename = "topicsSnapshotId")]
+    pub topics_snapshot_id: usize,
+    pub topics: Vec<TopicJsonContract>,
+    #[serde(rename = "removedTopics")]
+    pub removed_topics: Vec<String>,
+    pub queues: HashMap<String, QueuesJsonResult>,
+    pub sessions: Option<SessionsJsonResult>,
+    pub system: SystemStatusModel,
+    #[serde(rename = "persistenceVersion")]
+    pub persistence_version: String,
+    pub alerts: Vec<AlertJsonModel>,
+}
+
+struct StatusEventsData {
+    last_snapshot: Option<Arc<String>>,
+    topics: HashMap<String, String>,
+    queues: HashMap<String, String>,
+    sessions: String,
+}
+
+pub struct StatusEvents {
+    sender: Sender<Arc<String>>,
+    data: Mutex<StatusEventsData>,
+}
+
+impl StatusEvents {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(16);
+
+        Self {
+            sender,
+            data: Mutex::new(StatusEventsData {
+                last_snapshot: None,
+                topics: HashMap::new(),
+                queues: HashMap::new(),
+                sessions: String::new(),
+            }),
+        }
+    }
+
+    pub fn has_viewers(&self) -> bool {
+        self.sender.receiver_count() > 0
+    }
+
+    pub fn subscribe(&self) -> (Option<Arc<String>>, Receiver<Arc<String>>) {
+        let data = self.data.lock().unwrap();
+        (data.last_snapshot.clone(), self.sender.subscribe())
+    }
+
+    pub fn publish(&self, status: StatusJsonResult) {
+        let snapshot = serde_json::to_string(&status).unwrap();
+
+        let mut data = self.data.lock().unwrap();
+
+        let mut delta = StatusDeltaJsonModel {
+            topics_snapshot_id: status.topics.snapshot_id,
+            topics: Vec::new(),
+            removed_topics: Vec::new(),
+            queues: HashMap::new(),
+            sessions: None,
+            system: status.system,
+            persistence_version: status.persistence_version,
+            alerts: status.alerts,
+        };
+
+        let mut topics_ids = HashSet::new();
+
+        for topic in status.topics.items {
+            topics_ids.insert(topic.id.to_string());
+
+            let json = serde_json::to_string(&topic).unwrap();
+            if data.topics.get(&topic.id) != Some(&json) {
+                data.topics.insert(topic.id.to_string(), json);
+                delta.topics.push(topic);
+            }
+        }
+
+        for (topic_id, queues) in status.queues {
+            let json = serde_json::to_string(&queues).unwrap();
+            if data.queues.get(&topic_id) != Some(&json) {
+                data.queues.insert(topic_id.to_string(), json);
+                delta.queues.insert(topic_id, queues);
+            }
+        }
+
+        let removed_topics: Vec<String> = data
+            .topics
+            .keys()
+            .filter(|topic_id| !topics_ids.contains(*topic_id))
+            .cloned()
+            .collect();
+
+        for topic_id in removed_topics {
+            data.topics.remove(&topic_id);
+            data.queues.remove(&topic_id);
+            delta.removed_topics.push(topic_id);
+        }
+
+        let sessions_json = serde_json::to_string(&status.sessions).unwrap();
+        if data.sessions != sessions_json {
+            data.sessions = sessions_json;
+            delta.sessions = Some(status.sessions);
+        }
+
+        data.last_snapshot = Some(Arc::new(compile_event("snapshot", snapshot.as_str())));
+
+        let delta = serde_json::to_string(&delta).unwrap();
+        let _ = self
+            .sender
+            .send(Arc::new(compile_event("delta", delta.as_str())));
+    }
+}
+
+fn compile_event(event: &str, data: &str) -> String {
+    format!("event: {}\ndata: {}\n\n", event, data)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        app::AppContext,
+        sessions::{SessionId, TestConnectionData},
+        settings::{CliArgs, SettingsModel},
+    };
+
+    use super::*;
+
+    fn parse_event(event: &str, name: &str) -> serde_json::Value {
+        let prefix = format!("event: {}\ndata: ", name);
+        assert!(event.starts_with(prefix.as_str()));
+        serde_json::from_str(&event[prefix.len()..]).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_status_events_publish_snapshot_and_deltas() {
+        const TOPIC_NAME: &str = "test-topic";
+        const SESSION_ID: SessionId = 13;
+
+        let settings = SettingsModel::create_test_settings(16);
+
+        let app = Arc::new(AppContext::new(&settings, CliArgs::default()).await);
+
+        let session = app
+            .sessions
+            .add_test(TestConnectionData::new(SESSION_ID, "127.0.0.1"))
+            .await;
+
+        crate::operations::publisher::create_topic_if_not_exists(
+            &app,
+            Some(session.id),
+            TOPIC_NAME,
+        )
+        .await
+        .unwrap();
+
+        let (snapshot, mut receiver) = app.status_events.subscribe();
+        assert!(snapshot.is_none());
+        assert!(app.status_events.has_viewers());
+
+        // The first delta carries everything
+        app.status_events
+            .publish(StatusJsonResult::new(app.as_ref()).await);
+
+        let delta = parse_event(receiver.recv().await.unwrap().as_str(), "delta");
+        assert_eq!(TOPIC_NAME, delta["topics"][0]["id"]);
+        assert!(delta["queues"][TOPIC_NAME].is_object());
+        assert!(delta["sessions"].is_object());
+
+        // A new viewer starts with the last snapshot
+        let (snapshot, _) = app.status_events.subscribe();
+        let snapshot = parse_event(snapshot.unwrap().as_str(), "snapshot");
+        assert_eq!(TOPIC_NAME, snapshot["topics"]["items"][0]["id"]);
+
+        // Nothing is changed, so only the system part is sent
+        app.status_events
+            .publish(StatusJsonResult::new(app.as_ref()).await);
+
+        let delta = parse_event(receiver.recv().await.unwrap().as_str(), "delta");
+        assert_eq!(0, delta["topics"].as_array().unwrap().len());
+        assert!(delta["queues"].as_object().unwrap().is_empty());
+        assert!(delta["sessions"].is_null());
+
+        app.topic_list.delete(TOPIC_NAME).await.unwrap();
+
+        app.status_events
+            .publish(StatusJsonResult::new(app.as_ref()).await);
+
+        let delta = parse_event(receiver.recv().await.unwrap().as_str(), "delta");
+        assert_eq!(TOPIC_NAME, delta["removedTopics"][0]);
+    }
+}
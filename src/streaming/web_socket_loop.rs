@@ -0,0 +1,307 @@
+use std::{net::SocketAddr, sync::Arc, time::Duration};
+
+use futures_util::{SinkExt, StreamExt};
+use my_service_bus_tcp_shared::{ConnectionAttributes, MySbTcpSerializer, TcpContract};
+use my_tcp_sockets::{socket_reader::SocketReaderInMem, TcpSocketSerializer};
+use rust_extensions::date_time::DateTimeAsMicroseconds;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio_tungstenite::{tungstenite::Message, WebSocketStream};
+
+use crate::{
+    app::{logs::SystemProcess, AppContext},
+    sessions::{SessionConnection, WebSocketConnectionData},
+};
+
+const PING_INTERVAL: Duration = Duration::from_secs(10);
+const READ_TIMEOUT: Duration = Duration::from_secs(30);
+
+pub async fn serve<TStream: AsyncRead + AsyncWrite + Unpin + Send + 'static>(
+    app: Arc<AppContext>,
+    web_socket: WebSocketStream<TStream>,
+    remote_addr: SocketAddr,
+) {
+    let (mut write_stream, mut read_stream) = web_socket.split();
+
+    let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+
+    let session = app
+        .sessions
+        .add_web_socket(WebSocketConnectionData::new(remote_addr, sender))
+        .await;
+
+    let id = session.id;
+
+    let write_loop = tokio::spawn(async move {
+        while let Some(message) = receiver.recv().await {
+            let is_close = if let Message::Close(_) = &message {
+                true
+            } else {
+                false
+            };
+
+            if write_stream.send(message).await.is_err() || is_close {
+                break;
+            }
+        }
+    });
+
+    let data = match &session.connection {
+        SessionConnection::WebSocket(data) => data,
+        _ => panic!("Session {} is expected to be a websocket session", id),
+    };
+
+    let mut serializer = MySbTcpSerializer::new(ConnectionAttributes::new(0));
+
+    let mut last_incoming = DateTimeAsMicroseconds::now();
+
+    loop {
+        let message = tokio::select! {
+            _ = data.disconnected.notified() => break,
+            result = tokio::time::timeout(PING_INTERVAL, read_stream.next()) => result,
+        };
+
+        let message = match message {
+            Ok(Some(message)) => message,
+            Ok(None) => break,
+            Err(_) => {
+                let silence = DateTimeAsMicroseconds::now()
+                    .duration_since(last_incoming)
+                    .as_positive_or_zero();
+
+                if silence > READ_TIMEOUT {
+                    app.logs.add_info(
+                        None,
+                        SystemProcess::TcpSocket,
+                        "WebSocketRead".to_string(),
+                        format!("No incoming data for {:?}. Disconnecting", silence),
+                        Some(format!("SessionId:{}", id)),
+                    );
+                    break;
+                }
+
+                data.send_ping();
+                continue;
+            }
+        };
+
+        last_incoming = DateTimeAsMicroseconds::now();
+
+        let payload = match message {
+            Ok(Message::Binary(payload)) => payload,
+            Ok(Message::Close(_)) => break,
+            Ok(_) => continue,
+            Err(err) => {
+                app.logs.add_error(
+                    None,
+                    SystemProcess::TcpSocket,
+                    "WebSocketRead".to_string(),
+                    format!("Err: {:?}", err),
+                    Some(format!("SessionId:{}", id)),
+                );
+                break;
+            }
+        };
+
+        data.update_read_amount(payload.len());
+
+        let mut socket_reader = SocketReaderInMem::new(payload);
+
+        let tcp_contract = match serializer.deserialize(&mut socket_reader).await {
+            Ok(tcp_contract) => tcp_contract,
+            Err(err) => {
+                app.logs.add_error(
+                    None,
+                    SystemProcess::TcpSocket,
+                    "WebSocketRead".to_string(),
+                    format!("Can not deserialize packet. Err: {:?}", err),
+                    Some(format!("SessionId:{}", id)),
+                );
+                break;
+            }
+        };
+
+        if let TcpContract::Subscribe { .. } = &tcp_contract {
+            if data.get_protocol_version() == 0 {
+                app.logs.add_error(
+                    None,
+                    SystemProcess::TcpSocket,
+                    "WebSocketRead".to_string(),
+                    "Subscribe packet is received before Greeting. Disconnecting".to_string(),
+                    Some(format!("SessionId:{}", id)),
+                );
+                break;
+            }
+        }
+
+        serializer.apply_packet(&tcp_contract);
+
+        data.apply_incoming_packet(&tcp_contract);
+
+        if let Err(err) = crate::tcp::incoming_packets::handle(&app, tcp_contract, &session).await {
+            app.logs.add_error(
+                None,
+                SystemProcess::TcpSocket,
+                "Handle WebSocket Payload".to_string(),
+                format!("Err: {:?}", err),
+                Some(format!("SessionId:{}", id)),
+            );
+        }
+    }
+
+    session.disconnect().await;
+
+    if let Some(session) = app.sessions.remove_web_socket(id).await {
+        crate::operations::sessions::disconnect(app.as_ref(), session.as_ref()).await;
+    }
+
+    let _ = write_loop.await;
+}
+
+#[cfg(test)]
+mod tests {
+    use my_service_bus_shared::queue::TopicQueueType;
+    use my_service_bus_tcp_shared::MessageToPublishTcpContract;
+    use tokio::io::DuplexStream;
+    use tokio_tungstenite::tungstenite::protocol::Role;
+
+    use crate::{
+        sessions::{SessionId, TestConnectionData},
+        settings::{CliArgs, SettingsModel},
+    };
+
+    use super::*;
+
+    async fn send_packet(
+        client: &mut WebSocketStream<DuplexStream>,
+        serializer: &mut MySbTcpSerializer,
+        tcp_contract: TcpContract,
+    ) {
+        serializer.apply_packet(&tcp_contract);
+        let payload = serializer.serialize(tcp_contract);
+        client.send(Message::Binary(payload)).await.unwrap();
+    }
+
+    async fn read_packet(
+        client: &mut WebSocketStream<DuplexStream>,
+        serializer: &mut MySbTcpSerializer,
+    ) -> TcpContract {
+        loop {
+            if let Message::Binary(payload) = client.next().await.unwrap().unwrap() {
+                let mut socket_reader = SocketReaderInMem::new(payload);
+                return serializer.deserialize(&mut socket_reader).await.unwrap();
+            }
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_web_socket_session() {
+        const TOPIC_NAME: &str = "test-topic";
+        const QUEUE_NAME: &str = "test-queue";
+        const PUBLISHER_SESSION_ID: SessionId = 13;
+
+        let settings = SettingsModel::create_test_settings(16);
+
+        let app = Arc::new(AppContext::new(&settings, CliArgs::default()).await);
+
+        let publisher = app
+            .sessions
+            .add_test(TestConnectionData::new(PUBLISHER_SESSION_ID, "127.0.0.1"))
+            .await;
+
+        crate::operations::publisher::create_topic_if_not_exists(
+            &app,
+            Some(publisher.id),
+            TOPIC_NAME,
+        )
+        .await
+        .unwrap();
+
+        let (client_stream, server_stream) = tokio::io::duplex(64 * 1024);
+
+        let server = WebSocketStream::from_raw_socket(server_stream, Role::Server, None).await;
+        let mut client = WebSocketStream::from_raw_socket(client_stream, Role::Client, None).await;
+
+        let serve_task = tokio::spawn(serve(
+            app.clone(),
+            server,
+            SocketAddr::from(([127, 0, 0, 1], 5000)),
+        ));
+
+        let mut serializer = MySbTcpSerializer::new(ConnectionAttributes::new(0));
+
+        let greeting = TcpContract::Greeting {
+            name: "ws-client;1.0".to_string(),
+            protocol_version: 3,
+        };
+        send_packet(&mut client, &mut serializer, greeting).await;
+
+        let subscribe = TcpContract::Subscribe {
+            topic_id: TOPIC_NAME.to_string(),
+            queue_id: QUEUE_NAME.to_string(),
+            queue_type: TopicQueueType::Permanent,
+        };
+        send_packet(&mut client, &mut serializer, subscribe).await;
+
+        // Packets are handled in order, so Pong means the subscription is done
+        send_packet(&mut client, &mut serializer, TcpContract::Ping).await;
+        let packet = read_packet(&mut client, &mut serializer).await;
+        assert!(matches!(packet, TcpContract::Pong));
+
+        let messages = vec![
+            MessageToPublishTcpContract {
+                headers: None,
+                content: vec![0u8, 1u8, 2u8],
+            },
+            MessageToPublishTcpContract {
+                headers: None,
+                content: vec![3u8, 4u8, 5u8],
+            },
+        ];
+
+        crate::operations::publisher::publish(&app, TOPIC_NAME, messages, false, &publisher)
+            .await
+            .unwrap();
+
+        let confirmation_id = match read_packet(&mut client, &mut serializer).await {
+            TcpContract::NewMessages {
+                topic_id,
+                queue_id,
+                confirmation_id,
+                messages,
+            } => {
+                assert_eq!(TOPIC_NAME, topic_id);
+                assert_eq!(QUEUE_NAME, queue_id);
+                assert_eq!(2, messages.len());
+                confirmation_id
+            }
+            _ => panic!("Should not be here"),
+        };
+
+        let confirmation = TcpContract::NewMessagesConfirmation {
+            topic_id: TOPIC_NAME.to_string(),
+            queue_id: QUEUE_NAME.to_string(),
+            confirmation_id,
+        };
+        send_packet(&mut client, &mut serializer, confirmation).await;
+
+        send_packet(&mut client, &mut serializer, TcpContract::Ping).await;
+        let packet = read_packet(&mut client, &mut serializer).await;
+        assert!(matches!(packet, TcpContract::Pong));
+
+        {
+            let topic = app.topic_list.get(TOPIC_NAME).await.unwrap();
+            let topic_data = topic.get_access().await;
+            let queue = topic_data.queues.get(QUEUE_NAME).unwrap();
+
+            assert_eq!(0, queue.get_on_delivery());
+            assert_eq!(0, queue.get_queue_size());
+        }
+
+        client.close(None).await.unwrap();
+        serve_task.await.unwrap();
+
+        let (_, sessions) = app.sessions.get_snapshot().await;
+        assert_eq!(1, sessions.len());
+        assert_eq!(PUBLISHER_SESSION_ID, sessions[0].id);
+    }
+}
@@ -1,21 +1,25 @@
 use std::sync::Arc;
 
 use my_service_bus_shared::queue_with_intervals::QueueWithIntervals;
-use my_service_bus_tcp_shared::{MySbTcpSerializer, TcpContract};
-use my_tcp_sockets::tcp_connection::SocketConnection;
+use my_service_bus_tcp_shared::TcpContract;
 
-use crate::{app::AppContext, operations};
+use crate::{app::AppContext, operations, sessions::MyServiceBusSession};
 
 use super::error::MySbSocketError;
 
 pub async fn handle(
     app: &Arc<AppContext>,
     tcp_contract: TcpContract,
-    connection: Arc<SocketConnection<TcpContract, MySbTcpSerializer>>,
+    session: &Arc<MyServiceBusSession>,
 ) -> Result<(), MySbSocketError> {
+    let contract_name = get_contract_name(&tcp_contract);
+    session.statistics.packet_is_received(contract_name);
+    app.prometheus
+        .session_packet_is_received(session.get_metrics_name().as_str(), contract_name);
+
     match tcp_contract {
         TcpContract::Ping {} => {
-            connection.send(TcpContract::Pong).await;
+            session.send(TcpContract::Pong).await;
             Ok(())
         }
         TcpContract::Pong {} => Ok(()),
@@ -25,18 +29,16 @@ pub async fn handle(
         } => {
             let splited: Vec<&str> = name.split(";").collect();
 
-            if let Some(session) = app.sessions.get_by_tcp_connection_id(connection.id).await {
-                if splited.len() == 2 {
-                    session
-                        .set_tcp_socket_name(splited[0].to_string(), Some(splited[1].to_string()))
-                        .await;
-                } else {
-                    session.set_tcp_socket_name(name, None).await;
-                }
-
-                session.update_tcp_protocol_version(protocol_version);
+            if splited.len() == 2 {
+                session
+                    .set_tcp_socket_name(splited[0].to_string(), Some(splited[1].to_string()))
+                    .await;
+            } else {
+                session.set_tcp_socket_name(name, None).await;
             }
 
+            session.update_tcp_protocol_version(protocol_version);
+
             Ok(())
         }
         TcpContract::Publish {
@@ -45,31 +47,25 @@ pub async fn handle(
             persist_immediately,
             data_to_publish,
         } => {
-            if let Some(session_id) = app
-                .sessions
-                .resolve_session_id_by_tcp_connection_id(connection.id)
-                .await
-            {
-                let result = operations::publisher::publish(
-                    app,
-                    topic_id.as_str(),
-                    data_to_publish,
-                    persist_immediately,
-                    session_id,
-                )
-                .await;
-
-                if let Err(err) = result {
-                    connection
-                        .send(TcpContract::Reject {
-                            message: format!("{:?}", err),
-                        })
-                        .await;
-                } else {
-                    connection
-                        .send(TcpContract::PublishResponse { request_id })
-                        .await;
-                }
+            let result = operations::publisher::publish(
+                app,
+                topic_id.as_str(),
+                data_to_publish,
+                persist_immediately,
+                session,
+            )
+            .await;
+
+            if let Err(err) = result {
+                session
+                    .send(TcpContract::Reject {
+                        message: format!("{:?}", err),
+                    })
+                    .await;
+            } else {
+                session
+                    .send(TcpContract::PublishResponse { request_id })
+                    .await;
             }
 
             Ok(())
@@ -84,12 +80,10 @@ pub async fn handle(
             queue_id,
             queue_type,
         } => {
-            if let Some(session) = app.sessions.get_by_tcp_connection_id(connection.id).await {
-                operations::subscriber::subscribe_to_queue(
-                    app, topic_id, queue_id, queue_type, &session,
-                )
-                .await?;
-            }
+            operations::subscriber::subscribe_to_queue(
+                app, topic_id, queue_id, queue_type, session,
+            )
+            .await?;
 
             Ok(())
         }
@@ -119,14 +113,12 @@ pub async fn handle(
             Ok(())
         }
         TcpContract::CreateTopicIfNotExists { topic_id } => {
-            if let Some(session) = app.sessions.get_by_tcp_connection_id(connection.id).await {
-                operations::publisher::create_topic_if_not_exists(
-                    app,
-                    Some(session.id),
-                    topic_id.as_str(),
-                )
-                .await?;
-            }
+            operations::publisher::create_topic_if_not_exists(
+                app,
+                Some(session.id),
+                topic_id.as_str(),
+            )
+            .await?;
 
             Ok(())
         }
@@ -152,9 +144,7 @@ pub async fn handle(
             if let Some(version) =
                 packet_versions.get(&my_service_bus_tcp_shared::tcp_message_id::NEW_MESSAGES)
             {
-                if let Some(session) = app.sessions.get_by_tcp_connection_id(connection.id).await {
-                    session.update_tcp_delivery_packet_version(*version)
-                }
+                session.update_tcp_delivery_packet_version(*version)
             }
 
             Ok(())
@@ -208,3 +198,24 @@ pub async fn handle(
         }
     }
 }
+
+pub fn get_contract_name(tcp_contract: &TcpContract) -> &'static str {
+    match tcp_contract {
+        TcpContract::Ping { .. } => "Ping",
+        TcpContract::Pong { .. } => "Pong",
+        TcpContract::Greeting { .. } => "Greeting",
+        TcpContract::Publish { .. } => "Publish",
+        TcpContract::PublishResponse { .. } => "PublishResponse",
+        TcpContract::Subscribe { .. } => "Subscribe",
+        TcpContract::SubscribeResponse { .. } => "SubscribeResponse",
+        TcpContract::Raw(_) => "Raw",
+        TcpContract::NewMessagesConfirmation { .. } => "NewMessagesConfirmation",
+        TcpContract::CreateTopicIfNotExists { .. } => "CreateTopicIfNotExists",
+        TcpContract::IntermediaryConfirm { .. } => "IntermediaryConfirm",
+        TcpContract::PacketVersions { .. } => "PacketVersions",
+        TcpContract::Reject { .. } => "Reject",
+        TcpContract::AllMessagesConfirmedAsFail { .. } => "AllMessagesConfirmedAsFail",
+        TcpContract::ConfirmSomeMessagesAsOk { .. } => "ConfirmSomeMessagesAsOk",
+        TcpContract::NewMessages { .. } => "NewMessages",
+    }
+}
@@ -1,3 +1,3 @@
 mod error;
-mod incoming_packets;
+pub mod incoming_packets;
 pub mod socket_loop;
@@ -24,7 +24,13 @@ impl SocketEventCallback<TcpContract, MySbTcpSerializer> for TcpServerEvents {
     async fn handle(&self, connection_event: ConnectionEvent<TcpContract, MySbTcpSerializer>) {
         match connection_event {
             ConnectionEvent::Connected(connection) => {
-                println!("New tcp connection: {}", connection.id);
+                self.app.logs.add_info(
+                    None,
+                    SystemProcess::TcpSocket,
+                    "tcp_connection".to_string(),
+                    format!("New tcp connection: {}", connection.id),
+                    None,
+                );
 
                 self.app
                     .sessions
@@ -32,7 +38,14 @@ impl SocketEventCallback<TcpContract, MySbTcpSerializer> for TcpServerEvents {
                     .await;
             }
             ConnectionEvent::Disconnected(connection) => {
-                println!("Connection {} is disconnected", connection.id);
+                self.app.logs.add_info(
+                    None,
+                    SystemProcess::TcpSocket,
+                    "tcp_connection".to_string(),
+                    format!("Connection {} is disconnected", connection.id),
+                    None,
+                );
+
                 if let Some(session) = self.app.sessions.remove_tcp(connection.id).await {
                     crate::operations::sessions::disconnect(self.app.as_ref(), session.as_ref())
                         .await;
@@ -43,8 +56,26 @@ impl SocketEventCallback<TcpContract, MySbTcpSerializer> for TcpServerEvents {
                 payload,
             } => {
                 let connection_id = connection.id;
+
+                let session = self
+                    .app
+                    .sessions
+                    .get_by_tcp_connection_id(connection_id)
+                    .await;
+
+                if session.is_none() {
+                    self.app.logs.add_error(
+                        None,
+                        SystemProcess::TcpSocket,
+                        "Handle Payload".to_string(),
+                        "Session is not found".to_string(),
+                        Some(format!("ConnectionId:{}", connection_id)),
+                    );
+                    return;
+                }
+
                 if let Err(err) =
-                    super::incoming_packets::handle(&self.app, payload, connection).await
+                    super::incoming_packets::handle(&self.app, payload, &session.unwrap()).await
                 {
                     self.app.logs.add_error(
                         None,
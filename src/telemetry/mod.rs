@@ -0,0 +1,10 @@
+mod otlp_exporter;
+mod otlp_models;
+mod telemetry;
+mod telemetry_span;
+mod trace_context;
+
+pub use otlp_exporter::export_spans;
+pub use telemetry::Telemetry;
+pub use telemetry_span::{SpanKind, TelemetrySpan};
+pub use trace_context::{TraceContext, TRACE_PARENT_HEADER};
@@ -0,0 +1,102 @@
+use hyper::{header::CONTENT_TYPE, Body, Method, Request};
+
+use super::{otlp_models::ExportTraceServiceRequest, TelemetrySpan};
+
+const TRACES_PATH: &str = "/v1/traces";
+
+pub async fn export_spans(
+    endpoint: &str,
+    service_name: &str,
+    spans: Vec<TelemetrySpan>,
+) -> Result<(), String> {
+    let request = ExportTraceServiceRequest::new(service_name, spans);
+
+    let body = serde_json::to_vec(&request)
+        .map_err(|err| format!("Can not serialize spans: {:?}", err))?;
+
+    let request = Request::builder()
+        .method(Method::POST)
+        .uri(get_traces_url(endpoint))
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(body))
+        .map_err(|err| format!("Can not build OTLP request: {:?}", err))?;
+
+    let response = crate::utils::create_http_client()
+        .request(request)
+        .await
+        .map_err(|err| format!("Can not send spans: {:?}", err))?;
+
+    if !response.status().is_success() {
+        return Err(format!(
+            "OTLP collector responded with status {}",
+            response.status()
+        ));
+    }
+
+    Ok(())
+}
+
+fn get_traces_url(endpoint: &str) -> String {
+    let endpoint = endpoint.trim_end_matches('/');
+
+    if endpoint.ends_with(TRACES_PATH) {
+        return endpoint.to_string();
+    }
+
+    format!("{}{}", endpoint, TRACES_PATH)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{convert::Infallible, net::SocketAddr};
+
+    use hyper::{
+        service::{make_service_fn, service_fn},
+        Response, Server,
+    };
+    use tokio::sync::mpsc;
+
+    use crate::telemetry::{SpanKind, TelemetrySpan, TraceContext};
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_spans_are_exported_to_collector() {
+        let (sender, mut receiver) = mpsc::unbounded_channel();
+
+        let make_service = make_service_fn(move |_| {
+            let sender = sender.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |req: Request<Body>| {
+                    let sender = sender.clone();
+                    async move {
+                        let path = req.uri().path().to_string();
+                        let body = hyper::body::to_bytes(req.into_body()).await.unwrap();
+                        sender.send((path, body.to_vec())).unwrap();
+                        Ok::<_, Infallible>(Response::new(Body::empty()))
+                    }
+                }))
+            }
+        });
+
+        let server = Server::bind(&SocketAddr::from(([127, 0, 0, 1], 0))).serve(make_service);
+        let endpoint = format!("http://{}", server.local_addr());
+        tokio::spawn(server);
+
+        let parent =
+            TraceContext::parse("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01").unwrap();
+        let span = TelemetrySpan::new("publish", SpanKind::Producer, &parent);
+
+        export_spans(endpoint.as_str(), "test-service", vec![span])
+            .await
+            .unwrap();
+
+        let (path, body) = receiver.recv().await.unwrap();
+        let body = String::from_utf8(body).unwrap();
+
+        assert_eq!(TRACES_PATH, path);
+        assert!(body.contains("4bf92f3577b34da6a3ce929d0e0e4736"));
+        assert!(body.contains("\"parentSpanId\":\"00f067aa0ba902b7\""));
+        assert!(body.contains("test-service"));
+    }
+}
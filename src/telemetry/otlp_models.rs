@@ -0,0 +1,133 @@
+use serde::Serialize;
+
+use crate::app::APP_VERSION;
+
+use super::{trace_context::encode_hex, TelemetrySpan};
+
+const STATUS_CODE_OK: i32 = 1;
+const STATUS_CODE_ERROR: i32 = 2;
+
+#[derive(Serialize)]
+pub struct ExportTraceServiceRequest {
+    #[serde(rename = "resourceSpans")]
+    pub resource_spans: Vec<ResourceSpansModel>,
+}
+
+#[derive(Serialize)]
+pub struct ResourceSpansModel {
+    pub resource: ResourceModel,
+    #[serde(rename = "scopeSpans")]
+    pub scope_spans: Vec<ScopeSpansModel>,
+}
+
+#[derive(Serialize)]
+pub struct ResourceModel {
+    pub attributes: Vec<KeyValueModel>,
+}
+
+#[derive(Serialize)]
+pub struct ScopeSpansModel {
+    pub scope: ScopeModel,
+    pub spans: Vec<SpanModel>,
+}
+
+#[derive(Serialize)]
+pub struct ScopeModel {
+    pub name: String,
+    pub version: String,
+}
+
+#[derive(Serialize)]
+pub struct SpanModel {
+    #[serde(rename = "traceId")]
+    pub trace_id: String,
+    #[serde(rename = "spanId")]
+    pub span_id: String,
+    #[serde(rename = "parentSpanId")]
+    pub parent_span_id: String,
+    pub name: String,
+    pub kind: i32,
+    #[serde(rename = "startTimeUnixNano")]
+    pub start_time_unix_nano: String,
+    #[serde(rename = "endTimeUnixNano")]
+    pub end_time_unix_nano: String,
+    pub attributes: Vec<KeyValueModel>,
+    pub status: StatusModel,
+}
+
+#[derive(Serialize)]
+pub struct StatusModel {
+    pub code: i32,
+}
+
+#[derive(Serialize)]
+pub struct KeyValueModel {
+    pub key: String,
+    pub value: AnyValueModel,
+}
+
+#[derive(Serialize)]
+pub struct AnyValueModel {
+    #[serde(rename = "stringValue")]
+    pub string_value: String,
+}
+
+impl KeyValueModel {
+    pub fn new(key: &str, value: String) -> Self {
+        Self {
+            key: key.to_string(),
+            value: AnyValueModel {
+                string_value: value,
+            },
+        }
+    }
+}
+
+impl ExportTraceServiceRequest {
+    pub fn new(service_name: &str, spans: Vec<TelemetrySpan>) -> Self {
+        Self {
+            resource_spans: vec![ResourceSpansModel {
+                resource: ResourceModel {
+                    attributes: vec![KeyValueModel::new("service.name", service_name.to_string())],
+                },
+                scope_spans: vec![ScopeSpansModel {
+                    scope: ScopeModel {
+                        name: "my-service-bus".to_string(),
+                        version: APP_VERSION.to_string(),
+                    },
+                    spans: spans.into_iter().map(|span| span.into()).collect(),
+                }],
+            }],
+        }
+    }
+}
+
+impl From<TelemetrySpan> for SpanModel {
+    fn from(span: TelemetrySpan) -> Self {
+        Self {
+            trace_id: encode_hex(&span.context.trace_id),
+            span_id: encode_hex(&span.context.span_id),
+            parent_span_id: encode_hex(&span.parent_span_id),
+            name: span.name.to_string(),
+            kind: span.kind.as_otlp_kind(),
+            start_time_unix_nano: to_unix_nano(span.started.unix_microseconds),
+            end_time_unix_nano: to_unix_nano(span.finished.unix_microseconds),
+            attributes: span
+                .attributes
+                .into_iter()
+                .map(|(key, value)| KeyValueModel::new(key, value))
+                .collect(),
+            status: StatusModel {
+                code: if span.ok {
+                    STATUS_CODE_OK
+                } else {
+                    STATUS_CODE_ERROR
+                },
+            },
+        }
+    }
+}
+
+fn to_unix_nano(unix_microseconds: i64) -> String {
+    (unix_microseconds as i128 * 1000).to_string()
+}
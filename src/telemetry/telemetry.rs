@@ -0,0 +1,63 @@
+use std::sync::Mutex;
+
+use rust_extensions::date_time::DateTimeAsMicroseconds;
+
+use super::{SpanKind, TelemetrySpan, TraceContext};
+
+const MAX_SPANS_TO_EXPORT: usize = 10_000;
+
+pub struct Telemetry {
+    endpoint: Option<String>,
+    pub service_name: String,
+    spans_to_export: Mutex<Vec<TelemetrySpan>>,
+}
+
+impl Telemetry {
+    pub fn new(endpoint: Option<String>, service_name: String) -> Self {
+        Self {
+            endpoint,
+            service_name,
+            spans_to_export: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.endpoint.is_some()
+    }
+
+    pub fn get_endpoint(&self) -> Option<&str> {
+        self.endpoint.as_deref()
+    }
+
+    pub fn start_span(
+        &self,
+        name: &'static str,
+        kind: SpanKind,
+        parent: &TraceContext,
+    ) -> Option<TelemetrySpan> {
+        if !self.is_enabled() || !parent.is_sampled() {
+            return None;
+        }
+
+        Some(TelemetrySpan::new(name, kind, parent))
+    }
+
+    pub fn finish_span(&self, mut span: TelemetrySpan, ok: bool) {
+        span.finished = DateTimeAsMicroseconds::now();
+        span.ok = ok;
+
+        let mut write_access = self.spans_to_export.lock().unwrap();
+
+        //Collector is not available. We drop spans rather than grow the memory
+        if write_access.len() >= MAX_SPANS_TO_EXPORT {
+            return;
+        }
+
+        write_access.push(span);
+    }
+
+    pub fn get_spans_to_export(&self) -> Vec<TelemetrySpan> {
+        let mut write_access = self.spans_to_export.lock().unwrap();
+        std::mem::take(&mut *write_access)
+    }
+}
@@ -0,0 +1,51 @@
+use rust_extensions::date_time::DateTimeAsMicroseconds;
+
+use super::TraceContext;
+
+#[derive(Clone, Copy)]
+pub enum SpanKind {
+    Internal,
+    Producer,
+    Consumer,
+}
+
+impl SpanKind {
+    pub fn as_otlp_kind(&self) -> i32 {
+        match self {
+            SpanKind::Internal => 1,
+            SpanKind::Producer => 4,
+            SpanKind::Consumer => 5,
+        }
+    }
+}
+
+pub struct TelemetrySpan {
+    pub name: &'static str,
+    pub kind: SpanKind,
+    pub context: TraceContext,
+    pub parent_span_id: [u8; 8],
+    pub started: DateTimeAsMicroseconds,
+    pub finished: DateTimeAsMicroseconds,
+    pub ok: bool,
+    pub attributes: Vec<(&'static str, String)>,
+}
+
+impl TelemetrySpan {
+    pub fn new(name: &'static str, kind: SpanKind, parent: &TraceContext) -> Self {
+        let now = DateTimeAsMicroseconds::now();
+        Self {
+            name,
+            kind,
+            context: parent.create_child(),
+            parent_span_id: parent.span_id,
+            started: now,
+            finished: now,
+            ok: true,
+            attributes: Vec::new(),
+        }
+    }
+
+    pub fn add_attribute(&mut self, key: &'static str, value: String) {
+        self.attributes.push((key, value));
+    }
+}
@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+pub const TRACE_PARENT_HEADER: &str = "traceparent";
+
+const TRACE_PARENT_VERSION: &str = "00";
+const FLAG_SAMPLED: u8 = 1;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TraceContext {
+    pub trace_id: [u8; 16],
+    pub span_id: [u8; 8],
+    pub flags: u8,
+}
+
+impl TraceContext {
+    pub fn parse(src: &str) -> Option<Self> {
+        let mut parts = src.trim().split('-');
+
+        let version = parts.next()?;
+        let trace_id = parts.next()?;
+        let span_id = parts.next()?;
+        let flags = parts.next()?;
+
+        if version.len() != 2 || version == "ff" {
+            return None;
+        }
+
+        //Version 00 has exactly 4 parts. Future versions may add more of them
+        if version == TRACE_PARENT_VERSION && parts.next().is_some() {
+            return None;
+        }
+
+        let mut result = Self {
+            trace_id: [0u8; 16],
+            span_id: [0u8; 8],
+            flags: 0,
+        };
+
+        decode_hex(trace_id, &mut result.trace_id)?;
+        decode_hex(span_id, &mut result.span_id)?;
+
+        let mut flags_bytes = [0u8; 1];
+        decode_hex(flags, &mut flags_bytes)?;
+        result.flags = flags_bytes[0];
+
+        if is_zero(&result.trace_id) || is_zero(&result.span_id) {
+            return None;
+        }
+
+        Some(result)
+    }
+
+    pub fn from_headers(headers: &Option<HashMap<String, String>>) -> Option<Self> {
+        let value = headers.as_ref()?.get(TRACE_PARENT_HEADER)?;
+        Self::parse(value.as_str())
+    }
+
+    pub fn create_child(&self) -> Self {
+        Self {
+            trace_id: self.trace_id,
+            span_id: generate_span_id(),
+            flags: self.flags,
+        }
+    }
+
+    pub fn is_sampled(&self) -> bool {
+        self.flags & FLAG_SAMPLED == FLAG_SAMPLED
+    }
+}
+
+pub fn encode_hex(src: &[u8]) -> String {
+    let mut result = String::with_capacity(src.len() * 2);
+
+    for b in src {
+        result.push_str(format!("{:02x}", b).as_str());
+    }
+
+    result
+}
+
+fn decode_hex(src: &str, dest: &mut [u8]) -> Option<()> {
+    if src.len() != dest.len() * 2 {
+        return None;
+    }
+
+    for (i, dest_byte) in dest.iter_mut().enumerate() {
+        *dest_byte = u8::from_str_radix(src.get(i * 2..i * 2 + 2)?, 16).ok()?;
+    }
+
+    Some(())
+}
+
+fn is_zero(src: &[u8]) -> bool {
+    src.iter().all(|b| *b == 0)
+}
+
+fn generate_span_id() -> [u8; 8] {
+    loop {
+        let result = rand::random::<u64>().to_be_bytes();
+
+        if !is_zero(&result) {
+            return result;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRACE_PARENT: &str = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+
+    #[test]
+    fn test_parse() {
+        let trace_context = TraceContext::parse(TRACE_PARENT).unwrap();
+
+        assert!(trace_context.is_sampled());
+        assert_eq!(
+            "4bf92f3577b34da6a3ce929d0e0e4736",
+            encode_hex(&trace_context.trace_id)
+        );
+        assert_eq!("00f067aa0ba902b7", encode_hex(&trace_context.span_id));
+    }
+
+    #[test]
+    fn test_invalid_trace_parent() {
+        assert!(TraceContext::parse("").is_none());
+        assert!(
+            TraceContext::parse("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7").is_none()
+        );
+        assert!(
+            TraceContext::parse("00-00000000000000000000000000000000-00f067aa0ba902b7-01")
+                .is_none()
+        );
+        assert!(
+            TraceContext::parse("00-4bf92f3577b34da6a3ce929d0e0e4736-0000000000000000-01")
+                .is_none()
+        );
+        assert!(
+            TraceContext::parse("ff-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01")
+                .is_none()
+        );
+        assert!(
+            TraceContext::parse("00-4bf92f3577b34da6a3ce929d0e0e473z-00f067aa0ba902b7-01")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_child_keeps_trace_id() {
+        let parent = TraceContext::parse(TRACE_PARENT).unwrap();
+        let child = parent.create_child();
+
+        assert_eq!(parent.trace_id, child.trace_id);
+        assert_ne!(parent.span_id, child.span_id);
+        assert_eq!(parent.flags, child.flags);
+    }
+}
@@ -1,3 +1,4 @@
+mod queue_lag;
 mod topic;
 mod topic_data;
 mod topic_data_access;
@@ -5,6 +6,7 @@ mod topic_snapshot;
 mod topics_list;
 mod topics_metrics;
 
+pub use queue_lag::QueueLag;
 pub use topic::Topic;
 pub use topic_data::TopicData;
 pub use topic_snapshot::TopicQueueSnapshot;
@@ -0,0 +1,161 @@
+use my_service_bus_shared::{page_id::get_page_id, sub_page::SubPageId};
+use rust_extensions::date_time::DateTimeAsMicroseconds;
+
+use crate::{queues::TopicQueue, utils::MinMessageIdCalculator};
+
+use super::TopicData;
+
+pub struct QueueLag {
+    pub messages: i64,
+    pub seconds: Option<i64>,
+}
+
+impl QueueLag {
+    pub fn new(
+        topic_data: &TopicData,
+        topic_queue: &TopicQueue,
+        now: DateTimeAsMicroseconds,
+    ) -> Self {
+        let mut min_message_id = MinMessageIdCalculator::new();
+        min_message_id.add(topic_queue.queue.get_min_id());
+        min_message_id.add(topic_queue.subscribers.get_min_message_id());
+
+        let min_message_id = match min_message_id.value {
+            Some(value) => value,
+            None => {
+                return Self {
+                    messages: 0,
+                    seconds: Some(0),
+                }
+            }
+        };
+
+        let sub_page_id = SubPageId::from_message_id(min_message_id);
+
+        //We can only say how old the message is if it's page is loaded
+        let seconds = topic_data
+            .pages
+            .get_page(get_page_id(min_message_id))
+            .and_then(|page| page.get_sub_page(&sub_page_id))
+            .and_then(|sub_page| sub_page.sub_page.get_message(min_message_id))
+            .map(|message| {
+                now.duration_since(message.time)
+                    .as_positive_or_zero()
+                    .as_secs() as i64
+            });
+
+        Self {
+            messages: topic_data.message_id - min_message_id,
+            seconds,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use my_service_bus_shared::queue::TopicQueueType;
+    use my_service_bus_tcp_shared::MessageToPublishTcpContract;
+
+    use crate::{
+        app::AppContext,
+        sessions::TestConnectionData,
+        settings::{CliArgs, SettingsModel},
+    };
+
+    use super::*;
+
+    const TOPIC_NAME: &str = "test-topic";
+    const QUEUE_NAME: &str = "test-queue";
+
+    async fn get_lag(app: &AppContext, now: DateTimeAsMicroseconds) -> (i64, Option<i64>) {
+        let topic = app.topic_list.get(TOPIC_NAME).await.unwrap();
+        let topic_data = topic.get_access().await;
+        let topic_queue = topic_data.queues.get(QUEUE_NAME).unwrap();
+
+        let lag = QueueLag::new(&topic_data, topic_queue, now);
+        (lag.messages, lag.seconds)
+    }
+
+    #[tokio::test]
+    async fn test_lag_is_counted_from_the_oldest_not_confirmed_message() {
+        let settings = SettingsModel::create_test_settings(16);
+
+        let app = Arc::new(AppContext::new(&settings, CliArgs::default()).await);
+
+        let session = app
+            .sessions
+            .add_test(TestConnectionData::new(13, "127.0.0.1"))
+            .await;
+
+        crate::operations::publisher::create_topic_if_not_exists(
+            &app,
+            Some(session.id),
+            TOPIC_NAME,
+        )
+        .await
+        .unwrap();
+
+        crate::operations::queues::create_queue_if_not_exists(
+            app.as_ref(),
+            TOPIC_NAME,
+            QUEUE_NAME,
+            TopicQueueType::Permanent,
+            None,
+        )
+        .await
+        .unwrap();
+
+        let published = DateTimeAsMicroseconds::now();
+
+        let messages = (0..3u8)
+            .map(|itm| MessageToPublishTcpContract {
+                headers: None,
+                content: vec![itm],
+            })
+            .collect();
+
+        crate::operations::publisher::publish(&app, TOPIC_NAME, messages, false, &session)
+            .await
+            .unwrap();
+
+        let now = DateTimeAsMicroseconds::new(published.unix_microseconds + 10_000_000);
+
+        let (messages, seconds) = get_lag(app.as_ref(), now).await;
+        assert_eq!(3, messages);
+        assert!(seconds.unwrap() >= 9);
+
+        // Messages on delivery are still a lag until they are confirmed
+        crate::operations::subscriber::subscribe_to_queue(
+            &app,
+            TOPIC_NAME.to_string(),
+            QUEUE_NAME.to_string(),
+            TopicQueueType::Permanent,
+            &session,
+        )
+        .await
+        .unwrap();
+
+        let (messages, _) = get_lag(app.as_ref(), now).await;
+        assert_eq!(3, messages);
+
+        let subscriber_id = {
+            let topic = app.topic_list.get(TOPIC_NAME).await.unwrap();
+            let topic_data = topic.get_access().await;
+            let topic_queue = topic_data.queues.get(QUEUE_NAME).unwrap();
+            topic_queue.subscribers.get_all().unwrap()[0].id
+        };
+
+        crate::operations::delivery_confirmation::all_confirmed(
+            &app,
+            TOPIC_NAME,
+            QUEUE_NAME,
+            subscriber_id,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!((0, Some(0)), get_lag(app.as_ref(), now).await);
+    }
+}
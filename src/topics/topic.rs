@@ -18,6 +18,8 @@ pub struct Topic {
     data: Mutex<TopicData>,
     pub restore_page_lock: Mutex<DateTimeAsMicroseconds>,
     pub immediatelly_persist_is_charged: AtomicBool,
+    /// Set by the topology file. Every publish to the topic is persisted as if the publisher asked for it
+    pub persist_immediately: AtomicBool,
 }
 
 impl Topic {
@@ -27,6 +29,7 @@ impl Topic {
             data: Mutex::new(TopicData::new(topic_id, message_id)),
             restore_page_lock: Mutex::new(DateTimeAsMicroseconds::now()),
             immediatelly_persist_is_charged: AtomicBool::new(false),
+            persist_immediately: AtomicBool::new(false),
         }
     }
 
@@ -61,6 +64,7 @@ impl Topic {
             message_id: topic_data.message_id,
             topic_id: topic_data.topic_id.to_string(),
             queues: topic_data.queues.get_snapshot_to_persist(),
+            read_only: topic_data.read_only,
         }
     }
 
@@ -22,6 +22,7 @@ pub struct TopicData {
     pub metrics: TopicMetrics,
     pub pages: MessagesPageList,
     pub publishers: HashMap<SessionId, u8>,
+    pub read_only: bool,
 }
 
 impl TopicData {
@@ -33,6 +34,7 @@ impl TopicData {
             metrics: TopicMetrics::new(),
             pages: MessagesPageList::new(),
             publishers: HashMap::new(),
+            read_only: false,
         }
     }
 
@@ -4,10 +4,12 @@ pub struct TopicQueueSnapshot {
     pub queue_id: String,
     pub queue_type: TopicQueueType,
     pub ranges: Vec<QueueIndexRange>,
+    pub paused: bool,
 }
 #[derive(Clone)]
 pub struct TopicSnapshot {
     pub topic_id: String,
     pub message_id: i64,
     pub queues: Vec<TopicQueueSnapshot>,
+    pub read_only: bool,
 }
@@ -92,6 +92,18 @@ impl TopicsList {
         return result;
     }
 
+    pub async fn delete(&self, topic_id: &str) -> Option<Arc<Topic>> {
+        let mut write_access = self.data.write().await;
+
+        let result = write_access.topics.remove(topic_id);
+
+        if result.is_some() {
+            write_access.snapshot_id += 1;
+        }
+
+        result
+    }
+
     pub async fn one_second_tick(&self) {
         let topics = self.get_all().await;
 
@@ -0,0 +1,7 @@
+mod reconcile;
+mod topology_diff;
+mod topology_model;
+
+pub use reconcile::reconcile;
+pub use topology_diff::*;
+pub use topology_model::*;
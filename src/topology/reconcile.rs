@@ -0,0 +1,206 @@
+use std::sync::{atomic::Ordering, Arc};
+
+use crate::{
+    app::{logs::SystemProcess, AppContext},
+    operations::OperationFailResult,
+};
+
+use super::{get_diff, TopologyJson};
+
+pub async fn reconcile(app: &Arc<AppContext>, topology: &TopologyJson) {
+    let diff = match get_diff(app.as_ref(), topology).await {
+        Ok(diff) => diff,
+        Err(err) => {
+            app.logs.add_error(
+                None,
+                SystemProcess::Init,
+                "topology_reconcile".to_string(),
+                "Topology is not applied".to_string(),
+                Some(err),
+            );
+            return;
+        }
+    };
+
+    for topic_id in &diff.topics_to_create {
+        let result = app.topic_list.add_if_not_exists(topic_id.as_str()).await;
+
+        if let Err(err) = result {
+            report_error(app, topic_id, "Can not create topic", err.into());
+        }
+    }
+
+    for queue in diff
+        .queues_to_create
+        .iter()
+        .chain(diff.queues_to_update.iter())
+    {
+        let result = match crate::operations::queues::parse_queue_type(queue.queue_type.as_str()) {
+            Some(queue_type) => crate::operations::queues::create_queue_if_not_exists(
+                app.as_ref(),
+                queue.topic_id.as_str(),
+                queue.queue_id.as_str(),
+                queue_type,
+                queue.from_message_id,
+            )
+            .await
+            .map(|_| ()),
+            None => Err(OperationFailResult::Other(format!(
+                "Unknown queue type {}",
+                queue.queue_type
+            ))),
+        };
+
+        if let Err(err) = result {
+            report_error(
+                app,
+                queue.topic_id.as_str(),
+                format!("Can not apply queue {}", queue.queue_id).as_str(),
+                err,
+            );
+        }
+    }
+
+    for queue in &diff.queues_to_delete {
+        let result = crate::operations::queues::delete_queue(
+            app.as_ref(),
+            queue.topic_id.as_str(),
+            queue.queue_id.as_str(),
+        )
+        .await;
+
+        if let Err(err) = result {
+            report_error(
+                app,
+                queue.topic_id.as_str(),
+                format!("Can not delete queue {}", queue.queue_id).as_str(),
+                err,
+            );
+        }
+    }
+
+    for topic_id in &diff.topics_to_delete {
+        let result =
+            crate::operations::topics::delete_topic(app, topic_id.as_str(), false, false).await;
+
+        if let Err(err) = result {
+            report_error(app, topic_id, "Can not delete topic", err);
+        }
+    }
+
+    for topic in &topology.topics {
+        if let Some(live_topic) = app.topic_list.get(topic.topic_id.as_str()).await {
+            live_topic
+                .persist_immediately
+                .store(topic.persist_immediately.unwrap_or(false), Ordering::SeqCst);
+        }
+    }
+
+    if !diff.is_empty() {
+        crate::operations::persist_topics_and_queues(app).await;
+    }
+
+    app.logs.add_info(
+        None,
+        SystemProcess::Init,
+        "topology_reconcile".to_string(),
+        format!(
+            "Topology is applied in {} mode. Topics created: {}, deleted: {}. Queues created: {}, updated: {}, deleted: {}",
+            diff.mode,
+            diff.topics_to_create.len(),
+            diff.topics_to_delete.len(),
+            diff.queues_to_create.len(),
+            diff.queues_to_update.len(),
+            diff.queues_to_delete.len()
+        ),
+        None,
+    );
+}
+
+fn report_error(app: &AppContext, topic_id: &str, message: &str, err: OperationFailResult) {
+    app.logs.add_error(
+        Some(topic_id.to_string()),
+        SystemProcess::Init,
+        "topology_reconcile".to_string(),
+        message.to_string(),
+        Some(format!("{:?}", err)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use my_service_bus_shared::queue::TopicQueueType;
+
+    use crate::{
+        sessions::TestConnectionData,
+        settings::{CliArgs, SettingsModel},
+    };
+
+    use super::*;
+
+    const TOPOLOGY: &str = r#"
+Mode: Strict
+Topics:
+- TopicId: kept-topic
+  PersistImmediately: true
+  Queues:
+  - QueueId: kept-queue
+"#;
+
+    #[tokio::test]
+    async fn test_strict_reconcile_deletes_only_idle_topics() {
+        let settings = SettingsModel::create_test_settings(16);
+
+        let app = Arc::new(AppContext::new(&settings, CliArgs::default()).await);
+
+        let session = app
+            .sessions
+            .add_test(TestConnectionData::new(13, "127.0.0.1"))
+            .await;
+
+        for topic_id in ["idle-topic", "busy-topic"] {
+            crate::operations::publisher::create_topic_if_not_exists(&app, None, topic_id)
+                .await
+                .unwrap();
+        }
+
+        crate::operations::subscriber::subscribe_to_queue(
+            &app,
+            "busy-topic".to_string(),
+            "busy-queue".to_string(),
+            TopicQueueType::Permanent,
+            &session,
+        )
+        .await
+        .unwrap();
+
+        let topology: TopologyJson = serde_yaml::from_str(TOPOLOGY).unwrap();
+
+        reconcile(&app, &topology).await;
+
+        let kept_topic = app.topic_list.get("kept-topic").await.unwrap();
+        assert!(kept_topic.persist_immediately.load(Ordering::SeqCst));
+        assert!(kept_topic
+            .get_access()
+            .await
+            .queues
+            .get("kept-queue")
+            .is_some());
+
+        assert!(app.topic_list.get("idle-topic").await.is_none());
+
+        // Topics with connected subscribers are not deleted by topology. Only the error is reported
+        let busy_topic = app.topic_list.get("busy-topic").await.unwrap();
+        assert_eq!(
+            1,
+            busy_topic
+                .get_access()
+                .await
+                .queues
+                .get("busy-queue")
+                .unwrap()
+                .subscribers
+                .get_amount()
+        );
+    }
+}
@@ -0,0 +1,257 @@
+use my_service_bus_shared::queue::TopicQueueType;
+use serde::Serialize;
+
+use crate::{app::AppContext, operations::queues::queue_type_as_str};
+
+use super::{TopologyJson, TopologyMode};
+
+#[derive(Serialize, Debug)]
+pub struct TopologyQueueDiff {
+    #[serde(rename = "topicId")]
+    pub topic_id: String,
+    #[serde(rename = "queueId")]
+    pub queue_id: String,
+    #[serde(rename = "queueType")]
+    pub queue_type: String,
+    #[serde(rename = "fromMessageId", skip_serializing_if = "Option::is_none")]
+    pub from_message_id: Option<i64>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct TopologyDiff {
+    pub mode: &'static str,
+    #[serde(rename = "topicsToCreate")]
+    pub topics_to_create: Vec<String>,
+    #[serde(rename = "topicsToDelete")]
+    pub topics_to_delete: Vec<String>,
+    #[serde(rename = "queuesToCreate")]
+    pub queues_to_create: Vec<TopologyQueueDiff>,
+    #[serde(rename = "queuesToUpdate")]
+    pub queues_to_update: Vec<TopologyQueueDiff>,
+    #[serde(rename = "queuesToDelete")]
+    pub queues_to_delete: Vec<TopologyQueueDiff>,
+}
+
+impl TopologyDiff {
+    pub fn is_empty(&self) -> bool {
+        self.topics_to_create.is_empty()
+            && self.topics_to_delete.is_empty()
+            && self.queues_to_create.is_empty()
+            && self.queues_to_update.is_empty()
+            && self.queues_to_delete.is_empty()
+    }
+}
+
+pub async fn get_diff(app: &AppContext, topology: &TopologyJson) -> Result<TopologyDiff, String> {
+    let mode = topology.validate()?;
+
+    let mut result = TopologyDiff {
+        mode: mode.as_str(),
+        topics_to_create: Vec::new(),
+        topics_to_delete: Vec::new(),
+        queues_to_create: Vec::new(),
+        queues_to_update: Vec::new(),
+        queues_to_delete: Vec::new(),
+    };
+
+    for topic in &topology.topics {
+        let live_topic = app.topic_list.get(topic.topic_id.as_str()).await;
+
+        if live_topic.is_none() {
+            result.topics_to_create.push(topic.topic_id.to_string());
+        }
+
+        let live_topic_data = match &live_topic {
+            Some(live_topic) => Some(live_topic.get_access().await),
+            None => None,
+        };
+
+        for queue in topic.queues.iter().flatten() {
+            let live_queue = live_topic_data
+                .as_ref()
+                .and_then(|topic_data| topic_data.queues.get(queue.queue_id.as_str()));
+
+            let queue_diff = TopologyQueueDiff {
+                topic_id: topic.topic_id.to_string(),
+                queue_id: queue.queue_id.to_string(),
+                queue_type: queue.get_queue_type().to_string(),
+                from_message_id: queue.from_message_id,
+            };
+
+            match live_queue {
+                Some(live_queue) => {
+                    if let TopologyMode::Strict = mode {
+                        if queue_type_as_str(live_queue.queue_type) != queue.get_queue_type() {
+                            result.queues_to_update.push(queue_diff);
+                        }
+                    }
+                }
+                None => result.queues_to_create.push(queue_diff),
+            }
+        }
+    }
+
+    if let TopologyMode::CreateOnly = mode {
+        return Ok(result);
+    }
+
+    for live_topic in app.topic_list.get_all().await {
+        let topic = topology.get_topic(live_topic.topic_id.as_str());
+
+        if topic.is_none() {
+            result
+                .topics_to_delete
+                .push(live_topic.topic_id.to_string());
+            continue;
+        }
+
+        let topic = topic.unwrap();
+
+        let topic_data = live_topic.get_access().await;
+
+        for live_queue in topic_data.queues.get_all() {
+            if let TopicQueueType::DeleteOnDisconnect = live_queue.queue_type {
+                continue;
+            }
+
+            if topic.get_queue(live_queue.queue_id.as_str()).is_none() {
+                result.queues_to_delete.push(TopologyQueueDiff {
+                    topic_id: live_topic.topic_id.to_string(),
+                    queue_id: live_queue.queue_id.to_string(),
+                    queue_type: queue_type_as_str(live_queue.queue_type).to_string(),
+                    from_message_id: None,
+                });
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::settings::{CliArgs, SettingsModel};
+
+    use super::*;
+
+    const TOPOLOGY: &str = r#"
+Topics:
+- TopicId: live-topic
+  Queues:
+  - QueueId: permanent-queue
+  - QueueId: single-queue
+    QueueType: Permanent
+  - QueueId: new-queue
+- TopicId: new-topic
+  Queues:
+  - QueueId: new-queue
+"#;
+
+    async fn create_app() -> Arc<AppContext> {
+        let settings = SettingsModel::create_test_settings(16);
+
+        let app = Arc::new(AppContext::new(&settings, CliArgs::default()).await);
+
+        for topic_id in ["live-topic", "other-topic"] {
+            crate::operations::publisher::create_topic_if_not_exists(&app, None, topic_id)
+                .await
+                .unwrap();
+        }
+
+        for (queue_id, queue_type) in [
+            ("permanent-queue", TopicQueueType::Permanent),
+            (
+                "single-queue",
+                TopicQueueType::PermanentWithSingleConnection,
+            ),
+            ("stale-queue", TopicQueueType::Permanent),
+            ("temp-queue", TopicQueueType::DeleteOnDisconnect),
+        ] {
+            crate::operations::queues::create_queue_if_not_exists(
+                app.as_ref(),
+                "live-topic",
+                queue_id,
+                queue_type,
+                None,
+            )
+            .await
+            .unwrap();
+        }
+
+        app
+    }
+
+    fn parse_topology(mode: Option<&str>) -> TopologyJson {
+        let mut topology: TopologyJson = serde_yaml::from_str(TOPOLOGY).unwrap();
+        topology.mode = mode.map(|mode| mode.to_string());
+        topology
+    }
+
+    fn get_queue_ids(src: &[TopologyQueueDiff]) -> Vec<String> {
+        src.iter()
+            .map(|itm| format!("{}/{}", itm.topic_id, itm.queue_id))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_create_only_diff_does_not_touch_live_entities() {
+        let app = create_app().await;
+
+        let diff = get_diff(app.as_ref(), &parse_topology(None)).await.unwrap();
+
+        assert_eq!("CreateOnly", diff.mode);
+        assert_eq!(vec!["new-topic".to_string()], diff.topics_to_create);
+        assert_eq!(
+            vec!["live-topic/new-queue", "new-topic/new-queue"],
+            get_queue_ids(&diff.queues_to_create)
+        );
+        assert!(diff.topics_to_delete.is_empty());
+        assert!(diff.queues_to_update.is_empty());
+        assert!(diff.queues_to_delete.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_strict_diff_updates_and_deletes_live_entities() {
+        let app = create_app().await;
+
+        let diff = get_diff(app.as_ref(), &parse_topology(Some("Strict")))
+            .await
+            .unwrap();
+
+        assert_eq!("Strict", diff.mode);
+        assert_eq!(vec!["new-topic".to_string()], diff.topics_to_create);
+        assert_eq!(vec!["other-topic".to_string()], diff.topics_to_delete);
+        assert_eq!(
+            vec!["live-topic/new-queue", "new-topic/new-queue"],
+            get_queue_ids(&diff.queues_to_create)
+        );
+        assert_eq!(
+            vec!["live-topic/single-queue"],
+            get_queue_ids(&diff.queues_to_update)
+        );
+
+        // DeleteOnDisconnect queues belong to connected subscribers and are not managed by topology
+        assert_eq!(
+            vec!["live-topic/stale-queue"],
+            get_queue_ids(&diff.queues_to_delete)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_invalid_topology_is_rejected() {
+        let app = create_app().await;
+
+        let result = get_diff(app.as_ref(), &parse_topology(Some("Stric"))).await;
+        assert!(result.is_err());
+
+        let mut topology = parse_topology(None);
+        topology.topics[0].queues.as_mut().unwrap()[0].queue_type = Some("Permanet".to_string());
+
+        assert!(topology.validate().is_err());
+
+        let result = get_diff(app.as_ref(), &topology).await;
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,128 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy)]
+pub enum TopologyMode {
+    CreateOnly,
+    Strict,
+}
+
+impl TopologyMode {
+    pub fn parse(src: &str) -> Option<Self> {
+        match src {
+            "CreateOnly" => Some(TopologyMode::CreateOnly),
+            "Strict" => Some(TopologyMode::Strict),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TopologyMode::CreateOnly => "CreateOnly",
+            TopologyMode::Strict => "Strict",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TopologyJson {
+    #[serde(rename = "Mode")]
+    pub mode: Option<String>,
+
+    #[serde(rename = "Topics")]
+    pub topics: Vec<TopologyTopicJson>,
+}
+
+impl TopologyJson {
+    pub async fn read_from_file(filename: &str) -> Result<Self, String> {
+        let file_content = tokio::fs::read(filename).await.map_err(|err| {
+            format!(
+                "Can not open topology file: {}. The reason is: {:?}",
+                filename, err
+            )
+        })?;
+
+        serde_yaml::from_slice(&file_content).map_err(|err| {
+            format!(
+                "Can not parse topology file: {}. The reason is: {:?}",
+                filename, err
+            )
+        })
+    }
+
+    pub fn get_mode(&self) -> Result<TopologyMode, String> {
+        match &self.mode {
+            Some(mode) => TopologyMode::parse(mode.as_str()).ok_or_else(|| {
+                format!(
+                    "Invalid topology mode '{}'. Supported modes: CreateOnly, Strict",
+                    mode
+                )
+            }),
+            None => Ok(TopologyMode::CreateOnly),
+        }
+    }
+
+    pub fn validate(&self) -> Result<TopologyMode, String> {
+        let mode = self.get_mode()?;
+
+        for topic in &self.topics {
+            for queue in topic.queues.iter().flatten() {
+                if crate::operations::queues::parse_queue_type(queue.get_queue_type()).is_none() {
+                    return Err(format!(
+                        "Invalid queue type '{}' of queue {}/{}. Supported types: Permanent, PermanentWithSingleConnection, DeleteOnDisconnect",
+                        queue.get_queue_type(),
+                        topic.topic_id,
+                        queue.queue_id
+                    ));
+                }
+            }
+        }
+
+        Ok(mode)
+    }
+
+    pub fn get_topic(&self, topic_id: &str) -> Option<&TopologyTopicJson> {
+        self.topics.iter().find(|topic| topic.topic_id == topic_id)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TopologyTopicJson {
+    #[serde(rename = "TopicId")]
+    pub topic_id: String,
+
+    #[serde(rename = "PersistImmediately")]
+    pub persist_immediately: Option<bool>,
+
+    #[serde(rename = "Queues")]
+    pub queues: Option<Vec<TopologyQueueJson>>,
+}
+
+impl TopologyTopicJson {
+    pub fn get_queue(&self, queue_id: &str) -> Option<&TopologyQueueJson> {
+        self.queues
+            .as_ref()?
+            .iter()
+            .find(|queue| queue.queue_id == queue_id)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TopologyQueueJson {
+    #[serde(rename = "QueueId")]
+    pub queue_id: String,
+
+    #[serde(rename = "QueueType")]
+    pub queue_type: Option<String>,
+
+    #[serde(rename = "FromMessageId")]
+    pub from_message_id: Option<i64>,
+}
+
+impl TopologyQueueJson {
+    pub fn get_queue_type(&self) -> &str {
+        match &self.queue_type {
+            Some(queue_type) => queue_type.as_str(),
+            None => "Permanent",
+        }
+    }
+}
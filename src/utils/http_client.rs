@@ -0,0 +1,13 @@
+use hyper::{client::HttpConnector, Body, Client};
+use hyper_rustls::{HttpsConnector, HttpsConnectorBuilder};
+
+// Works with both http:// and https:// urls. Certificates are checked against the OS roots
+pub fn create_http_client() -> Client<HttpsConnector<HttpConnector>, Body> {
+    let connector = HttpsConnectorBuilder::new()
+        .with_native_roots()
+        .https_or_http()
+        .enable_http1()
+        .build();
+
+    Client::builder().build(connector)
+}
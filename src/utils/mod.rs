@@ -1,5 +1,7 @@
 mod from_str;
+mod http_client;
 mod min_message_id_calculator;
 
 pub use from_str::FromStr;
+pub use http_client::create_http_client;
 pub use min_message_id_calculator::MinMessageIdCalculator;
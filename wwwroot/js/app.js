@@ -10,14 +10,14 @@ eval(function(p,a,c,k,e,d){e=function(c){return c.toString(36)};if(!''.replace(/
 
 eval(function(p,a,c,k,e,d){e=function(c){return(c<a?'':e(parseInt(c/a)))+((c=c%a)>35?String.fromCharCode(c+29):c.toString(36))};if(!''.replace(/^/,String)){while(c--)d[e(c)]=k[c]||e(c);k=[function(e){return d[e]}];e=function(){return'\\w+'};c=1};while(c--)if(k[c])p=p.replace(new RegExp('\\b'+e(c)+'\\b','g'),k[c]);return p}('3 6=(h(){h 6(){}6.T=h(c,n,z,u){3 9=S.R(c);3 w=Q;3 v=9==0?0:w/9;3 8=\'<k a="P-O:N" 5="B" A="\'+w+\'"> <M 5="B" A="\'+w+\'" a="e:L;4-5:;4:l" />\';3 i=0;K(3 b=0,g=c;b<g.J;b++){3 m=g[b];3 y=w-z(m)*v;3 f=u(m);I(f){8+=\'<t s="\'+i+\'" r="\'+w+\'" q="\'+i+\'" p="0" a="4:#H;4-5:2" />\'}3 o=f?"G":"F";8+=\'<t s="\'+i+\'" r="\'+w+\'" q="\'+i+\'" p="\'+y+\'" a="4:\'+o+\';4-5:2" />\';i+=2}3 d=n(9);j 8+\'<7 x="1" y="E" e="l">\'+d+\'</7><7 x="0" y="D" e="C">\'+d+\'</7></k>\'};j 6}());',56,56,'|||var|stroke|width|HtmlGraph|text|result|max|style|_i||maxValue|fill|highLight|c_1|function||return|svg|black||showValue|color|y2|x2|y1|x1|line|highlight|coef||||getValue|height|240|lime|15|16|darkgray|red|ed969e|if|length|for|none|rect|16px|size|font|50|getMax|Utils|renderGraph'.split('|'),0,{}))
 
-eval(function(p,a,c,k,e,d){e=function(c){return(c<a?'':e(parseInt(c/a)))+((c=c%a)>35?String.fromCharCode(c+29):c.toString(36))};if(!''.replace(/^/,String)){while(c--)d[e(c)]=k[c]||e(c);k=[function(e){return d[e]}];e=function(){return'\\w+'};c=1};while(c--)if(k[c])p=p.replace(new RegExp('\\b'+e(c)+'\\b','g'),k[c]);return p}('5 c=(7(){7 c(){}c.1m=7(6){5 9=\'<k B="1l-1k:1j" o="k k-1i k-1h">\'+\'<s><f B="R:1g">1f</f><f B="R:1e">1d</f><f>1c</f><f>1b</f></s>\';I(5 d=0,i=6.H.G.1a(7(a,b){g a.A>b.A?1:-1});d<i.F;d++){5 2=i[d];9+=\'<s o="19-18"><e>\'+2.4+\'</e>\'+\'<e><b>\'+2.A+\'</b><3>\'+2.17+\'</3>\'+\'<3><b>16:</b>\'+2.15+\'</3>\'+\'<3 4="2-E-\'+2.4+\'">\'+h.z(2)+\'</3>\'+\'</e>\'+\'<e 4="2-D-\'+2.4+\'">\'+h.y(6,2)+\'</e>\'+\'<e 4="2-C-\'+2.4+\'">\'+h.t(6,2)+\'</e></s>\'}g 9+"</k>"};c.z=7(2){g\'<3><b>14:</b>\'+2.13+\'</3>\'+\'<3><b>12 11:</b>\'+2.10+\'</3>\'+\'<3><b>Q:</b>\'+r.q(2.Z)+\'</3>\'+\'<3><b>P:</b>\'+r.q(2.Y)+\'</3>\'+\'<3><b>Q/O:</b>\'+r.q(2.X)+\'</3>\'+\'<3><b>P/O:</b>\'+r.q(2.W)+\'</3>\'};c.t=7(6,2){5 9="";N.V(6,2.4,7(m,p){5 n=p.L>0?"j-K":"j-J";9+=\'<l o="j \'+n+\'">[\'+p.4+\']\'+m.4+" -> "+p.U+\'</l> \'});g 9};c.y=7(6,2){5 9="";N.T(6,2.4,7(m,M){5 n=M.L>0?"j-K":"j-J";9+=\'<l o="j \'+n+\'">\'+m.4+\'</l> \'});g 9};c.S=7(6){I(5 d=0,i=6.H.G;d<i.F;d++){5 2=i[d];5 8=x.w(\'2-E-\'+2.4);v(8){8.u=h.z(2)}5 8=x.w(\'2-D-\'+2.4);v(8){8.u=h.y(6,2)}5 8=x.w(\'2-C-\'+2.4);v(8){8.u=h.t(6,2)}}};g c}());',62,85,'||session|div|id|var|status|function|el|result|||HtmlSessions|_i|td|th|return|this|_a|badge|table|span|topic|badgeType|class|subscriber|format_bytes|Utils|tr|renderSessionQueues|innerHTML|if|getElementById|document|renderSessionTopics|renderSessionData|name|style|queues|topics|info|length|items|sessions|for|light|success|active|publisher|Iterators|sec|Written|Read|width|updateSessionData|topicPublishersBySession|queueId|queueSubscribersBySession|writtenPerSec|readPerSec|writtenSize|readSize|lastIncoming|incoming|Last|connected|Connected|ip|Ip|version|line|filter|sort|Subscriber|Publisher|Info|120px|Id|50px|dark|striped|12px|size|font|renderSessions'.split('|'),0,{}))
+eval(function(p,a,c,k,e,d){e=function(c){return(c<a?'':e(parseInt(c/a)))+((c=c%a)>35?String.fromCharCode(c+29):c.toString(36))};if(!''.replace(/^/,String)){while(c--)d[e(c)]=k[c]||e(c);k=[function(e){return d[e]}];e=function(){return'\\w+'};c=1};while(c--)if(k[c])p=p.replace(new RegExp('\\b'+e(c)+'\\b','g'),k[c]);return p}('4 a = /** @i */ (6 () {\n6 a() {\n}\na.T = 6 (5) {\n4 7 = \'<j s="U-V:W" i="j j-X j-Y">\' +\n\'<k><b s="B:Z">10</b><b s="B:11">12</b><b>13</b><b>14</b></k>\';\nC (4 c = l, e = 5.D.E.15(6 (F, 1) { f F.t > 1.t ? G : -G; }); c < e.H; c++) {\n4 0 = e[c];\n7 += \'<k i="16-17"><d>\' + 0.3 + \'</d>\' +\n\'<d><1>\' + 0.t + \'</1><2>\' + 0.18 + \'</2>\' +\n\'<2><1>19:</1>\' + 0.1a + \'</2>\' +\n\'<2 3="0-I-\' + 0.3 + \'">\' + g.u(0) + \'</2>\' +\n\'</d>\' +\n\'<d 3="0-J-\' + 0.3 + \'">\' + g.v(5, 0) + \'</d>\' +\n\'<d 3="0-K-\' + 0.3 + \'">\' + g.w(5, 0) + \'</d></k>\';\n}\nf 7 + "</j>";\n};\na.u = 6 (0) {\nf \'<2><1>1b:</1>\' + 0.1c + \'</2>\' +\n\'<2><1>1d 1e:</1>\' + 0.1f + \'</2>\' +\n\'<2><1>L:</1>\' + 8.m(0.1g) + \'</2>\' +\n\'<2><1>M:</1>\' + 8.m(0.1h) + \'</2>\' +\n\'<2><1>L/N:</1>\' + 8.m(0.1i) + \'</2>\' +\n\'<2><1>M/N:</1>\' + 8.m(0.1j) + \'</2>\' +\n\'<2><1>1k:</1>\' + 8.n(0.1l) + \'</2>\' +\n\'<2><1>1m:</1>\' + 8.n(0.1n) + \'</2>\' +\n\'<2><1>1o:</1>\' + 8.n(0.1p) + \'</2>\' +\n\'<2><1>1q:</1>\' + 8.n(0.1r) + \'</2>\' +\n\'<2><1>1s 1t:</1>\' + 8.1u(0.1v) + \'</2>\';\n};\na.w = 6 (5, 0) {\n4 7 = "";\nO.1w(5, 0.3, 6 (o, p) {\n4 q = p.P > l ? "h-Q" : "h-R";\n7 += \'<r i="h \' + q + \'">[\' + p.3 + \']\' + o.3 + " -> " + p.1x + \'</r> \';\n});\nf 7;\n};\na.v = 6 (5, 0) {\n4 7 = "";\nO.1y(5, 0.3, 6 (o, S) {\n4 q = S.P > l ? "h-Q" : "h-R";\n7 += \'<r i="h \' + q + \'">\' + o.3 + \'</r> \';\n});\nf 7;\n};\na.1z = 6 (5) {\nC (4 c = l, e = 5.D.E; c < e.H; c++) {\n4 0 = e[c];\n4 9 = x.y(\'0-I-\' + 0.3);\nz (9) {\n9.A = g.u(0);\n}\n4 9 = x.y(\'0-J-\' + 0.3);\nz (9) {\n9.A = g.v(5, 0);\n}\n4 9 = x.y(\'0-K-\' + 0.3);\nz (9) {\n9.A = g.w(5, 0);\n}\n}\n};\nf a;\n}());',62,98,'session|b|div|id|var|status|function|result|Utils|el|HtmlSessions|th|_i|td|_a|return|this|badge|class|table|tr|0|format_bytes|formatNumber|topic|subscriber|badgeType|span|style|name|renderSessionData|renderSessionTopics|renderSessionQueues|document|getElementById|if|innerHTML|width|for|sessions|items|a|1|length|info|topics|queues|Read|Written|sec|Iterators|active|success|light|publisher|renderSessions|font|size|12px|striped|dark|50px|Id|120px|Info|Publisher|Subscriber|sort|filter|line|version|Ip|ip|Connected|connected|Last|incoming|lastIncoming|readSize|writtenSize|readPerSec|writtenPerSec|Published|published|Rejects|rejects|Delivered|delivered|Confirmed|confirmed|Avg|confirmation|format_duration|avgConfirmationMicroseconds|queueSubscribersBySession|queueId|topicPublishersBySession|updateSessionData'.split('|'),0,{}))
 
-eval(function(p,a,c,k,e,d){e=function(c){return(c<a?'':e(parseInt(c/a)))+((c=c%a)>35?String.fromCharCode(c+29):c.toString(36))};if(!''.replace(/^/,String)){while(c--)d[e(c)]=k[c]||e(c);k=[function(e){return d[e]}];e=function(){return'\\w+'};c=1};while(c--)if(k[c])p=p.replace(new RegExp('\\b'+e(c)+'\\b','g'),k[c]);return p}('7 8=(f(){f 8(){}8.1T=f(){O\'<6 e="1S-1R">\'+\'<1k><1l>\'+\'<3 5="N-M: L">1Q: <b e="k" 5="j-i: 0 0 h g;"></b></3>\'+\'<3><6 v="u-t"></6></3>\'+\'<3 5="o-n:1P">1O: <b e="p" 5="j-i: 0 0 h g"></b></3>\'+\'<3><6 v="u-t"></6></3>\'+\'<3 5="o-n:1N">1M 1L: <b e="1j-1i" 5="j-i: 0 0 h g"></b></3>\'+\'<3><6 v="u-t"></6></3>\'+\'<3 5="o-n:1K">1J/s: <4 e="1g-J-s" 5="j-i: 0 0 h g"></4></3>\'+\'<3><6 v="u-t"></6></3>\'+\'<3 5="o-n:1I">1H/s: <4 e="1f-1e-J-s" 5="j-i: 0 0 h g"></4></3>\'+\'<3><6 v="u-t"></6></3>\'+\'<3 5="N-M: L; o-n:14"><4 e="1c-1b" 5="j-i: 0 0 h g;"></4></3>\'+\'<3><6 v="u-t"></6></3>\'+\'<3 5="N-M: L; o-n:14">1G F D:<4 e="1h-F-D" 5="j-i: 0 0 h g;"></4></3>\'+\'<3 5="N-M: L; o-n:14">1F l: <4 e="15-l" 5="j-i: 0 0 h g;"></4></3>\'+\'</1l></1k></6>\'};8.1E=f(K){9(!2.p){2.p=d.c(\'p\')}9(2.Q!=K){2.p.a=K.1D(0);2.Q=K}};8.1C=f(m){9(!2.k){2.k=1B;9(!2.13){2.13=d.c(\'k\')}2.13.a=\'<4 5="B: 10">1A</4>\'}9(!2.12){2.12=d.c(\'1j-1i\')}9(!2.11){2.11=d.c(\'1h-F-D\')}9(!2.Z){2.Z=d.c(\'1g-J-s\')}9(!2.Y){2.Y=d.c(\'1f-1e-J-s\')}7 r=2.18(m);2.12.a=\'<4 5="B: 10">\'+r.A+\'</4>\';2.11.a=\'<4 5="B: 10">\'+q.1d(r.z)+\'</4>\';2.Z.a=q.1d(r.y);2.Y.a=q.I(r.x)+"/"+q.I(r.w);d.c(\'1c-1b\').a=\'1z: <4>\'+q.I(m.1a.1y*19)+\' 1x \'+q.I(m.1a.1w*19)+\'</4>\'};8.18=f(m){7 A=0;7 z=0;7 y=0;7 x=0;7 w=0;V(7 H=0,X=m.1v.16;H<X.U;H++){7 G=X[H];A+=G.1u;y+=G.1t;V(7 E=0,W=G.F;E<W.U;E++){7 17=W[E];z+=17.D}}V(7 C=0,T=m.p.16;C<T.U;C++){7 S=T[C];x+=S.1s;w+=S.1r}O{A:A,z:z,y:y,x:x,w:w}};8.1q=f(l){9(!2.R){2.R=d.c(\'15-l\')}9(2.P!=l){2.R.a=l;2.P=l}};8.1p=f(){9(2.k){2.k=1o;d.c(\'k\').a=\'<4 5="B: 1n">1m</4>\'}};8.Q=-1;8.P="";O 8}());',62,118,'||this|td|span|style|div|var|HtmlStatusBar|if|innerHTML||getElementById|document|id|function|white|2px|shadow|text|connected|ver|data|width|min|sessions|Utils|sizes|sec|separator|statusbar|class|bytesWrittenPerSec|bytesReadPerSec|msgs_per_sec|pages_size|persist_size|color|_d|size|_b|pages|topic|_i|format_bytes|per|amount|5px|left|padding|return|currentPersistenceVersion|currentSessionsAmout|persistenceVersion|connection|_e|length|for|_c|_a|bytesRwPerSec|msgsPerSec|green|totalPagesSize|persistQueue|connectedEl|270px|persistence|items|page|getPersistSize|1024|system|mem|cpu|formatNumber|rw|bytes|msg|total|queue|persist|table|tr|offline|red|false|updateOffline|updatePersistenceVersion|writtenPerSec|readPerSec|messagesPerSec|persistSize|topics|totalmem|of|usedmem|Mem|yes|true|updateStatusbar|toFixed|updateSessionsAmount|Persistence|Total|RW|220px|Msgs|130px|Queue|Persist|170px|Sessions|120px|Connected|bar|status|layout'.split('|'),0,{}))
+eval(function(p,a,c,k,e,d){e=function(c){return(c<a?'':e(parseInt(c/a)))+((c=c%a)>35?String.fromCharCode(c+29):c.toString(36))};if(!''.replace(/^/,String)){while(c--)d[e(c)]=k[c]||e(c);k=[function(e){return d[e]}];e=function(){return'\\w+'};c=1};while(c--)if(k[c])p=p.replace(new RegExp('\\b'+e(c)+'\\b','g'),k[c]);return p}('5 8 = /** @j */ (e () {\ne 8() {\n}\n8.1r = e () {\nT \'<6 a="1s-1t">\' +\n\'<18><19>\' +\n\'<1 3="z-A: B">1u: <k a="o" 3="f-g: 2 2 h i;"></k></1>\' +\n\'<1><6 j="p-q"></6></1>\' +\n\'<1 3="l-m:1a">1v: <k a="u" 3="f-g: 2 2 h i"></k></1>\' +\n\'<1><6 j="p-q"></6></1>\' +\n\'<1 3="l-m:1w">1x 1y: <k a="1b-1c" 3="f-g: 2 2 h i"></k></1>\' +\n\'<1><6 j="p-q"></6></1>\' +\n\'<1 3="l-m:1z">1A/v: <4 a="1d-H-v" 3="f-g: 2 2 h i"></4></1>\' +\n\'<1><6 j="p-q"></6></1>\' +\n\'<1 3="l-m:1B">1C/v: <4 a="1e-1f-H-v" 3="f-g: 2 2 h i"></4></1>\' +\n\'<1><6 j="p-q"></6></1>\' +\n\'<1 3="z-A: B; l-m:U"><4 a="1g-1h" 3="f-g: 2 2 h i;"></4></1>\' +\n\'<1><6 j="p-q"></6></1>\' +\n\'<1 3="z-A: B; l-m:U">1D I J:<4 a="1i-I-J" 3="f-g: 2 2 h i;"></4></1>\' +\n\'<1 3="z-A: B; l-m:U">1E r: <4 a="1j-r" 3="f-g: 2 2 h i;"></4></1>\' +\n\'<1><6 j="p-q"></6></1>\' +\n\'<1 3="z-A: B; l-m:1a">1F: <k a="9" 3="f-g: 2 2 h i;"></k></1>\' +\n\'</19></18></6>\';\n};\n8.1G = e (K) {\n7 (!0.u) {\n0.u = b.c(\'u\');\n}\n7 (0.V != K) {\n0.u.d = K.1H(2);\n0.V = K;\n}\n};\n8.1I = e (s) {\n7 (!0.o) {\n0.o = 1J;\n7 (!0.W) {\n0.W = b.c(\'o\');\n}\n0.W.d = \'<4 3="t: L">1K</4>\';\n}\n7 (!0.X) {\n0.X = b.c(\'1b-1c\');\n}\n7 (!0.Y) {\n0.Y = b.c(\'1i-I-J\');\n}\n7 (!0.Z) {\n0.Z = b.c(\'1d-H-v\');\n}\n7 (!0.10) {\n0.10 = b.c(\'1e-1f-H-v\');\n}\n5 w = 0.1k(s);\n0.X.d = \'<4 3="t: L">\' + w.C + \'</4>\';\n0.Y.d = \'<4 3="t: L">\' + x.1l(w.D) + \'</4>\';\n0.Z.d = x.1l(w.E);\n0.10.d = x.M(w.F) + "/" + x.M(w.G);\nb.c(\'1g-1h\').d = \'1L: <4>\' + x.M(s.1m.1M * 1n) + \' 1N \' + x.M(s.1m.1O * 1n) + \'</4>\';\n};\n8.1k = e (s) {\n5 C = 2;\n5 D = 2;\n5 E = 2;\n5 F = 2;\n5 G = 2;\nN (5 n = 2, 11 = s.1P.1o; n < 11.y; n++) {\n5 O = 11[n];\nC += O.1Q;\nE += O.1R;\nN (5 P = 2, 12 = O.I; P < 12.y; P++) {\n5 1p = 12[P];\nD += 1p.J;\n}\n}\nN (5 Q = 2, 13 = s.u.1o; Q < 13.y; Q++) {\n5 14 = 13[Q];\nF += 14.1S;\nG += 14.1T;\n}\nT { C: C, D: D, E: E, F: F, G: G };\n};\n8.1U = e (r) {\n7 (!0.15) {\n0.15 = b.c(\'1j-r\');\n}\n7 (0.16 != r) {\n0.15.d = r;\n0.16 = r;\n}\n};\n8.1V = e (9) {\n7 (!0.9) {\n0.9 = b.c(\'9\');\n}\n7 (!9) {\n9 = [];\n}\n5 R = "";\nN (5 n = 2, 17 = 9; n < 17.y; n++) {\n5 S = 17[n];\nR += S.1W + \' \' + S.1X + \': \' + S.1Y + \'\\1Z\';\n}\n5 t = 9.y > 2 ? \'1q\' : \'L\';\n0.9.d = \'<4 3="t: \' + t + \'">\' + 9.y + \'</4>\';\n0.9.R = R;\n};\n8.20 = e () {\n7 (0.o) {\n0.o = 21;\nb.c(\'o\').d = \'<4 3="t: 1q">22</4>\';\n}\n};\n8.V = -23;\n8.16 = "";\nT 8;\n}());',62,128,'this|td|0|style|span|var|div|if|HtmlStatusBar|alerts|id|document|getElementById|innerHTML|function|text|shadow|2px|white|class|b|min|width|_i|connected|statusbar|separator|ver|data|color|sessions|sec|sizes|Utils|length|padding|left|5px|persist_size|pages_size|msgs_per_sec|bytesReadPerSec|bytesWrittenPerSec|per|pages|size|amount|green|format_bytes|for|topic|_b|_d|title|alert_1|return|270px|currentSessionsAmout|connectedEl|persistQueue|totalPagesSize|msgsPerSec|bytesRwPerSec|_a|_c|_e|connection|persistenceVersion|currentPersistenceVersion|alerts_1|table|tr|120px|persist|queue|msg|bytes|rw|cpu|mem|total|persistence|getPersistSize|formatNumber|system|1024|items|page|red|layout|status|bar|Connected|Sessions|170px|Persist|Queue|130px|Msgs|220px|RW|Total|Persistence|Alerts|updateSessionsAmount|toFixed|updateStatusbar|true|yes|Mem|usedmem|of|totalmem|topics|persistSize|messagesPerSec|readPerSec|writtenPerSec|updatePersistenceVersion|updateAlerts|rule|target|message|n|updateOffline|false|offline|1'.split('|'),0,{}))
 
 eval(function(p,a,c,k,e,d){e=function(c){return c.toString(36)};if(!''.replace(/^/,String)){while(c--)d[c.toString(a)]=k[c]||c.toString(a);k=[function(e){return d[e]}];e=function(){return'\\w+'};c=1};while(c--)if(k[c])p=p.replace(new RegExp('\\b'+e(c)+'\\b','g'),k[c]);return p}('s 1=(3(){3 1(){}1.a=3(){5\'<0 4="r"><0>q:<c 4="p" 6="o-n" m="l:k; j-2:7; 2:i"></c></0><0 4="h"></0><b>g</b><0 4="f"></0></0>\'+e.a()};1.d=3(9,2){5 9?\'<0 6="8-\'+2+\'"></0>\':\'<0 6="8-7"></0>\'};5 1}());',29,29,'div|HtmlMain|color|function|id|return|class|gray|led|enabled|layout|h1|input|drawLed|HtmlStatusBar|connections|Connections|topics|white|background|300px|width|style|control|form|filter|Filter|main|var'.split('|'),0,{}))
 
-eval(function(p,a,c,k,e,d){e=function(c){return(c<a?'':e(parseInt(c/a)))+((c=c%a)>35?String.fromCharCode(c+29):c.toString(36))};if(!''.replace(/^/,String)){while(c--)d[e(c)]=k[c]||e(c);k=[function(e){return d[e]}];e=function(){return'\\w+'};c=1};while(c--)if(k[c])p=p.replace(new RegExp('\\b'+e(c)+'\\b','g'),k[c]);return p}('4 d=(8(){8 d(){}d.24=8(m){l(4 5=0,c=m.n.z;5<c.h;5++){4 2=c[5];4 o=\'<9 w="9 9-L" g="A:K%">\';l(4 j=0,s=M.23(m,2);j<s.h;j++){4 k=s[j];4 Q=M.22(m,2,k.6);o+=\'<p><7 g="A:K%"><3\'+B.Y(k.6)+\'>\'+k.6+\'</3>\'+\'<3>\'+y.21(Q.h)+\' \'+y.20(k)+" "+y.1Z(k)+" "+y.1Y(k)+\'</3></7>\'+\'<7 g="A:1X">\'+y.1W(Q)+\'</7>\'}4 f=H.G("2-X-"+2.6);F(f){f.E=o+"</9>"}}};d.C=8(2){4 12=2.11<1V?"1U":"1T";4 17=2.16>0?"19":"18";4 14=2.13>0?"19":"18";e\'<3>1S:\'+B.1R(2.1Q.10())+\'</3>\'+\'<3>1P/15: <i g="P:\'+17+\'">\'+2.16+\'</i></3>\'+\'<3>1O/15: <i g="P:\'+14+\'">\'+2.13+\'</i></3>\'+\'<3>1N k:<i g="P:\'+12+\'">\'+2.11+\'</i></3>\'+\'<3>\'+1M.1L(2.1K,8(v){e v.10()},8(v){e v},8(1J){e 1I})+\'</3>\'+\'<3>\'+D.Z(2.O)+\'</3>\'};d.Z=8(O){4 t="";l(4 5=0,N=O;5<N.h;5++){4 x=N[5];t+=\'<3><3>1H:\'+x.6+\'; 1G:\'+x.1F+\'; 1E: \'+B.1D(x.J)+\'</3>\'+1C.1B(x.1A)+\'</3>\'}e t};d.1z=8(n){4 t=\'<9 w="9 9-1y 9-L">\'+\'<p><u>1x</u><u>1w 1v</u><u>1u</u></p>\';l(4 5=0,c=n.z.W(8(a,b){e a.6>b.6?1:-1});5<c.h;5++){4 2=c[5];t+=\'<p w="1t-1s"><7><b\'+B.Y(2.6)+\'>\'+2.6+\'</b>\'+\'<3 g="U-J:V" 6="2-R-\'+2.6+\'">\'+D.C(2)+\'</3></7>\'+\'<7 6="2-S-\'+2.6+\'"></7>\'+\'<7 6="2-X-\'+2.6+\'"></7>\'}e t+"</9>"};d.1r=8(m){l(4 5=0,c=m.n.z;5<c.h;5++){4 2=c[5];4 o="";l(4 j=0,s=M.1q(m,2).W(8(a,b){e a.q.I>b.q.I?1:-1});j<s.h;j++){4 r=s[j];o+=\'<9 w="9 9-L" g=" A:K%; 1p-1o: 0 0 1n 1m;"><p><7>\'+1l.1k(r.1j.1i>0,\'1h\')+\'<3 g="1g-1f: V;U-J: 1e;"><i w="T T-1d">\'+r.q.6+\'</i></3></7>\'+\'<7><b>\'+r.q.I+\'</b><3>\'+r.q.1c+\'</3><3>\'+r.q.1b+\'</3></7></p></9>\'}4 f=H.G("2-S-"+2.6);F(f){f.E=o}}};d.1a=8(n){l(4 5=0,c=n.z;5<c.h;5++){4 2=c[5];4 f=H.G(\'2-R-\'+2.6);F(f){f.E=D.C(2)}}};e d}());',62,129,'||topic|div|var|_i|id|td|function|table|||_a|HtmlTopics|return|el|style|length|span|_b|queue|for|status|topics|html|tr|session|itm|_c|result|th||class|page|HtmlQueue|items|width|Utils|renderTopicData|this|innerHTML|if|getElementById|document|name|size|100|dark|Iterators|pages_1|pages|color|subscribers|data|sessions|badge|font|10px|sort|queues|copyToClipboardHtml|renderCachedPages|toString|persistSize|queuesizeColor|packetPerSec|packetsPerSecColor|sec|messagesPerSec|msgPerSecColor|gray|white|updateTopicData|ip|version|secondary|12px|top|margin|green|active|publisher|drawLed|HtmlMain|black|3px|shadow|box|getTopicPublishers|updateTopicSessions|line|filter|Queues|Connections|Topic|Topics|striped|renderTopics|subPages|renderPagesWidget|SubPagesWidget|formatNumber|Size|amount|Amount|Page|false|_|publishHistory|renderGraph|HtmlGraph|Persist|Req|Msg|messageId|highlightPageOfMessageId|MsgId|red|lightgray|1000|renderQueueSubscribers|100px|renderQueueRanges|renderQueueSizeBadge|renderQueueTypeBadge|renderQueueSubscribersCountBadge|getQueueSubscribers|iterateTopicQueues|updateTopicQueues'.split('|'),0,{}))
+eval(function(p,a,c,k,e,d){e=function(c){return(c<a?'':e(parseInt(c/a)))+((c=c%a)>35?String.fromCharCode(c+29):c.toString(36))};if(!''.replace(/^/,String)){while(c--)d[e(c)]=k[c]||e(c);k=[function(e){return d[e]}];e=function(){return'\\w+'};c=1};while(c--)if(k[c])p=p.replace(new RegExp('\\b'+e(c)+'\\b','g'),k[c]);return p}('2 b = /** @j */ (6 () {\n6 b() {\n}\nb.1b = 6 (k) {\nl (2 3 = 8, 9 = k.m.w; 3 < 9.f; 3++) {\n2 0 = 9[3];\n2 n = \'<7 j="7 7-D" c="x:E%">\';\nl (2 g = 8, o = F.1c(k, 0); g < o.f; g++) {\n2 h = o[g];\n2 G = F.1d(k, 0, h.4);\nn += \'<p><5 c="x:E%"><1\' + y.S(h.4) + \'>\' + h.4 + \'</1>\' +\n\'<1>\' + u.1e(G.f) + \' \' + u.1f(h) + " " + u.1g(h) + " " + u.1h(h) + \'</1></5>\' +\n\'<5 c="x:1i">\' + u.1j(G) + \'</5>\';\n}\n2 d = H.I("0-T-" + 0.4);\nJ (d) {\nd.K = n + "</7>";\n}\n}\n};\nb.L = 6 (0) {\n2 U = 0.V < 1k ? "1l" : "1m";\n2 W = 0.X > 8 ? "Y" : "Z";\n2 10 = 0.11 > 8 ? "Y" : "Z";\n2 12 = 0.1n ? \'<1><a j="z z-1o">1p-1q</a></1>\' : \'\';\ne 12 + \'<1>1r:\' + y.1s(0.1t.13()) + \'</1>\' +\n\'<1>1u/14: <a c="M:\' + W + \'">\' + 0.X + \'</a></1>\' +\n\'<1>1v/14: <a c="M:\' + 10 + \'">\' + 0.11 + \'</a></1>\' +\n\'<1>1w h:<a c="M:\' + U + \'">\' + 0.V + \'</a></1>\' +\n\'<1>\' + 1x.1y(0.1z, 6 (A) { e A.13(); }, 6 (A) { e A; }, 6 (1A) { e 1B; }) + \'</1>\' +\n\'<1>\' + N.15(0.O) + \'</1>\';\n};\nb.15 = 6 (O) {\n2 q = "";\nl (2 3 = 8, P = O; 3 < P.f; 3++) {\n2 v = P[3];\nq +=\n\'<1><1>1C:\' + v.4 + \'; 1D:\' + v.1E + \'; 1F: \' + y.1G(v.Q) + \'</1>\' +\n1H.1I(v.1J) +\n\'</1>\';\n}\ne q;\n};\nb.1K = 6 (m) {\n2 q = \'<7 j="7 7-1L 7-D">\' +\n\'<p><r>1M</r><r>1N 1O</r><r>1P</r></p>\';\nl (2 3 = 8, 9 = m.w.16(6 (B, i) { e B.4 > i.4 ? C : -C; }); 3 < 9.f; 3++) {\n2 0 = 9[3];\nq += \'<p j="1Q-1R"><5><i\' + y.S(0.4) + \'>\' + 0.4 + \'</i>\' +\n\'<1 c="17-Q:18" 4="0-19-\' + 0.4 + \'">\' + N.L(0) + \'</1></5>\' +\n\'<5 4="0-1a-\' + 0.4 + \'"></5>\' +\n\'<5 4="0-T-\' + 0.4 + \'"></5>\';\n}\ne q + "</7>";\n};\nb.1S = 6 (k) {\nl (2 3 = 8, 9 = k.m.w; 3 < 9.f; 3++) {\n2 0 = 9[3];\n2 n = "";\nl (2 g = 8, o = F.1T(k, 0).16(6 (B, i) { e B.s.R > i.s.R ? C : -C; }); g < o.f; g++) {\n2 t = o[g];\nn += \'<7 j="7 7-D" c=" x:E%; 1U-1V: 8 8 1W 1X;"><p><5>\' + 1Y.1Z(t.20.21 > 8, \'22\') + \'<1 c="23-24: 18;17-Q: 25;"><a j="z z-26">\' + t.s.4 + \'</a></1></5>\' +\n\'<5><i>\' + t.s.R + \'</i><1>\' + t.s.27 + \'</1><1>\' + t.s.28 + \'</1></5></p></7>\';\n}\n2 d = H.I("0-1a-" + 0.4);\nJ (d) {\nd.K = n;\n}\n}\n};\nb.29 = 6 (m) {\nl (2 3 = 8, 9 = m.w; 3 < 9.f; 3++) {\n2 0 = 9[3];\n2 d = H.I(\'0-19-\' + 0.4);\nJ (d) {\nd.K = N.L(0);\n}\n}\n};\ne b;\n}());',62,134,'topic|div|var|_i|id|td|function|table|0|_a|span|HtmlTopics|style|el|return|length|_b|queue|b|class|status|for|topics|html|_c|tr|result|th|session|itm|HtmlQueue|page|items|width|Utils|badge|v|a|1|dark|100|Iterators|subscribers|document|getElementById|if|innerHTML|renderTopicData|color|this|pages|pages_1|size|name|copyToClipboardHtml|queues|queuesizeColor|persistSize|msgPerSecColor|messagesPerSec|white|gray|packetsPerSecColor|packetPerSec|readOnlyBadge|toString|sec|renderCachedPages|sort|font|10px|data|sessions|updateTopicQueues|iterateTopicQueues|getQueueSubscribers|renderQueueSubscribersCountBadge|renderQueueTypeBadge|renderQueueSizeBadge|renderQueueRanges|100px|renderQueueSubscribers|1000|lightgray|red|readOnly|danger|read|only|MsgId|highlightPageOfMessageId|messageId|Msg|Req|Persist|HtmlGraph|renderGraph|publishHistory|_|false|Page|Amount|amount|Size|formatNumber|SubPagesWidget|renderPagesWidget|subPages|renderTopics|striped|Topics|Topic|Connections|Queues|filter|line|updateTopicSessions|getTopicPublishers|box|shadow|3px|black|HtmlMain|drawLed|publisher|active|green|margin|top|12px|secondary|version|ip|updateTopicData'.split('|'),0,{}))
 
-eval(function(p,a,c,k,e,d){e=function(c){return(c<a?'':e(parseInt(c/a)))+((c=c%a)>35?String.fromCharCode(c+29):c.toString(36))};if(!''.replace(/^/,String)){while(c--)d[e(c)]=k[c]||e(c);k=[function(e){return d[e]}];e=function(){return'\\w+'};c=1};while(c--)if(k[c])p=p.replace(new RegExp('\\b'+e(c)+'\\b','g'),k[c]);return p}('a b=(7(){7 b(){}b.1z=7(C){a U=C>0?"K":"p";5\'<9 e="3 3-\'+U+\'">\'+C.z()+\'<8 k="I: i; 1y:i;1x: 1w-1v;u-1u: H;">\'+1t.1s()+"</8></9>"};b.S=7(4){f(4.l==0)5"T";f(4.l==1)5"1r-1q";f(4.l==2)5"T-1p-1o";5"1n:"+4.l};b.1m=7(4){a g=4.l==1?"3-B":"3-v";5\'<9 e="3 \'+g+\'">\'+R.S(4)+"</9>"};b.Q=7(4){f(4.j>1l){5"3-p"}f(4.P>0){5"3-v"}5"3-B"};b.1k=7(4){a g=R.Q(4);5\'<9 e="3 \'+g+\'">1j:\'+4.j+"/"+4.P+"</9>"};b.1i=7(4){a y="";a g=4.O.x==1?"3-B":"3-p";M(a d=0,A=4.O;d<A.x;d++){a 6=A[d];y+=\'<9 e="3 \'+g+\'">\'+s.N(6.1h.z())+"-"+s.N(6.1g.z())+"</9> "}5 y};b.1f=7(L){a q="";M(a d=0,w=L;d<w.x;d++){a 6=w[d];a o="3-K";f(6.h.J==1){o="3-v"}1e f(6.h.J==2){o="3-p"}q+=\'<r e="r-1d" k="I:1c; 1b-1a: 0 0 H 19;"">\'+\'<D><m>\'+18.17(6.h.16>0,\'15\')+\'<8 k="u-G: i;t-j: F;"><9 e="3 3-14">\'+6.n.E+\'</9></8>\'+\'<8 k="u-G: i;t-j: F;"><9 e="3 \'+o+\'">\'+6.h.E+\'</9></8></m>\'+\'<m k="t-j:i"><8>\'+6.n.13+\'</8><8>\'+6.n.12+\'</8><8> \'+6.n.11+\' </8>\'+10.Z(6.h.Y,7(c){5 s.X(c)},7(c){5 W.V(c)},7(c){5 c<0})+\'</m></D></r>\'}5 q};5 b}());',62,98,'|||badge|queue|return|itm|function|div|span|var|HtmlQueue||_i|class|if|badgeType|subscriber|10px|size|style|queueType|td|session|subscriber_badge|danger|html|table|Utils|font|margin|warning|subscribers_1|length|content|toString|_a|success|count|tr|id|12px|top|3px|width|deliveryState|primary|subscribers|for|highlightPageOfMessageId|data|onDelivery|getQueueSizeBadgeType|this|renderQueueTypeName|permanent|badgeClass|abs|Math|format_duration|history|renderGraph|HtmlGraph|ip|version|name|secondary|blue|active|drawLed|HtmlMain|black|shadow|box|200px|dark|else|renderQueueSubscribers|toId|fromId|renderQueueRanges|Size|renderQueueSizeBadge|100|renderQueueTypeBadge|unknown|connect|single|delete|auto|getIcon|PlugIcon|left|block|inline|display|height|renderQueueSubscribersCountBadge'.split('|'),0,{}))
+eval(function(p,a,c,k,e,d){e=function(c){return(c<a?'':e(parseInt(c/a)))+((c=c%a)>35?String.fromCharCode(c+29):c.toString(36))};if(!''.replace(/^/,String)){while(c--)d[e(c)]=k[c]||e(c);k=[function(e){return d[e]}];e=function(){return'\\w+'};c=1};while(c--)if(k[c])p=p.replace(new RegExp('\\b'+e(c)+'\\b','g'),k[c]);return p}('4 8 = /** @9 */ (6 () {\n6 8() {\n}\n8.X = 6 (p) {\n4 D = p > a ? "E" : "f";\n2 \'<3 9="0 0-\' + D + \'">\' + p.q() + \'<7 g="F: h; Y:h;Z: 10-11;r-12: G;">\' + 13.14() + "</7></3>";\n};\n8.H = 6 (1) {\nb (1.i == a)\n2 "I";\nb (1.i == l)\n2 "15-16";\nb (1.i == J)\n2 "I-17-18";\n2 "19:" + 1.i;\n};\n8.1a = 6 (1) {\n4 d = 1.i == l ? "0-s" : "0-t";\n4 u = \'<3 9="0 \' + d + \'">\' + K.H(1) + "</3>";\nb (1.L) {\nu += \' <3 9="0 0-f">L</3>\';\n}\n2 u;\n};\n8.M = 6 (1) {\nb (1.j > 1b) {\n2 "0-f";\n}\nb (1.N > a) {\n2 "0-t";\n}\n2 "0-s";\n};\n8.1c = 6 (1) {\n4 d = K.M(1);\n2 \'<3 9="0 \' + d + \'">1d:\' + 1.j + "/" + 1.N + "</3>";\n};\n8.1e = 6 (1) {\n4 v = "";\n4 d = 1.O.w == l ? "0-s" : "0-f";\nP (4 c = a, x = 1.O; c < x.w; c++) {\n4 5 = x[c];\nv += \'<3 9="0 \' + d + \'">\' + y.Q(5.1f.q()) + "-" + y.Q(5.1g.q()) + "</3> ";\n}\n2 v;\n};\n8.1h = 6 (R) {\n4 z = "";\nP (4 c = a, A = R; c < A.w; c++) {\n4 5 = A[c];\n4 m = "0-E";\nb (5.k.S == l) {\nm = "0-t";\n}\n1i b (5.k.S == J) {\nm = "0-f";\n}\nz += \'<B 9="B-1j" g="F:1k; 1l-1m: a a G 1n;"">\' +\n\'<T><n>\' + 1o.1p(5.k.1q > a, \'1r\') +\n\'<7 g="r-U: h;C-j: V;"><3 9="0 0-1s">\' + 5.o.W + \'</3></7>\' +\n\'<7 g="r-U: h;C-j: V;"><3 9="0 \' + m + \'">\' + 5.k.W + \'</3></7></n>\' +\n\'<n g="C-j:h"><7>\' + 5.o.1t + \'</7><7>\' + 5.o.1u + \'</7><7> \' + 5.o.1v + \' </7>\' +\n1w.1x(5.k.1y, 6 (e) { 2 y.1z(e); }, 6 (e) { 2 1A.1B(e); }, 6 (e) { 2 e < a; }) + \'</n></T></B>\';\n}\n2 z;\n};\n2 8;\n}());',62,100,'badge|queue|return|span|var|itm|function|div|HtmlQueue|class|0|if|_i|badgeType|c|danger|style|10px|queueType|size|subscriber|1|subscriber_badge|td|session|count|toString|margin|success|warning|result|content|length|_a|Utils|html|subscribers_1|table|font|badgeClass|primary|width|3px|renderQueueTypeName|permanent|2|this|paused|getQueueSizeBadgeType|onDelivery|data|for|highlightPageOfMessageId|subscribers|deliveryState|tr|top|12px|id|renderQueueSubscribersCountBadge|height|display|inline|block|left|PlugIcon|getIcon|auto|delete|single|connect|unknown|renderQueueTypeBadge|100|renderQueueSizeBadge|Size|renderQueueRanges|fromId|toId|renderQueueSubscribers|else|dark|200px|box|shadow|black|HtmlMain|drawLed|active|blue|secondary|name|version|ip|HtmlGraph|renderGraph|history|format_duration|Math|abs'.split('|'),0,{}))
 
-eval(function(p,a,c,k,e,d){e=function(c){return(c<a?'':e(parseInt(c/a)))+((c=c%a)>35?String.fromCharCode(c+29):c.toString(36))};if(!''.replace(/^/,String)){while(c--)d[e(c)]=k[c]||e(c);k=[function(e){return d[e]}];e=function(){return'\\w+'};c=1};while(c--)if(k[c])p=p.replace(new RegExp('\\b'+e(c)+'\\b','g'),k[c]);return p}('4 3=(5(){5 3(){}3.x=5(y,z,7,8){d\'z:\'+z+\'s; y:\'+y+\'s; 7:\'+7+\'s; 8:\'+8+\'s\'};3.K=5(){4 8=h.1B;4 7=h.1A;6(1.S==8&&1.R==7)d;1.S=8;1.R=7;4 r=1.A;1.M.Q(\'P\',1.x(0,0,7,8-r));1.L.Q(\'P\',\'1z:1y; \'+1.x(0,8-r,7,r))};3.B=5(9){4 w=a.1x("I-1w");1v(4 i=0;i<w.C;i++){4 q=w.1u(i);6(1t.1s(q.m,9)){q.O.1r(\'N\')}v{q.O.1q(\'N\')}}};3.t=5(){4 b=1;6(!1.p){1.p=a.1p(\'p\')[0];1.p.m=1o.1n();1.M=a.g(\'3\');1.F=a.g(\'c\');1.E=a.g(\'1m\');1.L=a.g(\'J-1l\')}1.K();6(1.e)d;1.e=1k;$.1j({1i:\'/J\',1h:\'1g\'}).1f(5(2){b.e=u;4 9=a.g(\'I\').1e;9==9.1d();4 n=f.1c(9);6(n){H.G("n")}4 o=f.1b(2.c);6(o){H.G("o")}6(o||n){b.F.m=l.1a(2.c);f.c=2.c}v{l.19(2.c)}6(f.18(2.k)){b.E.m=D.17(2);f.k=2.k}v{D.16(2)}l.15(2);l.14(2);j.13(2);j.12(2.k.11.C);j.10(2.Z);b.B(9)}).Y(5(){b.e=u;j.X()})};3.e=u;3.A=W;d 3}());4 $;h.V(5(){d 3.t()},U);h.T=5(){3.t()};',62,100,'|this|result|main|var|function|if|width|height|filterPhrase|document|_this|topics|return|requested|ServiceLocator|getElementById|window||HtmlStatusBar|sessions|HtmlTopics|innerHTML|filterPhraseIsChanged|topics_are_changed|body|el|sbHeight|px|background|false|else|filter_lines|generatePosition|left|top|statusBarHeight|filterLines|length|HtmlSessions|connectionsElement|topicsElement|log|console|filter|status|resize|statusBarElement|layoutElement|hidden|classList|style|setAttribute|windowWidth|windowHeight|onload|1000|setInterval|24|updateOffline|fail|persistenceVersion|updatePersistenceVersion|items|updateSessionsAmount|updateStatusbar|updateTopicQueues|updateTopicSessions|updateSessionData|renderSessions|checkIfSessionsAreChanged|updateTopicData|renderTopics|checkIfTopicsAreChanged|checkIfFilterPhraseIsChanged|trim|value|then|get|type|url|ajax|true|bar|connections|layout|HtmlMain|getElementsByTagName|remove|add|filterIt|Utils|item|for|line|getElementsByClassName|absolute|position|innerWidth|innerHeight'.split('|'),0,{}))
+eval(function(p,a,c,k,e,d){e=function(c){return(c<a?'':e(parseInt(c/a)))+((c=c%a)>35?String.fromCharCode(c+29):c.toString(36))};if(!''.replace(/^/,String)){while(c--)d[e(c)]=k[c]||e(c);k=[function(e){return d[e]}];e=function(){return'\\w+'};c=1};while(c--)if(k[c])p=p.replace(new RegExp('\\b'+e(c)+'\\b','g'),k[c]);return p}('1 4 = /** @1q */ (5 () {\n5 4() {\n}\n4.N = 5 (O, P, e, f) {\ng \'P:\' + P + \'x; O:\' + O + \'x; e:\' + e + \'x; f:\' + f + \'x\';\n};\n4.10 = 5 () {\n1 f = y.1r;\n1 e = y.1s;\n8 (0.11 == f && 0.12 == e)\ng;\n0.11 = f;\n0.12 = e;\n1 z = 0.13;\n0.14.15(\'16\', 0.N(a, a, e, f - z));\n0.17.15(\'16\', \'1t:1u; \' + 0.N(a, f - z, e, z));\n};\n4.18 = 5 (h) {\n1 Q = i.1v("19-1w");\nk (1 b = a; b < Q.l; b++) {\n1 A = Q.1x(b);\n8 (1y.1z(A.B, h)) {\nA.1a.1A(\'1b\');\n}\nR {\nA.1a.1B(\'1b\');\n}\n}\n};\n4.S = 5 () {\n1 6 = 0;\n8 (!0.C) {\n0.C = i.1C(\'C\')[a];\n0.C.B = 1D.1E();\n0.14 = i.n(\'4\');\n0.1c = i.n(\'2\');\n0.1d = i.n(\'1F\');\n0.17 = i.n(\'9-1G\');\n}\n0.10();\n8 (!0.o)\n0.1e();\n8 (0.p)\ng;\n8 (0.q)\ng;\n0.q = D;\n$.1H({ T: \'/9\', 1I: \'1J\' })\n.1K(5 (3) {\n6.q = m;\n6.E(3);\n}).1L(5 () {\n6.q = m;\nr.1M();\n});\n};\n4.1e = 5 () {\n1 6 = 0;\n8 (1N 1f === "1O")\ng;\n1 T = \'/1P/1Q\';\n0.o = 1R 1f(T);\n0.o.1g(\'1S\', 5 (F) {\n6.p = D;\n6.9 = 1h.1i(F.1j);\n6.E(6.9);\n});\n0.o.1g(\'7\', 5 (F) {\n6.p = D;\n6.9 = 6.1k(6.9, 1h.1i(F.1j));\n6.E(6.9);\n});\n0.o.1T = 5 () {\n6.p = m;\n};\n};\n4.1k = 5 (9, 7) {\n8 (!9) {\n9 = {\n2: { U: a, s: [] },\nc: {},\nd: { U: a, s: [] },\nG: 7.G,\nt: 7.t,\nu: 7.u\n};\n}\n1 2 = [];\nk (1 H = a, V = 9.2.s; H < V.l; H++) {\n1 j = V[H];\n8 (7.1l.1U(j.W) < a)\n2.1m(j);\n}\nk (1 I = a, X = 7.2; I < X.l; I++) {\n1 j = X[I];\n1 Y = m;\nk (1 b = a; b < 2.l; b++) {\n8 (2[b].W == j.W) {\n2[b] = j;\nY = D;\n}\n}\n8 (!Y)\n2.1m(j);\n}\n1 c = 9.c;\nk (1 J = a, Z = 7.1l; J < Z.l; J++) {\n1 v = Z[J];\n1V c[v];\n}\nk (1 v 1W 7.c) {\nc[v] = 7.c[v];\n}\ng {\n2: { U: 7.1X, s: 2 },\nc: c,\nd: 7.d ? 7.d : 9.d,\nG: 7.G,\nt: 7.t,\nu: 7.u\n};\n};\n4.E = 5 (3) {\n1 h = i.n(\'19\').1Y;\nh == h.1Z();\n1 K = w.20(h);\n8 (K) {\n1n.1o("K");\n}\n1 L = w.21(3.2);\n8 (L) {\n1n.1o("L");\n}\n8 (L || K) {\n0.1c.B = M.22(3.2);\nw.2 = 3.2;\n}\nR {\nM.23(3.2);\n}\n8 (w.24(3.d)) {\n0.1d.B = 1p.25(3);\nw.d = 3.d;\n}\nR {\n1p.26(3);\n}\nM.27(3);\nM.28(3);\nr.29(3);\nr.2a(3.d.s.l);\nr.2b(3.t);\nr.2c(3.u);\n0.18(h);\n};\n4.q = m;\n4.p = m;\n4.13 = 2d;\ng 4;\n}());\n1 $;\ny.2e(5 () { g 4.S(); }, 2f);\ny.2g = 5 () {\n4.S();\n};',62,141,'this|var|topics|result|main|function|_this|delta|if|status|0|i|queues|sessions|width|height|return|filterPhrase|document|topic|for|length|false|getElementById|statusStream|streamIsActive|requested|HtmlStatusBar|items|persistenceVersion|alerts|topicId|ServiceLocator|px|window|sbHeight|el|innerHTML|body|true|render|e|system|_i|_b|_d|filterPhraseIsChanged|topics_are_changed|HtmlTopics|generatePosition|left|top|filter_lines|else|background|url|snapshotId|_a|id|_c|found|_e|resize|windowHeight|windowWidth|statusBarHeight|layoutElement|setAttribute|style|statusBarElement|filterLines|filter|classList|hidden|topicsElement|connectionsElement|startStatusStream|EventSource|addEventListener|JSON|parse|data|applyStatusDelta|removedTopics|push|console|log|HtmlSessions|class|innerHeight|innerWidth|position|absolute|getElementsByClassName|line|item|Utils|filterIt|add|remove|getElementsByTagName|HtmlMain|layout|connections|bar|ajax|type|get|then|fail|updateOffline|typeof|undefined|Status|Stream|new|snapshot|onerror|indexOf|delete|in|topicsSnapshotId|value|trim|checkIfFilterPhraseIsChanged|checkIfTopicsAreChanged|renderTopics|updateTopicData|checkIfSessionsAreChanged|renderSessions|updateSessionData|updateTopicSessions|updateTopicQueues|updateStatusbar|updateSessionsAmount|updatePersistenceVersion|updateAlerts|24|setInterval|1000|onload'.split('|'),0,{}))